@@ -0,0 +1,58 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use gc_arena::rootless_arena;
+use ruffle_core::font::{EvalParameters, Font};
+use ruffle_core::string::WString;
+use ruffle_core::swf::{self, Twips};
+use ruffle_render::backend::null::NullRenderer;
+use ruffle_render::backend::ViewportDimensions;
+
+const PARAGRAPH: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do \
+    eiusmod tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam, \
+    quis nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo consequat.";
+
+fn measure(c: &mut Criterion) {
+    rootless_arena(|mc| {
+        let mut renderer = NullRenderer::new(ViewportDimensions {
+            width: 0,
+            height: 0,
+            scale_factor: 1.0,
+        });
+        let data = include_bytes!("../assets/noto-sans-definefont3.bin");
+        let mut reader = swf::read::Reader::new(data, 8);
+        let tag = reader.read_define_font_2(3).unwrap();
+        let font = Font::from_swf_tag(mc, &mut renderer, tag, reader.encoding());
+
+        let paragraph = WString::from_utf8(PARAGRAPH);
+        let params = EvalParameters::from_parts(Twips::from_pixels(12.0), Twips::ZERO, true);
+
+        // Layout measures the same text over and over, which hits the string cache.
+        c.bench_function("measure paragraph x1000", |b| {
+            b.iter(|| {
+                for _ in 0..1000 {
+                    black_box(font.measure(black_box(&paragraph), params, false));
+                }
+            })
+        });
+
+        // Text that keeps changing misses the string cache, but still hits the glyph
+        // advance cache. There are more strings than the string cache holds, so that
+        // they are never served from it.
+        let paragraphs: Vec<_> = (0..2000)
+            .map(|i| {
+                let mut text = WString::from_utf8(PARAGRAPH);
+                text.push_utf8(&i.to_string());
+                text
+            })
+            .collect();
+        c.bench_function("measure distinct paragraphs x2000", |b| {
+            b.iter(|| {
+                for text in &paragraphs {
+                    black_box(font.measure(black_box(text), params, false));
+                }
+            })
+        });
+    });
+}
+
+criterion_group!(benches, measure);
+criterion_main!(benches);
@@ -0,0 +1,640 @@
+//! AMF serialization shared between AVM1 and AVM2.
+//!
+//! The AMF0/AMF3 encoding itself is implemented by `flash_lso`. This module
+//! deals with reading and writing single values outside of an LSO container,
+//! independent of which VM the values are coming from.
+//!
+//! `flash_lso` values are trees, so they can't describe objects that are
+//! referenced more than once. For AMF3, whose object reference table allows
+//! exactly that, this module also provides its own codec for an `ObjectGraph`.
+
+use flash_lso::amf0::read::AMF0Decoder;
+use flash_lso::amf3::read::AMF3Decoder;
+use flash_lso::types::{AMFVersion, Element, Lso, Value};
+use fnv::FnvHashMap;
+
+/// Encode a single AMF value, without any surrounding LSO header.
+pub fn encode_value(value: Value, amf_version: AMFVersion) -> Option<Vec<u8>> {
+    let mut lso = Lso::new(vec![Element::new("", value)], "", amf_version);
+    let bytes = flash_lso::write::write_to_bytes(&mut lso).ok()?;
+
+    // `flash_lso` only writes whole LSO files, so strip out the header, the
+    // (empty) element name and the trailing padding to keep only the value.
+    let element_padding = match amf_version {
+        AMFVersion::AMF0 => 8,
+        AMFVersion::AMF3 => 7,
+    };
+    let start = flash_lso::write::header_length(&lso.header) + element_padding;
+    bytes.get(start..bytes.len() - 1).map(<[u8]>::to_vec)
+}
+
+/// Decode a single AMF value, returning it along with any unread bytes.
+pub fn decode_value(bytes: &[u8], amf_version: AMFVersion) -> Option<(&[u8], Value)> {
+    match amf_version {
+        AMFVersion::AMF0 => AMF0Decoder::default().parse_single_element(bytes).ok(),
+        AMFVersion::AMF3 => AMF3Decoder::default().parse_single_element(bytes).ok(),
+    }
+}
+
+/// Identifies an object of an `ObjectGraph`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObjectId(usize);
+
+/// A value stored in AMF3, referring to objects by their `ObjectId`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GraphValue {
+    Undefined,
+    Null,
+    Bool(bool),
+    Integer(i32),
+    Number(f64),
+    String(String),
+    Object(ObjectId),
+}
+
+/// An object stored in AMF3. Every kind of object takes up an entry in the
+/// object reference table, and so keeps its identity.
+#[derive(Clone, Debug, PartialEq)]
+pub enum GraphObject {
+    /// An object with the given properties. Anonymous objects have an empty class name.
+    Object {
+        class_name: String,
+        properties: Vec<(String, GraphValue)>,
+    },
+    Array {
+        dense: Vec<GraphValue>,
+        associative: Vec<(String, GraphValue)>,
+    },
+    /// A date, in milliseconds since the Unix epoch.
+    Date(f64),
+    ByteArray(Vec<u8>),
+    Xml(String),
+    /// A vector or dictionary, which can be read but not yet deserialized.
+    Unsupported,
+}
+
+/// A set of AMF3 objects that may refer to each other, or to themselves.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ObjectGraph {
+    objects: Vec<GraphObject>,
+}
+
+impl ObjectGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a placeholder object, to be filled in with `set` once its contents are known.
+    ///
+    /// This allows the contents of an object to refer to the object itself.
+    pub fn reserve(&mut self) -> ObjectId {
+        self.add(GraphObject::Unsupported)
+    }
+
+    pub fn add(&mut self, object: GraphObject) -> ObjectId {
+        self.objects.push(object);
+        ObjectId(self.objects.len() - 1)
+    }
+
+    pub fn set(&mut self, id: ObjectId, object: GraphObject) {
+        self.objects[id.0] = object;
+    }
+
+    pub fn get(&self, id: ObjectId) -> &GraphObject {
+        &self.objects[id.0]
+    }
+}
+
+const UNDEFINED_MARKER: u8 = 0x00;
+const NULL_MARKER: u8 = 0x01;
+const FALSE_MARKER: u8 = 0x02;
+const TRUE_MARKER: u8 = 0x03;
+const INTEGER_MARKER: u8 = 0x04;
+const DOUBLE_MARKER: u8 = 0x05;
+const STRING_MARKER: u8 = 0x06;
+const XML_DOCUMENT_MARKER: u8 = 0x07;
+const DATE_MARKER: u8 = 0x08;
+const ARRAY_MARKER: u8 = 0x09;
+const OBJECT_MARKER: u8 = 0x0a;
+const XML_MARKER: u8 = 0x0b;
+const BYTE_ARRAY_MARKER: u8 = 0x0c;
+const VECTOR_INT_MARKER: u8 = 0x0d;
+const VECTOR_UINT_MARKER: u8 = 0x0e;
+const VECTOR_DOUBLE_MARKER: u8 = 0x0f;
+const VECTOR_OBJECT_MARKER: u8 = 0x10;
+const DICTIONARY_MARKER: u8 = 0x11;
+
+/// Encode an AMF3 value along with the objects of `graph` that it refers to.
+pub fn encode_graph(graph: &ObjectGraph, value: &GraphValue) -> Vec<u8> {
+    let mut writer = Amf3Writer {
+        graph,
+        output: Vec::new(),
+        strings: FnvHashMap::default(),
+        objects: FnvHashMap::default(),
+        traits: FnvHashMap::default(),
+    };
+    writer.write_value(value);
+    writer.output
+}
+
+/// Decode an AMF3 value, returning it along with the objects it refers to and any unread bytes.
+pub fn decode_graph(bytes: &[u8]) -> Option<(&[u8], ObjectGraph, GraphValue)> {
+    let mut reader = Amf3Reader {
+        bytes,
+        graph: ObjectGraph::new(),
+        strings: Vec::new(),
+        objects: Vec::new(),
+        traits: Vec::new(),
+    };
+    let value = reader.read_value()?;
+    Some((reader.bytes, reader.graph, value))
+}
+
+struct Amf3Writer<'a> {
+    graph: &'a ObjectGraph,
+    output: Vec<u8>,
+    strings: FnvHashMap<&'a str, u32>,
+    objects: FnvHashMap<ObjectId, u32>,
+    /// Traits of the objects written so far, by class name. All objects are written as dynamic.
+    traits: FnvHashMap<&'a str, u32>,
+}
+
+impl<'a> Amf3Writer<'a> {
+    fn write_u29(&mut self, value: u32) {
+        let value = value & 0x1fff_ffff;
+        if value < 0x80 {
+            self.output.push(value as u8);
+        } else if value < 0x4000 {
+            self.output
+                .extend_from_slice(&[(value >> 7) as u8 | 0x80, (value & 0x7f) as u8]);
+        } else if value < 0x20_0000 {
+            self.output.extend_from_slice(&[
+                (value >> 14) as u8 | 0x80,
+                ((value >> 7) & 0x7f) as u8 | 0x80,
+                (value & 0x7f) as u8,
+            ]);
+        } else {
+            self.output.extend_from_slice(&[
+                (value >> 22) as u8 | 0x80,
+                ((value >> 15) & 0x7f) as u8 | 0x80,
+                ((value >> 8) & 0x7f) as u8 | 0x80,
+                value as u8,
+            ]);
+        }
+    }
+
+    /// Writes a string, or a reference to it if it was written before. Empty strings are
+    /// never referenced.
+    fn write_string(&mut self, string: &'a str) {
+        if let Some(&index) = self.strings.get(string) {
+            self.write_u29(index << 1);
+        } else {
+            if !string.is_empty() {
+                let index = self.strings.len() as u32;
+                self.strings.insert(string, index);
+            }
+            self.write_u29((string.len() as u32) << 1 | 1);
+            self.output.extend_from_slice(string.as_bytes());
+        }
+    }
+
+    fn write_value(&mut self, value: &'a GraphValue) {
+        match value {
+            GraphValue::Undefined => self.output.push(UNDEFINED_MARKER),
+            GraphValue::Null => self.output.push(NULL_MARKER),
+            GraphValue::Bool(false) => self.output.push(FALSE_MARKER),
+            GraphValue::Bool(true) => self.output.push(TRUE_MARKER),
+            GraphValue::Integer(value) if (-(1 << 28)..(1 << 28)).contains(value) => {
+                self.output.push(INTEGER_MARKER);
+                self.write_u29(*value as u32);
+            }
+            GraphValue::Integer(value) => self.write_number(f64::from(*value)),
+            GraphValue::Number(value) => self.write_number(*value),
+            GraphValue::String(value) => {
+                self.output.push(STRING_MARKER);
+                self.write_string(value);
+            }
+            GraphValue::Object(id) => self.write_object(*id),
+        }
+    }
+
+    fn write_number(&mut self, value: f64) {
+        self.output.push(DOUBLE_MARKER);
+        self.output.extend_from_slice(&value.to_be_bytes());
+    }
+
+    fn write_object(&mut self, id: ObjectId) {
+        let graph = self.graph;
+        let object = graph.get(id);
+        let marker = match object {
+            GraphObject::Object { .. } => OBJECT_MARKER,
+            GraphObject::Array { .. } => ARRAY_MARKER,
+            GraphObject::Date(_) => DATE_MARKER,
+            GraphObject::ByteArray(_) => BYTE_ARRAY_MARKER,
+            GraphObject::Xml(_) => XML_MARKER,
+            GraphObject::Unsupported => {
+                self.output.push(UNDEFINED_MARKER);
+                return;
+            }
+        };
+        self.output.push(marker);
+
+        if let Some(&index) = self.objects.get(&id) {
+            self.write_u29(index << 1);
+            return;
+        }
+        // The object is added to the reference table before its contents are written,
+        // so that they can refer back to it.
+        let index = self.objects.len() as u32;
+        self.objects.insert(id, index);
+
+        match object {
+            GraphObject::Object {
+                class_name,
+                properties,
+            } => {
+                if let Some(&index) = self.traits.get(class_name.as_str()) {
+                    self.write_u29(index << 2 | 0b01);
+                } else {
+                    let index = self.traits.len() as u32;
+                    self.traits.insert(class_name, index);
+                    // Inline traits of a dynamic class without any sealed properties.
+                    self.write_u29(0b1011);
+                    self.write_string(class_name);
+                }
+                for (name, value) in properties {
+                    self.write_string(name);
+                    self.write_value(value);
+                }
+                self.write_string("");
+            }
+            GraphObject::Array { dense, associative } => {
+                self.write_u29((dense.len() as u32) << 1 | 1);
+                for (name, value) in associative {
+                    self.write_string(name);
+                    self.write_value(value);
+                }
+                self.write_string("");
+                for value in dense {
+                    self.write_value(value);
+                }
+            }
+            GraphObject::Date(time) => {
+                self.write_u29(1);
+                self.output.extend_from_slice(&time.to_be_bytes());
+            }
+            GraphObject::ByteArray(bytes) => {
+                self.write_u29((bytes.len() as u32) << 1 | 1);
+                self.output.extend_from_slice(bytes);
+            }
+            GraphObject::Xml(xml) => {
+                self.write_u29((xml.len() as u32) << 1 | 1);
+                self.output.extend_from_slice(xml.as_bytes());
+            }
+            GraphObject::Unsupported => unreachable!(),
+        }
+    }
+}
+
+struct Traits {
+    class_name: String,
+    sealed: Vec<String>,
+    is_dynamic: bool,
+}
+
+struct Amf3Reader<'a> {
+    bytes: &'a [u8],
+    graph: ObjectGraph,
+    strings: Vec<String>,
+    objects: Vec<ObjectId>,
+    traits: Vec<Traits>,
+}
+
+impl<'a> Amf3Reader<'a> {
+    fn read_bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        if len > self.bytes.len() {
+            return None;
+        }
+        let (bytes, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Some(bytes)
+    }
+
+    fn read_u8(&mut self) -> Option<u8> {
+        Some(self.read_bytes(1)?[0])
+    }
+
+    fn read_f64(&mut self) -> Option<f64> {
+        Some(f64::from_be_bytes(self.read_bytes(8)?.try_into().ok()?))
+    }
+
+    fn read_u29(&mut self) -> Option<u32> {
+        let mut value = 0;
+        for _ in 0..3 {
+            let byte = self.read_u8()?;
+            value = value << 7 | u32::from(byte & 0x7f);
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+        Some(value << 8 | u32::from(self.read_u8()?))
+    }
+
+    /// Reads the header of a value that can be sent by reference, returning either the
+    /// referenced index or the inline length.
+    fn read_reference(&mut self) -> Option<Result<usize, usize>> {
+        let header = self.read_u29()?;
+        let value = (header >> 1) as usize;
+        Some(if header & 1 == 0 {
+            Ok(value)
+        } else {
+            Err(value)
+        })
+    }
+
+    fn read_string(&mut self) -> Option<String> {
+        match self.read_reference()? {
+            Ok(index) => self.strings.get(index).cloned(),
+            Err(len) => {
+                let string = String::from_utf8_lossy(self.read_bytes(len)?).into_owned();
+                if !string.is_empty() {
+                    self.strings.push(string.clone());
+                }
+                Some(string)
+            }
+        }
+    }
+
+    fn read_value(&mut self) -> Option<GraphValue> {
+        Some(match self.read_u8()? {
+            UNDEFINED_MARKER => GraphValue::Undefined,
+            NULL_MARKER => GraphValue::Null,
+            FALSE_MARKER => GraphValue::Bool(false),
+            TRUE_MARKER => GraphValue::Bool(true),
+            INTEGER_MARKER => {
+                // Sign-extend the 29-bit integer.
+                let value = self.read_u29()?;
+                GraphValue::Integer(((value << 3) as i32) >> 3)
+            }
+            DOUBLE_MARKER => GraphValue::Number(self.read_f64()?),
+            STRING_MARKER => GraphValue::String(self.read_string()?),
+            marker => GraphValue::Object(self.read_object(marker)?),
+        })
+    }
+
+    fn read_object(&mut self, marker: u8) -> Option<ObjectId> {
+        let len = match self.read_reference()? {
+            Ok(index) => return self.objects.get(index).copied(),
+            Err(len) => len,
+        };
+        // Register the object before reading its contents, which may refer back to it.
+        let id = self.graph.reserve();
+        self.objects.push(id);
+
+        let object = match marker {
+            OBJECT_MARKER => self.read_object_body(len)?,
+            ARRAY_MARKER => {
+                let mut associative = Vec::new();
+                loop {
+                    let name = self.read_string()?;
+                    if name.is_empty() {
+                        break;
+                    }
+                    associative.push((name, self.read_value()?));
+                }
+                let dense = (0..len).map(|_| self.read_value()).collect::<Option<_>>()?;
+                GraphObject::Array { dense, associative }
+            }
+            DATE_MARKER => GraphObject::Date(self.read_f64()?),
+            BYTE_ARRAY_MARKER => GraphObject::ByteArray(self.read_bytes(len)?.to_vec()),
+            XML_MARKER | XML_DOCUMENT_MARKER => {
+                GraphObject::Xml(String::from_utf8_lossy(self.read_bytes(len)?).into_owned())
+            }
+            VECTOR_INT_MARKER | VECTOR_UINT_MARKER => {
+                self.read_bytes(1 + len.checked_mul(4)?)?;
+                GraphObject::Unsupported
+            }
+            VECTOR_DOUBLE_MARKER => {
+                self.read_bytes(1 + len.checked_mul(8)?)?;
+                GraphObject::Unsupported
+            }
+            VECTOR_OBJECT_MARKER => {
+                self.read_u8()?;
+                self.read_string()?;
+                for _ in 0..len {
+                    self.read_value()?;
+                }
+                GraphObject::Unsupported
+            }
+            DICTIONARY_MARKER => {
+                self.read_u8()?;
+                for _ in 0..len {
+                    self.read_value()?;
+                    self.read_value()?;
+                }
+                GraphObject::Unsupported
+            }
+            _ => return None,
+        };
+        self.graph.set(id, object);
+        Some(id)
+    }
+
+    /// Reads the traits and properties of an object, given the rest of its header.
+    fn read_object_body(&mut self, header: usize) -> Option<GraphObject> {
+        let traits_index = if header & 1 == 0 {
+            header >> 1
+        } else if header & 0b10 != 0 {
+            // Externalizable objects can only be read by their class.
+            return None;
+        } else {
+            let is_dynamic = header & 0b100 != 0;
+            let class_name = self.read_string()?;
+            let sealed = (0..header >> 3)
+                .map(|_| self.read_string())
+                .collect::<Option<_>>()?;
+            self.traits.push(Traits {
+                class_name,
+                sealed,
+                is_dynamic,
+            });
+            self.traits.len() - 1
+        };
+
+        let mut properties = Vec::new();
+        for index in 0..self.traits.get(traits_index)?.sealed.len() {
+            let name = self.traits[traits_index].sealed[index].clone();
+            properties.push((name, self.read_value()?));
+        }
+        if self.traits[traits_index].is_dynamic {
+            loop {
+                let name = self.read_string()?;
+                if name.is_empty() {
+                    break;
+                }
+                properties.push((name, self.read_value()?));
+            }
+        }
+        Some(GraphObject::Object {
+            class_name: self.traits[traits_index].class_name.clone(),
+            properties,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use enumset::EnumSet;
+    use flash_lso::types::{Attribute, ClassDefinition};
+
+    fn round_trip(value: Value, amf_version: AMFVersion) {
+        let bytes = encode_value(value.clone(), amf_version).unwrap();
+        let (rest, decoded) = decode_value(&bytes, amf_version).unwrap();
+        assert!(
+            rest.is_empty(),
+            "{value:?} left {} bytes unread",
+            rest.len()
+        );
+        assert_eq!(decoded, value);
+    }
+
+    fn scalars() -> Vec<Value> {
+        vec![
+            Value::Number(1.5),
+            Value::Number(-0.25),
+            Value::Bool(true),
+            Value::Bool(false),
+            Value::String("".to_string()),
+            Value::String("ruffle".to_string()),
+            Value::Null,
+            Value::Undefined,
+        ]
+    }
+
+    #[test]
+    fn amf0_scalars() {
+        for value in scalars() {
+            round_trip(value, AMFVersion::AMF0);
+        }
+    }
+
+    #[test]
+    fn amf3_scalars() {
+        for value in scalars() {
+            round_trip(value, AMFVersion::AMF3);
+        }
+        round_trip(Value::Integer(0), AMFVersion::AMF3);
+        round_trip(Value::Integer(-1), AMFVersion::AMF3);
+        round_trip(Value::Integer((1 << 28) - 1), AMFVersion::AMF3);
+    }
+
+    #[test]
+    fn amf0_arrays() {
+        round_trip(
+            Value::StrictArray(vec![Value::Number(1.0), Value::String("a".to_string())]),
+            AMFVersion::AMF0,
+        );
+    }
+
+    #[test]
+    fn amf3_arrays_and_bytes() {
+        round_trip(
+            Value::StrictArray(vec![Value::Integer(1), Value::String("a".to_string())]),
+            AMFVersion::AMF3,
+        );
+        round_trip(Value::ByteArray(vec![0, 1, 2, 255]), AMFVersion::AMF3);
+        round_trip(Value::Date(1_000_000.0, None), AMFVersion::AMF3);
+    }
+
+    #[test]
+    fn amf3_objects() {
+        let object = Value::Object(
+            vec![
+                Element::new("x", Value::Number(1.0)),
+                Element::new("name", Value::String("clip".to_string())),
+            ],
+            Some(ClassDefinition {
+                name: "".to_string(),
+                attributes: EnumSet::only(Attribute::Dynamic),
+                static_properties: Vec::new(),
+            }),
+        );
+        round_trip(object, AMFVersion::AMF3);
+    }
+
+    #[test]
+    fn amf3_references() {
+        // Repeated strings are written once and then referenced by index.
+        let repeated = Value::String("repeated".to_string());
+        let array = Value::StrictArray(vec![repeated.clone(), repeated.clone(), repeated]);
+        let bytes = encode_value(array.clone(), AMFVersion::AMF3).unwrap();
+        assert_eq!(
+            bytes
+                .windows(b"repeated".len())
+                .filter(|w| *w == b"repeated")
+                .count(),
+            1
+        );
+        round_trip(array, AMFVersion::AMF3);
+    }
+
+    #[test]
+    fn amf3_cyclic_references() {
+        // An object that refers to itself, inside an array that refers to it twice.
+        let mut graph = ObjectGraph::new();
+        let object = graph.reserve();
+        graph.set(
+            object,
+            GraphObject::Object {
+                class_name: "".to_string(),
+                properties: vec![
+                    ("self".to_string(), GraphValue::Object(object)),
+                    ("x".to_string(), GraphValue::Integer(-1)),
+                ],
+            },
+        );
+        let array = graph.add(GraphObject::Array {
+            dense: vec![GraphValue::Object(object), GraphValue::Object(object)],
+            associative: vec![],
+        });
+
+        let bytes = encode_graph(&graph, &GraphValue::Object(array));
+        // The object is written once, then referenced by its index in the object table.
+        assert_eq!(
+            bytes
+                .windows(2)
+                .filter(|w| *w == [OBJECT_MARKER, 0x02])
+                .count(),
+            2
+        );
+
+        let (rest, decoded, value) = decode_graph(&bytes).unwrap();
+        assert!(rest.is_empty());
+        let elements = match value {
+            GraphValue::Object(id) => match decoded.get(id) {
+                GraphObject::Array { dense, associative } if associative.is_empty() => {
+                    dense.clone()
+                }
+                other => panic!("Expected a dense array, got {other:?}"),
+            },
+            other => panic!("Expected an array, got {other:?}"),
+        };
+        assert_eq!(elements.len(), 2);
+        assert_eq!(elements[0], elements[1]);
+        let object = match elements[0] {
+            GraphValue::Object(id) => id,
+            ref other => panic!("Expected an object, got {other:?}"),
+        };
+        assert_eq!(
+            decoded.get(object),
+            &GraphObject::Object {
+                class_name: "".to_string(),
+                properties: vec![
+                    ("self".to_string(), GraphValue::Object(object)),
+                    ("x".to_string(), GraphValue::Integer(-1)),
+                ],
+            }
+        );
+    }
+}
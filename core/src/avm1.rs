@@ -28,9 +28,10 @@ pub use debug::VariableDumper;
 pub use error::Error;
 pub use function::ExecutionReason;
 pub use globals::context_menu::make_context_menu_state;
+pub use globals::local_connection::decode_arguments as decode_local_connection_arguments;
 pub use globals::shared_object::flush;
 pub use globals::sound::start as start_sound;
-pub use globals::system::SystemProperties;
+pub use globals::system::{SandboxType, SystemProperties};
 pub use object::array_object::ArrayObject;
 pub use object::script_object::ScriptObject;
 pub use object::sound_object::SoundObject;
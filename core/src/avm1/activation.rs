@@ -16,7 +16,6 @@ use crate::vminterface::Instantiator;
 use crate::{avm_error, avm_warn};
 use gc_arena::{Gc, GcCell, MutationContext};
 use indexmap::IndexMap;
-use instant::Instant;
 use rand::Rng;
 use ruffle_render::bounding_box::BoundingBox;
 use smallvec::SmallVec;
@@ -1100,9 +1099,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             *self.context.time_offset += 1;
         }
 
-        let time = Instant::now()
-            .duration_since(self.context.start_time)
-            .as_millis() as u32;
+        let time = self.context.get_timer();
         let result = time.wrapping_add(*self.context.time_offset);
         self.context.avm1.push(result.into());
         Ok(FrameControl::Continue)
@@ -1124,26 +1121,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         // TODO: Use `StageObject::get_level_by_path`.
         if target.starts_with("_level") && target.len() > 6 {
             match target[6..].parse::<i32>() {
-                Ok(level_id) => {
-                    let level = self.resolve_level(level_id);
-
-                    if url.is_empty() {
-                        //Blank URL on movie loads = unload!
-                        if let Some(mut mc) = level.as_movie_clip() {
-                            mc.replace_with_movie(&mut self.context, None, None)
-                        }
-                    } else {
-                        let future = self.context.load_manager.load_movie_into_clip(
-                            self.context.player.clone(),
-                            level,
-                            Request::get(url),
-                            None,
-                            None,
-                            None,
-                        );
-                        self.context.navigator.spawn_future(future);
-                    }
-                }
+                Ok(level_id) => self.load_movie_into_level(level_id, url),
                 Err(e) => avm_warn!(
                     self,
                     "Couldn't parse level id {} for action_get_url: {}",
@@ -2057,7 +2035,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         };
         self.context
             .stage
-            .set_quality(self.context.gc_context, new_quality);
+            .set_quality(self.context.gc_context, self.context.renderer, new_quality);
         self.context
             .stage
             .set_use_bitmap_downsampling(self.context.gc_context, use_bitmap_downsamping);
@@ -2692,6 +2670,30 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         }
     }
 
+    /// Loads the movie at `url` into the given level, creating the level if necessary.
+    ///
+    /// An empty URL unloads the level instead.
+    pub fn load_movie_into_level(&mut self, level_id: i32, url: String) {
+        let level = self.resolve_level(level_id);
+
+        if url.is_empty() {
+            //Blank URL on movie loads = unload!
+            if let Some(mut mc) = level.as_movie_clip() {
+                mc.replace_with_movie(&mut self.context, None, None)
+            }
+        } else {
+            let future = self.context.load_manager.load_movie_into_clip(
+                self.context.player.clone(),
+                level,
+                Request::get(url),
+                None,
+                None,
+                None,
+            );
+            self.context.navigator.spawn_future(future);
+        }
+    }
+
     /// The current target clip of the executing code.
     /// Actions that affect `root` after an invalid `tellTarget` will use this.
     ///
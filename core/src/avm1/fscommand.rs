@@ -3,7 +3,8 @@
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
 use crate::avm_warn;
-use crate::string::WStr;
+use crate::display_object::{StageDisplayState, StageQuality, StageScaleMode};
+use crate::string::{FromWStr, WStr};
 
 /// Parse an FSCommand URL.
 pub fn parse(url: &WStr) -> Option<&WStr> {
@@ -20,11 +21,58 @@ pub fn parse(url: &WStr) -> Option<&WStr> {
     }
 }
 
+/// Parse the boolean argument of an FSCommand.
+///
+/// Flash Player treats `"true"` (case-insensitively) as true, and everything else as false.
+fn parse_bool(args: &WStr) -> bool {
+    args.eq_ignore_case(WStr::from_units(b"true"))
+}
+
 pub fn handle<'gc>(
     command: &WStr,
     args: &WStr,
     activation: &mut Activation<'_, 'gc>,
 ) -> Result<(), Error<'gc>> {
+    let stage = activation.context.stage;
+
+    // Some commands also change the state of the player itself; they are still
+    // forwarded to the embedder afterwards, which may want to react to them too.
+    let is_player_command = if command.eq_ignore_case(WStr::from_units(b"fullscreen")) {
+        let display_state = if parse_bool(args) {
+            StageDisplayState::FullScreen
+        } else {
+            StageDisplayState::Normal
+        };
+        stage.set_display_state(&mut activation.context, display_state);
+        true
+    } else if command.eq_ignore_case(WStr::from_units(b"allowscale")) {
+        let scale_mode = if parse_bool(args) {
+            StageScaleMode::ShowAll
+        } else {
+            StageScaleMode::NoScale
+        };
+        stage.set_scale_mode(&mut activation.context, scale_mode);
+        true
+    } else if command.eq_ignore_case(WStr::from_units(b"showmenu")) {
+        stage.set_show_menu(&mut activation.context, parse_bool(args));
+        true
+    } else if command.eq_ignore_case(WStr::from_units(b"quality")) {
+        if let Ok(quality) = StageQuality::from_wstr(args) {
+            stage.set_quality(
+                activation.context.gc_context,
+                activation.context.renderer,
+                quality,
+            );
+        } else {
+            avm_warn!(activation, "FSCommand quality: unknown quality {}", args);
+        }
+        true
+    } else {
+        // `trapallkeys` and everything else is only meaningful to the embedder
+        // (e.g. the standalone projector or the hosting web page).
+        false
+    };
+
     let command = command.to_utf8_lossy();
     let args = args.to_utf8_lossy();
 
@@ -32,6 +80,7 @@ pub fn handle<'gc>(
         .context
         .external_interface
         .invoke_fs_command(&command, &args)
+        && !is_player_command
     {
         avm_warn!(activation, "Unhandled FSCommand: {}", command);
     }
@@ -27,6 +27,7 @@ pub(crate) mod context_menu_item;
 pub mod convolution_filter;
 pub(crate) mod date;
 pub mod displacement_map_filter;
+mod display_object;
 pub mod drop_shadow_filter;
 pub(crate) mod error;
 mod external_interface;
@@ -36,12 +37,14 @@ pub mod gradient_bevel_filter;
 pub mod gradient_glow_filter;
 mod key;
 mod load_vars;
-mod local_connection;
+pub(crate) mod local_connection;
 mod math;
 mod matrix;
 pub(crate) mod mouse;
 pub(crate) mod movie_clip;
 mod movie_clip_loader;
+mod net_connection;
+mod net_stream;
 pub(crate) mod number;
 mod object;
 mod point;
@@ -74,6 +77,7 @@ const GLOBAL_DECLS: &[Declaration] = declare_properties! {
     "clearTimeout" => method(clear_timeout; DONT_ENUM);
     "setTimeout" => method(set_timeout; DONT_ENUM);
     "updateAfterEvent" => method(update_after_event; DONT_ENUM);
+    "getVersion" => method(system_capabilities::get_version; DONT_ENUM);
     "escape" => method(escape; DONT_ENUM);
     "unescape" => method(unescape; DONT_ENUM);
     "NaN" => property(get_nan; DONT_ENUM);
@@ -498,6 +502,7 @@ pub struct SystemPrototypes<'gc> {
     pub context_menu_item_constructor: Object<'gc>,
     pub bitmap_filter: Object<'gc>,
     pub bitmap_filter_constructor: Object<'gc>,
+    pub blur_filter_constructor: Object<'gc>,
     pub glow_filter: Object<'gc>,
     pub glow_filter_constructor: Object<'gc>,
     pub drop_shadow_filter: Object<'gc>,
@@ -557,6 +562,9 @@ pub fn create_globals<'gc>(
     let load_vars_proto = load_vars::create_proto(gc_context, object_proto, function_proto);
     let local_connection_proto =
         local_connection::create_proto(gc_context, object_proto, function_proto);
+    let net_connection_proto =
+        net_connection::create_proto(gc_context, object_proto, function_proto);
+    let net_stream_proto = net_stream::create_proto(gc_context, object_proto, function_proto);
     let matrix_proto = matrix::create_proto(gc_context, object_proto, function_proto);
     let point_proto = point::create_proto(gc_context, object_proto, function_proto);
     let rectangle_proto = rectangle::create_proto(gc_context, object_proto, function_proto);
@@ -636,6 +644,20 @@ pub fn create_globals<'gc>(
         function_proto,
         local_connection_proto,
     );
+    let net_connection = FunctionObject::constructor(
+        gc_context,
+        Executable::Native(net_connection::constructor),
+        constructor_to_fn!(net_connection::constructor),
+        function_proto,
+        net_connection_proto,
+    );
+    let net_stream = FunctionObject::constructor(
+        gc_context,
+        Executable::Native(net_stream::constructor),
+        constructor_to_fn!(net_stream::constructor),
+        function_proto,
+        net_stream_proto,
+    );
     let movie_clip = FunctionObject::constructor(
         gc_context,
         Executable::Native(movie_clip::constructor),
@@ -956,6 +978,18 @@ pub fn create_globals<'gc>(
         movie_clip_loader.into(),
         Attribute::DONT_ENUM,
     );
+    globals.define_value(
+        gc_context,
+        "NetConnection",
+        net_connection.into(),
+        Attribute::DONT_ENUM,
+    );
+    globals.define_value(
+        gc_context,
+        "NetStream",
+        net_stream.into(),
+        Attribute::DONT_ENUM,
+    );
     globals.define_value(gc_context, "Sound", sound.into(), Attribute::DONT_ENUM);
     globals.define_value(
         gc_context,
@@ -1139,6 +1173,7 @@ pub fn create_globals<'gc>(
             context_menu_item_constructor: context_menu_item,
             bitmap_filter: bitmap_filter_proto,
             bitmap_filter_constructor: bitmap_filter,
+            blur_filter_constructor: blur_filter,
             glow_filter: glow_filter_proto,
             glow_filter_constructor: glow_filter,
             drop_shadow_filter: drop_shadow_filter_proto,
@@ -1,6 +1,7 @@
 //! flash.display.BitmapData object
 
 use super::matrix::object_to_matrix;
+use super::movie_clip::object_to_rectangle;
 use crate::avm1::function::{Executable, FunctionObject};
 use crate::avm1::globals::color_transform::ColorTransformObject;
 use crate::avm1::object::bitmap_data::BitmapDataObject;
@@ -521,9 +522,11 @@ pub fn draw<'gc>(
                 }
             }
 
-            if args.get(4).is_some() {
-                tracing::warn!("BitmapData.draw with clip rect - not implemented")
-            }
+            let clip_rect = match args.get(4) {
+                Some(Value::Object(clip_rect)) => object_to_rectangle(activation, *clip_rect)?,
+                _ => None,
+            };
+
             let smoothing = args
                 .get(5)
                 .unwrap_or(&false.into())
@@ -557,7 +560,7 @@ pub fn draw<'gc>(
                 },
                 smoothing,
                 blend_mode,
-                None,
+                clip_rect,
                 &mut activation.context,
             );
             return Ok(Value::Undefined);
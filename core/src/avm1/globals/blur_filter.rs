@@ -14,6 +14,16 @@ pub struct BlurFilterObject {
     quality: i32,
 }
 
+impl BlurFilterObject {
+    pub fn filter(&self) -> swf::BlurFilter {
+        swf::BlurFilter {
+            blur_x: swf::Fixed16::from_f64(self.blur_x),
+            blur_y: swf::Fixed16::from_f64(self.blur_y),
+            num_passes: self.quality as u8,
+        }
+    }
+}
+
 macro_rules! blur_filter_method {
     ($index:literal) => {
         |activation, this, args| method(activation, this, args, $index)
@@ -2,6 +2,7 @@
 
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
+use crate::avm1::globals::display_object;
 use crate::avm1::property_decl::{define_properties_on, Declaration};
 use crate::avm1::{globals, Object, ScriptObject, TObject, Value};
 use crate::display_object::{Avm1Button, TDisplayObject};
@@ -42,6 +43,7 @@ const PROTO_DECLS: &[Declaration] = declare_properties! {
     "getDepth" => method(globals::get_depth; DONT_ENUM | DONT_DELETE | READ_ONLY | VERSION_6);
     "useHandCursor" => property(button_getter!(use_hand_cursor), button_setter!(set_use_hand_cursor));
     "blendMode" => property(button_getter!(blend_mode), button_setter!(set_blend_mode); DONT_DELETE | DONT_ENUM);
+    "filters" => property(button_getter!(filters), button_setter!(set_filters); DONT_DELETE | DONT_ENUM | VERSION_8);
 };
 
 pub fn create_proto<'gc>(
@@ -97,6 +99,21 @@ fn set_use_hand_cursor<'gc>(
     Ok(())
 }
 
+fn filters<'gc>(
+    this: Avm1Button<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    display_object::filters(this.into(), activation)
+}
+
+fn set_filters<'gc>(
+    this: Avm1Button<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+    value: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    display_object::set_filters(this.into(), activation, value)
+}
+
 fn blend_mode<'gc>(
     this: Avm1Button<'gc>,
     activation: &mut Activation<'_, 'gc>,
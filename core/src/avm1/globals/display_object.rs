@@ -0,0 +1,55 @@
+//! Properties shared by all AVM1 display objects
+
+use crate::avm1::activation::Activation;
+use crate::avm1::error::Error;
+use crate::avm1::object::NativeObject;
+use crate::avm1::{ArrayObject, TObject, Value};
+use crate::display_object::{DisplayObject, TDisplayObject};
+use swf::Filter;
+
+/// Implements the `filters` getter, returning a fresh array of filter objects.
+pub fn filters<'gc>(
+    this: DisplayObject<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let constructor = activation.context.avm1.prototypes().blur_filter_constructor;
+    let mut filters = Vec::new();
+    for filter in this.bitmap_filters() {
+        // TODO: Support the remaining filter types.
+        if let Filter::BlurFilter(blur) = filter {
+            let args = [
+                blur.blur_x.to_f64().into(),
+                blur.blur_y.to_f64().into(),
+                blur.num_passes.into(),
+            ];
+            filters.push(constructor.construct(activation, &args)?);
+        }
+    }
+    Ok(ArrayObject::new(
+        activation.context.gc_context,
+        activation.context.avm1.prototypes().array,
+        filters,
+    )
+    .into())
+}
+
+/// Implements the `filters` setter, replacing the display object's filters.
+pub fn set_filters<'gc>(
+    this: DisplayObject<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+    value: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    // No-op if value is not an array of filters.
+    if let Value::Object(array) = value {
+        let mut filters = Vec::new();
+        for i in 0..array.length(activation)? {
+            if let Value::Object(filter) = array.get_element(activation, i) {
+                if let NativeObject::BlurFilter(blur) = filter.native() {
+                    filters.push(Filter::BlurFilter(Box::new(blur.read().filter())));
+                }
+            }
+        }
+        this.set_bitmap_filters(activation.context.gc_context, filters);
+    }
+    Ok(())
+}
@@ -1,17 +1,104 @@
 //! LocalConnection class
 
+use crate::amf;
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
+use crate::avm1::globals::shared_object::{deserialize_value, serialize_value};
 use crate::avm1::property_decl::{define_properties_on, Declaration};
 use crate::avm1::{Object, ScriptObject, Value};
+use crate::backend::local_connection::LocalConnectionMessage;
 use crate::display_object::TDisplayObject;
+use crate::local_connection::LocalConnections;
 use crate::string::AvmString;
+use flash_lso::types::{AMFVersion, Value as AmfValue};
 use gc_arena::MutationContext;
 
 const PROTO_DECLS: &[Declaration] = declare_properties! {
     "domain" => method(domain; DONT_DELETE | READ_ONLY);
+    "connect" => method(connect; DONT_ENUM | DONT_DELETE);
+    "send" => method(send; DONT_ENUM | DONT_DELETE);
+    "close" => method(close; DONT_ENUM | DONT_DELETE);
 };
 
+/// Encodes the arguments of a `LocalConnection.send` call as a sequence of AMF0 values.
+fn encode_arguments<'gc>(activation: &mut Activation<'_, 'gc>, args: &[Value<'gc>]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for &arg in args {
+        let value = serialize_value(activation, arg).unwrap_or(AmfValue::Undefined);
+        if let Some(encoded) = amf::encode_value(value, AMFVersion::AMF0) {
+            bytes.extend(encoded);
+        }
+    }
+    bytes
+}
+
+/// Decodes the arguments of a received `LocalConnection` message.
+pub fn decode_arguments<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    bytes: &[u8],
+) -> Vec<Value<'gc>> {
+    let mut args = Vec::new();
+    let mut remaining = bytes;
+    while !remaining.is_empty() {
+        match amf::decode_value(remaining, AMFVersion::AMF0) {
+            Some((rest, value)) => {
+                args.push(deserialize_value(activation, &value));
+                remaining = rest;
+            }
+            None => {
+                tracing::warn!("LocalConnection: Unable to decode message arguments");
+                break;
+            }
+        }
+    }
+    args
+}
+
+pub fn connect<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let name = match args.get(0) {
+        Some(Value::String(name)) if !name.is_empty() => name.to_string(),
+        _ => return Ok(false.into()),
+    };
+
+    Ok(LocalConnections::connect(&mut activation.context, name, this.into()).into())
+}
+
+pub fn send<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let (name, method_name) = match (args.get(0), args.get(1)) {
+        (Some(Value::String(name)), Some(Value::String(method_name)))
+            if !name.is_empty() && !method_name.is_empty() =>
+        {
+            (name.to_string(), method_name.to_string())
+        }
+        _ => return Ok(false.into()),
+    };
+
+    let arguments = encode_arguments(activation, &args[2..]);
+    let message = LocalConnectionMessage {
+        method_name,
+        arguments,
+    };
+    LocalConnections::send(&mut activation.context, this.into(), &name, message);
+    Ok(true.into())
+}
+
+pub fn close<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    LocalConnections::close(&mut activation.context, this.into());
+    Ok(Value::Undefined)
+}
+
 pub fn domain<'gc>(
     activation: &mut Activation<'_, 'gc>,
     _this: Object<'gc>,
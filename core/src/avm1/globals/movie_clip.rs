@@ -2,6 +2,7 @@
 
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
+use crate::avm1::globals::display_object;
 use crate::avm1::globals::matrix::gradient_object_to_matrix;
 use crate::avm1::globals::{self, AVM_DEPTH_BIAS, AVM_MAX_DEPTH};
 use crate::avm1::property_decl::{define_properties_on, Declaration};
@@ -87,6 +88,7 @@ const PROTO_DECLS: &[Declaration] = declare_properties! {
     "nextFrame" => method(mc_method!(next_frame); DONT_ENUM | DONT_DELETE);
     "play" => method(mc_method!(play); DONT_ENUM | DONT_DELETE);
     "prevFrame" => method(mc_method!(prev_frame); DONT_ENUM | DONT_DELETE);
+    "setDepth" => method(mc_method!(set_depth); DONT_ENUM | DONT_DELETE | VERSION_6);
     "setMask" => method(mc_method!(set_mask); DONT_ENUM | DONT_DELETE | VERSION_6);
     "startDrag" => method(mc_method!(start_drag); DONT_ENUM | DONT_DELETE);
     "stop" => method(mc_method!(stop); DONT_ENUM | DONT_DELETE);
@@ -110,7 +112,9 @@ const PROTO_DECLS: &[Declaration] = declare_properties! {
     "_lockroot" => property(mc_getter!(lock_root), mc_setter!(set_lock_root); DONT_DELETE | DONT_ENUM);
     "useHandCursor" => property(mc_getter!(use_hand_cursor), mc_setter!(set_use_hand_cursor); DONT_DELETE | DONT_ENUM);
     "blendMode" => property(mc_getter!(blend_mode), mc_setter!(set_blend_mode); DONT_DELETE | DONT_ENUM);
+    "filters" => property(mc_getter!(filters), mc_setter!(set_filters); DONT_DELETE | DONT_ENUM | VERSION_8);
     "scrollRect" => property(mc_getter!(scroll_rect), mc_setter!(set_scroll_rect); DONT_DELETE | DONT_ENUM | VERSION_8);
+    "scale9Grid" => property(mc_getter!(scale_9_grid), mc_setter!(set_scale_9_grid); DONT_DELETE | DONT_ENUM | VERSION_8);
 };
 
 /// Implements `MovieClip`
@@ -135,7 +139,7 @@ fn new_rectangle<'gc>(
     proto.construct(activation, args)
 }
 
-fn object_to_rectangle<'gc>(
+pub(crate) fn object_to_rectangle<'gc>(
     activation: &mut Activation<'_, 'gc>,
     object: Object<'gc>,
 ) -> Result<Option<Rectangle<Twips>>, Error<'gc>> {
@@ -183,6 +187,29 @@ fn set_scroll_rect<'gc>(
     Ok(())
 }
 
+fn scale_9_grid<'gc>(
+    this: MovieClip<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    match this.scale9_grid() {
+        Some(rectangle) => new_rectangle(activation, rectangle),
+        None => Ok(Value::Undefined),
+    }
+}
+
+fn set_scale_9_grid<'gc>(
+    this: MovieClip<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+    value: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    let rectangle = match value {
+        Value::Object(object) => object_to_rectangle(activation, object)?,
+        _ => None,
+    };
+    this.set_scale9_grid(activation.context.gc_context, rectangle);
+    Ok(())
+}
+
 #[allow(clippy::comparison_chain)]
 pub fn hit_test<'gc>(
     movie_clip: MovieClip<'gc>,
@@ -271,7 +298,7 @@ fn attach_bitmap<'gc>(
                     bitmap_data,
                     smoothing,
                 );
-                movie_clip.replace_at_depth(&mut activation.context, display_object.into(), depth);
+                attach_at_depth(movie_clip, activation, display_object.into(), depth);
                 display_object.post_instantiation(
                     &mut activation.context,
                     None,
@@ -632,6 +659,22 @@ fn clear<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Places a script-created child into `parent` at the given (biased) depth.
+///
+/// Unlike timeline placement, any child already occupying the depth is removed,
+/// even if it was itself created by script.
+fn attach_at_depth<'gc>(
+    mut parent: MovieClip<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+    child: DisplayObject<'gc>,
+    depth: Depth,
+) {
+    if let Some(existing) = parent.child_by_depth(depth) {
+        parent.remove_child(&mut activation.context, existing);
+    }
+    parent.replace_at_depth(&mut activation.context, child, depth);
+}
+
 fn attach_movie<'gc>(
     movie_clip: MovieClip<'gc>,
     activation: &mut Activation<'_, 'gc>,
@@ -667,7 +710,7 @@ fn attach_movie<'gc>(
     {
         // Set name and attach to parent.
         new_clip.set_name(activation.context.gc_context, new_instance_name);
-        movie_clip.replace_at_depth(&mut activation.context, new_clip, depth);
+        attach_at_depth(movie_clip, activation, new_clip, depth);
         let init_object = if let Some(Value::Object(init_object)) = init_object {
             Some(init_object.to_owned())
         } else {
@@ -714,7 +757,7 @@ fn create_empty_movie_clip<'gc>(
 
     // Set name and attach to parent.
     new_clip.set_name(activation.context.gc_context, new_instance_name);
-    movie_clip.replace_at_depth(&mut activation.context, new_clip.into(), depth);
+    attach_at_depth(movie_clip, activation, new_clip.into(), depth);
     new_clip.post_instantiation(&mut activation.context, None, Instantiator::Avm1, true);
 
     Ok(new_clip.object())
@@ -759,8 +802,9 @@ fn create_text_field<'gc>(
         activation.context.gc_context,
         instance_name.coerce_to_string(activation)?,
     );
-    movie_clip.replace_at_depth(
-        &mut activation.context,
+    attach_at_depth(
+        movie_clip,
+        activation,
         text_field,
         (depth as Depth).wrapping_add(AVM_DEPTH_BIAS),
     );
@@ -833,7 +877,7 @@ pub fn duplicate_movie_clip_with_bias<'gc>(
 
     // Set name and attach to parent.
     new_clip.set_name(activation.context.gc_context, new_instance_name);
-    parent.replace_at_depth(&mut activation.context, new_clip.into(), depth);
+    attach_at_depth(parent, activation, new_clip.into(), depth);
 
     // Copy display properties from previous clip to new clip.
     let matrix = *movie_clip.base().matrix();
@@ -1146,6 +1190,43 @@ fn swap_depths<'gc>(
     Ok(Value::Undefined)
 }
 
+fn set_depth<'gc>(
+    movie_clip: MovieClip<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let depth = if let Some(depth) = args.get(0) {
+        depth
+            .coerce_to_i32(activation)?
+            .wrapping_add(AVM_DEPTH_BIAS)
+    } else {
+        avm_error!(activation, "MovieClip.setDepth: Too few parameters");
+        return Ok(Value::Undefined);
+    };
+
+    if movie_clip.removed() || depth < 0 || depth > AVM_MAX_DEPTH {
+        return Ok(Value::Undefined);
+    }
+
+    let mut parent = if let Some(parent) = movie_clip.avm1_parent().and_then(|o| o.as_movie_clip())
+    {
+        parent
+    } else {
+        return Ok(Value::Undefined);
+    };
+
+    if depth != movie_clip.depth() {
+        // Unlike `swapDepths`, the previous occupant of the depth is removed.
+        if let Some(existing) = parent.child_by_depth(depth) {
+            parent.remove_child(&mut activation.context, existing);
+        }
+        parent.swap_at_depth(&mut activation.context, movie_clip.into(), depth);
+        movie_clip.set_transformed_by_script(activation.context.gc_context, true);
+    }
+
+    Ok(Value::Undefined)
+}
+
 fn local_to_global<'gc>(
     movie_clip: MovieClip<'gc>,
     activation: &mut Activation<'_, 'gc>,
@@ -1251,11 +1332,20 @@ pub fn get_url<'gc>(
         }
 
         let window = if let Some(window) = args.get(1) {
-            window.coerce_to_string(activation)?.to_string()
+            normalize_window(&window.coerce_to_string(activation)?.to_string())
         } else {
             "".into()
         };
 
+        // `_levelN` targets load the movie into that level instead of navigating.
+        if let Some(level_id) = window
+            .strip_prefix("_level")
+            .and_then(|level| level.parse::<i32>().ok())
+        {
+            activation.load_movie_into_level(level_id, url.to_string());
+            return Ok(Value::Undefined);
+        }
+
         let method = match args.get(2) {
             Some(Value::String(s)) => NavigationMethod::from_method_str(s),
             _ => None,
@@ -1271,6 +1361,20 @@ pub fn get_url<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Lowercases the reserved window names (`_blank`, `_self`, `_parent`, `_top`, `_levelN`),
+/// which Flash matches case-insensitively. Other window names are passed through as-is.
+fn normalize_window(window: &str) -> String {
+    let window = window.trim();
+    let lower = window.to_ascii_lowercase();
+    if matches!(lower.as_str(), "_blank" | "_self" | "_parent" | "_top")
+        || lower.starts_with("_level")
+    {
+        lower
+    } else {
+        window.to_string()
+    }
+}
+
 fn global_to_local<'gc>(
     movie_clip: MovieClip<'gc>,
     activation: &mut Activation<'_, 'gc>,
@@ -1464,6 +1568,21 @@ fn set_use_hand_cursor<'gc>(
     Ok(())
 }
 
+fn filters<'gc>(
+    this: MovieClip<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    display_object::filters(this.into(), activation)
+}
+
+fn set_filters<'gc>(
+    this: MovieClip<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+    value: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    display_object::set_filters(this.into(), activation, value)
+}
+
 fn blend_mode<'gc>(
     this: MovieClip<'gc>,
     activation: &mut Activation<'_, 'gc>,
@@ -0,0 +1,56 @@
+//! NetConnection class
+
+use crate::avm1::activation::Activation;
+use crate::avm1::error::Error;
+use crate::avm1::property::Attribute;
+use crate::avm1::property_decl::{define_properties_on, Declaration};
+use crate::avm1::{Object, ScriptObject, TObject, Value};
+use crate::avm_warn;
+use gc_arena::MutationContext;
+
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "connect" => method(connect; DONT_ENUM | DONT_DELETE);
+};
+
+/// Implements `NetConnection`
+pub fn constructor<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    this.define_value(
+        activation.context.gc_context,
+        "isConnected",
+        false.into(),
+        Attribute::DONT_DELETE | Attribute::DONT_ENUM,
+    );
+    Ok(this.into())
+}
+
+pub fn create_proto<'gc>(
+    gc_context: MutationContext<'gc, '_>,
+    proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    let object = ScriptObject::new(gc_context, Some(proto));
+    define_properties_on(PROTO_DECLS, gc_context, object, fn_proto);
+    object.into()
+}
+
+fn connect<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    // Only "connecting" to `null`, for progressive downloads, is supported.
+    let connected = match args.get(0).unwrap_or(&Value::Undefined) {
+        Value::Null => true,
+        _ => {
+            avm_warn!(activation, "NetConnection.connect: Only null is supported");
+            false
+        }
+    };
+
+    this.set("isConnected", connected.into(), activation)?;
+    Ok(connected.into())
+}
@@ -0,0 +1,65 @@
+//! NetStream class
+
+use crate::avm1::activation::Activation;
+use crate::avm1::error::Error;
+use crate::avm1::object::NativeObject;
+use crate::avm1::property_decl::{define_properties_on, Declaration};
+use crate::avm1::{Object, ScriptObject, TObject, Value};
+use crate::streams::NetStream;
+use gc_arena::MutationContext;
+
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "play" => method(play; DONT_ENUM | DONT_DELETE);
+    "close" => method(close; DONT_ENUM | DONT_DELETE);
+};
+
+/// Implements `NetStream`
+pub fn constructor<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let stream = NetStream::new(activation.context.gc_context, Some(this));
+    this.set_native(
+        activation.context.gc_context,
+        NativeObject::NetStream(stream),
+    );
+    Ok(this.into())
+}
+
+pub fn create_proto<'gc>(
+    gc_context: MutationContext<'gc, '_>,
+    proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    let object = ScriptObject::new(gc_context, Some(proto));
+    define_properties_on(PROTO_DECLS, gc_context, object, fn_proto);
+    object.into()
+}
+
+fn play<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let NativeObject::NetStream(stream) = this.native() {
+        if let Some(url) = args.get(0) {
+            let url = url.coerce_to_string(activation)?;
+            stream.play(&mut activation.context, url.to_utf8_lossy().into_owned());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn close<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let NativeObject::NetStream(stream) = this.native() {
+        stream.close(&mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
@@ -55,7 +55,7 @@ pub fn get_disk_usage<'gc>(
 }
 
 /// Serialize a Value to an AmfValue
-fn serialize_value<'gc>(
+pub(crate) fn serialize_value<'gc>(
     activation: &mut Activation<'_, 'gc>,
     elem: Value<'gc>,
 ) -> Option<AmfValue> {
@@ -111,7 +111,10 @@ fn recursive_serialize<'gc>(
 }
 
 /// Deserialize a AmfValue to a Value
-fn deserialize_value<'gc>(activation: &mut Activation<'_, 'gc>, val: &AmfValue) -> Value<'gc> {
+pub(crate) fn deserialize_value<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    val: &AmfValue,
+) -> Value<'gc> {
     match val {
         AmfValue::Null => Value::Null,
         AmfValue::Undefined => Value::Undefined,
@@ -9,6 +9,7 @@ use crate::avm_warn;
 use bitflags::bitflags;
 use core::fmt;
 use gc_arena::MutationContext;
+use url::Url;
 
 const OBJECT_DECLS: &[Declaration] = declare_properties! {
     "exactSettings" => property(get_exact_settings, set_exact_settings);
@@ -40,7 +41,7 @@ impl fmt::Display for CpuArchitecture {
 }
 
 /// Available type of sandbox for a given SWF
-#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum SandboxType {
     Remote,
     LocalWithFile,
@@ -288,6 +289,8 @@ pub struct SystemProperties {
     pub os: OperatingSystem,
     /// The type of the player sandbox
     pub sandbox_type: SandboxType,
+    /// The domains that were allowed with `Security.allowDomain`
+    pub allowed_domains: Vec<String>,
     /// The cpu architecture of the platform
     pub cpu_architecture: CpuArchitecture,
     /// The highest supported h264 decoder level
@@ -295,6 +298,49 @@ pub struct SystemProperties {
 }
 
 impl SystemProperties {
+    /// Allows movies in the remote sandbox to access `domain`, or any domain if it is `*`.
+    pub fn allow_domain(&mut self, domain: String) {
+        if !self.allowed_domains.contains(&domain) {
+            self.allowed_domains.push(domain);
+        }
+    }
+
+    /// Whether the sandbox allows a movie loaded from `movie_url` to fetch `url`.
+    pub fn can_fetch(&self, movie_url: Option<&str>, url: &str) -> bool {
+        let url = match Url::parse(url) {
+            Ok(url) => url,
+            // Relative URLs are resolved against the movie's own location.
+            Err(_) => return true,
+        };
+        let is_local = url.scheme() == "file";
+        match self.sandbox_type {
+            SandboxType::LocalTrusted => true,
+            SandboxType::LocalWithFile => is_local,
+            SandboxType::LocalWithNetwork => !is_local,
+            SandboxType::Remote => {
+                let host = match url.host_str() {
+                    Some(host) if !is_local => host,
+                    _ => return false,
+                };
+                let movie_url = movie_url.and_then(|movie_url| Url::parse(movie_url).ok());
+                let movie_host = movie_url.as_ref().and_then(Url::host_str);
+                movie_host.map_or(false, |movie_host| movie_host.eq_ignore_ascii_case(host))
+                    || self
+                        .allowed_domains
+                        .iter()
+                        .any(|domain| domain == "*" || domain.eq_ignore_ascii_case(host))
+            }
+        }
+    }
+
+    /// Whether the sandbox allows scripts to call out through `ExternalInterface`.
+    pub fn can_call_external_interface(&self) -> bool {
+        matches!(
+            self.sandbox_type,
+            SandboxType::Remote | SandboxType::LocalTrusted
+        )
+    }
+
     pub fn get_version_string(&self, avm: &mut Avm1) -> String {
         format!(
             "{} {},0,0,0",
@@ -414,6 +460,7 @@ impl Default for SystemProperties {
             manufacturer: Manufacturer::Linux,
             os: OperatingSystem::Linux,
             sandbox_type: SandboxType::LocalTrusted,
+            allowed_domains: Vec::new(),
             cpu_architecture: CpuArchitecture::X86,
             idc_level: "5.1".into(),
         }
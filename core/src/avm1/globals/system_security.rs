@@ -20,9 +20,12 @@ const OBJECT_DECLS: &[Declaration] = declare_properties! {
 fn allow_domain<'gc>(
     activation: &mut Activation<'_, 'gc>,
     _this: Object<'gc>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm_warn!(activation, "System.security.allowDomain() not implemented");
+    for domain in args {
+        let domain = domain.coerce_to_string(activation)?.to_string();
+        activation.context.system.allow_domain(domain);
+    }
     Ok(Value::Undefined)
 }
 
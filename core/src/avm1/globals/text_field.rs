@@ -70,6 +70,7 @@ const PROTO_DECLS: &[Declaration] = declare_properties! {
     "html" => property(tf_getter!(html), tf_setter!(set_html));
     "htmlText" => property(tf_getter!(html_text), tf_setter!(set_html_text));
     "length" => property(tf_getter!(length));
+    "maxChars" => property(tf_getter!(max_chars), tf_setter!(set_max_chars));
     "maxhscroll" => property(tf_getter!(maxhscroll));
     "maxscroll" => property(tf_getter!(maxscroll));
     "multiline" => property(tf_getter!(multiline), tf_setter!(set_multiline));
@@ -507,6 +508,27 @@ pub fn set_multiline<'gc>(
     Ok(())
 }
 
+pub fn max_chars<'gc>(
+    this: EditText<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    // An unlimited text field returns `null`.
+    match this.max_chars() {
+        0 => Ok(Value::Null),
+        max_chars => Ok(max_chars.into()),
+    }
+}
+
+pub fn set_max_chars<'gc>(
+    this: EditText<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+    value: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    let max_chars = value.coerce_to_i32(activation)?;
+    this.set_max_chars(max_chars, &mut activation.context);
+    Ok(())
+}
+
 pub fn selectable<'gc>(
     this: EditText<'gc>,
     _activation: &mut Activation<'_, 'gc>,
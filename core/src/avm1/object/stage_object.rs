@@ -693,10 +693,11 @@ fn set_high_quality<'gc>(
         } else {
             StageQuality::High
         };
-        activation
-            .context
-            .stage
-            .set_quality(activation.context.gc_context, quality);
+        activation.context.stage.set_quality(
+            activation.context.gc_context,
+            activation.context.renderer,
+            quality,
+        );
     }
     Ok(())
 }
@@ -745,10 +746,11 @@ fn set_quality<'gc>(
     val: Value<'gc>,
 ) -> Result<(), Error<'gc>> {
     if let Ok(quality) = val.coerce_to_string(activation)?.parse() {
-        activation
-            .context
-            .stage
-            .set_quality(activation.context.gc_context, quality);
+        activation.context.stage.set_quality(
+            activation.context.gc_context,
+            activation.context.renderer,
+            quality,
+        );
     }
     Ok(())
 }
@@ -1,14 +1,17 @@
 //! ActionScript Virtual Machine 2 (AS3) support
 
+use crate::avm2::activation::{BudgetedExecution, SuspendedActivation};
 use crate::avm2::class::AllocatorFn;
 use crate::avm2::function::Executable;
 use crate::avm2::globals::SystemClasses;
 use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::script::{Script, TranslationUnit};
 use crate::context::UpdateContext;
+use crate::display_object::{DisplayObject, TDisplayObject};
 use crate::string::AvmString;
 use fnv::FnvHashMap;
 use gc_arena::{Collect, GcCell, MutationContext};
+use instant::Instant;
 use swf::avm2::read::Reader;
 use swf::{DoAbc, DoAbcFlag};
 
@@ -105,10 +108,46 @@ pub struct Avm2<'gc> {
     /// collector does not support weak references.
     broadcast_list: FnvHashMap<AvmString<'gc>, Vec<Object<'gc>>>,
 
+    /// The number of instructions a frame script may execute before it is
+    /// suspended until the next frame.
+    ///
+    /// If `None`, frame scripts always run to completion.
+    instruction_budget: Option<u32>,
+
+    /// Frame scripts that were suspended after running out of their
+    /// instruction budget.
+    suspended_scripts: Vec<SuspendedFrameScript<'gc>>,
+
+    /// The start of the update in which a `ScriptTimeoutError` was last
+    /// thrown.
+    ///
+    /// The error is only thrown once per update; scripts that keep running
+    /// after it are terminated.
+    #[collect(require_static)]
+    timeout_error_update: Option<Instant>,
+
     #[cfg(feature = "avm_debug")]
     pub debug_output: bool,
 }
 
+/// A frame script that ran out of its instruction budget.
+#[derive(Collect)]
+#[collect(no_drop)]
+struct SuspendedFrameScript<'gc> {
+    /// The clip that the script belongs to.
+    clip: DisplayObject<'gc>,
+
+    /// The suspended frame of the script.
+    activation: SuspendedActivation<'gc>,
+
+    /// Whether the script may continue on the current frame.
+    ///
+    /// This is unset when the script is suspended, so that it only continues
+    /// on the next frame.
+    #[collect(require_static)]
+    is_resumable: bool,
+}
+
 impl<'gc> Avm2<'gc> {
     /// Construct a new AVM interpreter.
     pub fn new(mc: MutationContext<'gc, '_>) -> Self {
@@ -124,6 +163,9 @@ impl<'gc> Avm2<'gc> {
             native_instance_allocator_table: Default::default(),
             native_instance_init_table: Default::default(),
             broadcast_list: Default::default(),
+            instruction_budget: None,
+            suspended_scripts: Vec::new(),
+            timeout_error_update: None,
 
             #[cfg(feature = "avm_debug")]
             debug_output: false,
@@ -291,6 +333,126 @@ impl<'gc> Avm2<'gc> {
         Ok(())
     }
 
+    /// Run a frame script of `clip`.
+    ///
+    /// If an instruction budget is set, a bytecode frame script that exceeds
+    /// it is suspended, and continues running when `clip` runs its frame
+    /// scripts on the following frames. This keeps long scripts from
+    /// blocking the host's event loop, at the cost of letting other scripts
+    /// run before the suspended one finishes.
+    ///
+    /// Returns whether the script was suspended.
+    pub fn run_frame_script(
+        clip: DisplayObject<'gc>,
+        callable: Object<'gc>,
+        reciever: Option<Object<'gc>>,
+        context: &mut UpdateContext<'_, 'gc>,
+    ) -> Result<bool, Error<'gc>> {
+        let budget = match context.avm2.instruction_budget {
+            Some(budget) => budget,
+            None => {
+                Self::run_stack_frame_for_callable(callable, reciever, &[], context)?;
+                return Ok(false);
+            }
+        };
+        let executable = match callable.as_executable() {
+            Some(executable) => executable.clone(),
+            None => {
+                Self::run_stack_frame_for_callable(callable, reciever, &[], context)?;
+                return Ok(false);
+            }
+        };
+
+        let mut activation = Activation::from_nothing(context.reborrow());
+        let result =
+            executable.exec_with_budget(reciever, &[], &mut activation, callable, budget)?;
+        Ok(activation.avm2().suspend_frame_script(clip, result))
+    }
+
+    /// Continue running the frame script suspended on `clip` on a previous
+    /// frame, if there is one.
+    ///
+    /// A script continues at most once per frame, even if a goto runs the
+    /// frame scripts of `clip` again.
+    ///
+    /// Returns whether the script is still suspended.
+    pub fn resume_frame_script(
+        clip: DisplayObject<'gc>,
+        context: &mut UpdateContext<'_, 'gc>,
+    ) -> Result<bool, Error<'gc>> {
+        let index = context
+            .avm2
+            .suspended_scripts
+            .iter()
+            .position(|script| DisplayObject::ptr_eq(script.clip, clip));
+        let index = match index {
+            Some(index) => index,
+            None => return Ok(false),
+        };
+        if !context.avm2.suspended_scripts[index].is_resumable {
+            return Ok(true);
+        }
+
+        let suspended = context.avm2.suspended_scripts.remove(index).activation;
+        let budget = context.avm2.instruction_budget.unwrap_or(u32::MAX);
+        let result = suspended.resume(context.reborrow(), budget)?;
+        Ok(context.avm2.suspend_frame_script(clip, result))
+    }
+
+    /// Returns whether `clip` has a frame script suspended on a previous
+    /// frame.
+    pub fn has_suspended_frame_script(&self, clip: DisplayObject<'gc>) -> bool {
+        self.suspended_scripts
+            .iter()
+            .any(|script| DisplayObject::ptr_eq(script.clip, clip))
+    }
+
+    /// Let the suspended frame scripts continue on this frame.
+    pub fn allow_resuming_frame_scripts(&mut self) {
+        for script in &mut self.suspended_scripts {
+            script.is_resumable = true;
+        }
+    }
+
+    /// Drop the frame script suspended on `clip`, as it is being unloaded.
+    ///
+    /// Returns whether there was such a script.
+    pub fn cancel_frame_script(&mut self, clip: DisplayObject<'gc>) -> bool {
+        let count = self.suspended_scripts.len();
+        self.suspended_scripts
+            .retain(|script| !DisplayObject::ptr_eq(script.clip, clip));
+        self.suspended_scripts.len() != count
+    }
+
+    /// Keep a frame script of `clip` around if it was suspended.
+    ///
+    /// Scripts of a clip that was unloaded meanwhile are dropped.
+    fn suspend_frame_script(
+        &mut self,
+        clip: DisplayObject<'gc>,
+        result: BudgetedExecution<'gc>,
+    ) -> bool {
+        match result {
+            BudgetedExecution::Suspended(activation) if !clip.removed() => {
+                self.suspended_scripts.push(SuspendedFrameScript {
+                    clip,
+                    activation,
+                    is_resumable: false,
+                });
+                true
+            }
+            BudgetedExecution::Suspended(_) | BudgetedExecution::Complete(_) => false,
+        }
+    }
+
+    /// Set the number of instructions a frame script may execute per frame.
+    ///
+    /// `None` disables suspending frame scripts.
+    #[cfg(feature = "avm2_instruction_budget")]
+    pub(crate) fn set_instruction_budget(&mut self, budget: Option<u32>) {
+        self.instruction_budget = budget;
+    }
+
     /// Load an ABC file embedded in a `DoAbc` tag.
     pub fn do_abc(
         context: &mut UpdateContext<'_, 'gc>,
@@ -3,7 +3,8 @@
 use crate::avm2::array::ArrayStorage;
 use crate::avm2::class::Class;
 use crate::avm2::domain::Domain;
-use crate::avm2::error::type_error;
+use crate::avm2::error::{script_timeout_error, type_error};
+use crate::avm2::function::Executable;
 use crate::avm2::method::{BytecodeMethod, Method, ParamConfig};
 use crate::avm2::object::{
     ArrayObject, ByteArrayObject, ClassObject, FunctionObject, NamespaceObject, ScriptObject,
@@ -19,7 +20,7 @@ use crate::avm2::{value, Avm2, Error};
 use crate::context::UpdateContext;
 use crate::string::{AvmString, WStr, WString};
 use crate::swf::extensions::ReadSwfExt;
-use gc_arena::{Gc, GcCell};
+use gc_arena::{Collect, Gc, GcCell};
 use smallvec::SmallVec;
 use std::borrow::Cow;
 use std::cmp::{min, Ordering};
@@ -69,6 +70,95 @@ enum FrameControl<'gc> {
     Return(Value<'gc>),
 }
 
+/// The result of running a bytecode method with an instruction budget.
+#[derive(Collect)]
+#[collect(no_drop)]
+pub enum BudgetedExecution<'gc> {
+    /// The method returned a value within its budget.
+    Complete(Value<'gc>),
+
+    /// The method ran out of budget and was suspended.
+    Suspended(SuspendedActivation<'gc>),
+}
+
+/// The saved state of a bytecode method that ran out of its instruction
+/// budget.
+///
+/// Only the frame of the method itself is saved: any methods that it calls
+/// always run to completion before it can be suspended.
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct SuspendedActivation<'gc> {
+    /// The method being executed.
+    method: Gc<'gc, BytecodeMethod<'gc>>,
+
+    /// The executable that is put back on the call stack when resuming.
+    executable: Executable<'gc>,
+
+    /// The offset of the next instruction to execute.
+    position: usize,
+
+    /// The operand stack frame of the method.
+    stack: Vec<Value<'gc>>,
+
+    /// The scope stack frame of the method.
+    scope_stack: Vec<Scope<'gc>>,
+
+    this: Option<Object<'gc>>,
+    arguments: Option<Object<'gc>>,
+    local_registers: RegisterSet<'gc>,
+    outer: ScopeChain<'gc>,
+    caller_domain: Domain<'gc>,
+    subclass_object: Option<ClassObject<'gc>>,
+    activation_class: Option<ClassObject<'gc>>,
+    script_globals: Option<Object<'gc>>,
+}
+
+impl<'gc> SuspendedActivation<'gc> {
+    /// Continue executing the method for at most `budget` more instructions.
+    pub fn resume(
+        self,
+        context: UpdateContext<'_, 'gc>,
+        budget: u32,
+    ) -> Result<BudgetedExecution<'gc>, Error<'gc>> {
+        let body: Result<_, Error<'gc>> = self
+            .method
+            .body()
+            .ok_or_else(|| "Cannot execute non-native method without body".into());
+        let body = body?;
+
+        let mut activation = Activation {
+            this: self.this,
+            arguments: self.arguments,
+            is_executing: false,
+            actions_since_timeout_check: 0,
+            local_registers: self.local_registers,
+            return_value: None,
+            outer: self.outer,
+            caller_domain: self.caller_domain,
+            subclass_object: self.subclass_object,
+            activation_class: self.activation_class,
+            script_globals: self.script_globals,
+            stack_depth: context.avm2.stack.len(),
+            scope_depth: context.avm2.scope_stack.len(),
+            max_stack_size: body.max_stack as usize,
+            max_scope_size: (body.max_scope_depth - body.init_scope_depth) as usize,
+            context,
+        };
+        activation.avm2().stack.extend(self.stack);
+        activation.avm2().scope_stack.extend(self.scope_stack);
+
+        let gc_context = activation.context.gc_context;
+        activation
+            .avm2()
+            .push_call(gc_context, self.executable.clone());
+        let result =
+            activation.run_actions_from(self.method, self.executable, self.position, budget);
+        activation.avm2().pop_call(gc_context);
+        result
+    }
+}
+
 /// Represents a single activation of a given AVM2 function or keyframe.
 pub struct Activation<'a, 'gc: 'a> {
     /// The immutable value of `this`.
@@ -76,7 +166,6 @@ pub struct Activation<'a, 'gc: 'a> {
     this: Option<Object<'gc>>,
 
     /// The arguments this function was called by.
-    #[allow(dead_code)]
     arguments: Option<Object<'gc>>,
 
     /// Flags that the current activation frame is being executed and has a
@@ -137,6 +226,15 @@ pub struct Activation<'a, 'gc: 'a> {
     /// and we will not allocate a class for one.
     activation_class: Option<ClassObject<'gc>>,
 
+    /// The global object of the script whose initializer is executing.
+    ///
+    /// Script initializers start with an empty scope stack and no outer
+    /// scope, so this stands in as the bottom of the scope stack until the
+    /// initializer pushes its own global scope.
+    ///
+    /// This will only be available in script initializer activations.
+    script_globals: Option<Object<'gc>>,
+
     /// The index where the stack frame starts.
     stack_depth: usize,
 
@@ -175,6 +273,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             caller_domain: context.avm2.globals,
             subclass_object: None,
             activation_class: None,
+            script_globals: None,
             stack_depth: context.avm2.stack.len(),
             scope_depth: context.avm2.scope_stack.len(),
             max_stack_size: 0,
@@ -219,6 +318,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             caller_domain: domain,
             subclass_object: None,
             activation_class: None,
+            script_globals: Some(global_object),
             stack_depth: context.avm2.stack.len(),
             scope_depth: context.avm2.scope_stack.len(),
             max_stack_size: max_stack as usize,
@@ -454,6 +554,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             caller_domain: outer.domain(),
             subclass_object,
             activation_class,
+            script_globals: None,
             stack_depth: context.avm2.stack.len(),
             scope_depth: context.avm2.scope_stack.len(),
             max_stack_size: body.max_stack as usize,
@@ -503,6 +604,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
                     callee.into(),
                     &mut activation,
                 )?;
+                activation.arguments = Some(args_object);
             }
 
             *activation
@@ -540,6 +642,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             caller_domain,
             subclass_object,
             activation_class: None,
+            script_globals: None,
             stack_depth: context.avm2.stack.len(),
             scope_depth: context.avm2.scope_stack.len(),
             max_stack_size: 0,
@@ -630,16 +733,24 @@ impl<'a, 'gc> Activation<'a, 'gc> {
     ///
     /// The global scope refers to scope at the bottom of the
     /// outer scope. If the outer scope is empty, we use the bottom
-    /// of the current scope stack instead.
+    /// of the current scope stack instead. Script initializers that have not
+    /// yet pushed any scopes use the script's global object.
     ///
     /// A return value of `None` implies that both the outer scope, and
-    /// the current scope stack were both empty.
+    /// the current scope stack were both empty, and that this activation is
+    /// not running a script initializer.
     pub fn global_scope(&self) -> Option<Object<'gc>> {
         let outer_scope = self.outer;
         outer_scope
             .get(0)
             .or_else(|| self.scope_frame().first().copied())
             .map(|scope| scope.values())
+            .or(self.script_globals)
+    }
+
+    /// The `arguments` object of this activation, if the method requested one.
+    pub fn arguments(&self) -> Option<Object<'gc>> {
+        self.arguments
     }
 
     pub fn avm2(&mut self) -> &mut Avm2<'gc> {
@@ -660,6 +771,23 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         self.subclass_object
     }
 
+    /// Summarizes this frame's operand stack for opcode traces: its length,
+    /// followed by up to three of the topmost values.
+    fn stack_summary(&self) -> String {
+        let stack = &self.context.avm2.stack[self.stack_depth..];
+        let top: Vec<String> = stack
+            .iter()
+            .rev()
+            .take(3)
+            .map(|value| match value {
+                Value::Object(_) => "[object]".to_string(),
+                value => format!("{value:?}"),
+            })
+            .collect();
+        let more = if stack.len() > top.len() { ", ..." } else { "" };
+        format!("{} [{}{more}]", stack.len(), top.join(", "))
+    }
+
     pub fn scope_frame(&self) -> &[Scope<'gc>] {
         &self.context.avm2.scope_stack[self.scope_depth..]
     }
@@ -885,6 +1013,89 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         val
     }
 
+    /// Run a bytecode method for at most `budget` instructions.
+    ///
+    /// A method that does not return within its budget is suspended, and can
+    /// be continued later with `SuspendedActivation::resume`. `executable`
+    /// is the executable the method was called through.
+    pub fn run_actions_with_budget(
+        &mut self,
+        method: Gc<'gc, BytecodeMethod<'gc>>,
+        executable: Executable<'gc>,
+        budget: u32,
+    ) -> Result<BudgetedExecution<'gc>, Error<'gc>> {
+        self.run_actions_from(method, executable, 0, budget)
+    }
+
+    /// Run a bytecode method from the instruction at `position`, suspending
+    /// it after `budget` instructions.
+    fn run_actions_from(
+        &mut self,
+        method: Gc<'gc, BytecodeMethod<'gc>>,
+        executable: Executable<'gc>,
+        position: usize,
+        budget: u32,
+    ) -> Result<BudgetedExecution<'gc>, Error<'gc>> {
+        let body: Result<_, Error<'gc>> = method
+            .body()
+            .ok_or_else(|| "Cannot execute non-native method without body".into());
+        let body = body?;
+        let mut reader = Reader::new(&body.code);
+        reader.seek_absolute(&body.code, position);
+
+        let mut remaining = budget;
+        let val = loop {
+            if remaining == 0 {
+                let position = reader.pos(&body.code);
+                return Ok(BudgetedExecution::Suspended(
+                    self.suspend(method, executable, position),
+                ));
+            }
+            remaining -= 1;
+
+            let result = self.do_next_opcode(method, &mut reader, &body.code);
+            match result {
+                Ok(FrameControl::Return(value)) => break Ok(BudgetedExecution::Complete(value)),
+                Ok(FrameControl::Continue) => {}
+                Err(e) => break Err(e),
+            }
+        };
+
+        self.clear_stack();
+        self.clear_scope();
+        val
+    }
+
+    /// Save the state of this activation, removing its stack frames from
+    /// the interpreter.
+    fn suspend(
+        &mut self,
+        method: Gc<'gc, BytecodeMethod<'gc>>,
+        executable: Executable<'gc>,
+        position: usize,
+    ) -> SuspendedActivation<'gc> {
+        let stack_depth = self.stack_depth;
+        let scope_depth = self.scope_depth;
+        let stack = self.avm2().stack.split_off(stack_depth);
+        let scope_stack = self.avm2().scope_stack.split_off(scope_depth);
+
+        SuspendedActivation {
+            method,
+            executable,
+            position,
+            stack,
+            scope_stack,
+            this: self.this,
+            arguments: self.arguments,
+            local_registers: std::mem::replace(&mut self.local_registers, RegisterSet::new(0)),
+            outer: self.outer,
+            caller_domain: self.caller_domain,
+            subclass_object: self.subclass_object,
+            activation_class: self.activation_class,
+            script_globals: self.script_globals,
+        }
+    }
+
     /// If a local exception handler exists for the error, use it to handle
     /// the error. Otherwise pass the error down the stack.
     fn handle_err<'b>(
@@ -938,6 +1149,34 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         Err(Error::AvmError(error))
     }
 
+    /// Check whether the current update has run for longer than allowed.
+    ///
+    /// Running over the limit throws a `ScriptTimeoutError`, which scripts
+    /// can catch. It is only thrown once per update: scripts that then keep
+    /// running for as long again are terminated.
+    fn check_timeout(&mut self) -> Result<(), Error<'gc>> {
+        let update_start = self.context.update_start;
+        let elapsed = update_start.elapsed();
+        let max_duration = self.context.max_execution_duration;
+        if elapsed >= max_duration.saturating_mul(2) {
+            return Err(
+                "A script in this movie has taken too long to execute and has been terminated."
+                    .into(),
+            );
+        }
+
+        if elapsed >= max_duration && self.avm2().timeout_error_update != Some(update_start) {
+            self.avm2().timeout_error_update = Some(update_start);
+            let message = format!(
+                "Error #1502: A script has executed for longer than the default timeout period of {} seconds.",
+                max_duration.as_secs()
+            );
+            return Err(Error::AvmError(script_timeout_error(self, &message, 1502)?));
+        }
+
+        Ok(())
+    }
+
     /// Run a single action from a given action reader.
     fn do_next_opcode<'b>(
         &mut self,
@@ -945,21 +1184,23 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         reader: &mut Reader<'b>,
         full_data: &'b [u8],
     ) -> Result<FrameControl<'gc>, Error<'gc>> {
+        let instruction_start = reader.pos(full_data);
+
         self.actions_since_timeout_check += 1;
         if self.actions_since_timeout_check >= 2000 {
             self.actions_since_timeout_check = 0;
-            if self.context.update_start.elapsed() >= self.context.max_execution_duration {
-                return Err(
-                    "A script in this movie has taken too long to execute and has been terminated."
-                        .into(),
-                );
+            if let Err(error) = self.check_timeout() {
+                return self.handle_err(method, reader, full_data, instruction_start, error);
             }
         }
-
-        let instruction_start = reader.pos(full_data);
         let op = reader.read_op();
         if let Ok(op) = op {
-            avm_debug!(self.avm2(), "Opcode: {op:?}");
+            avm_debug!(
+                self.avm2(),
+                "Opcode @{instruction_start}: {op:?} (stack: {}, scope depth: {})",
+                self.stack_summary(),
+                self.scope_frame().len()
+            );
 
             let result = match op {
                 Op::PushByte { value } => self.op_push_byte(value),
@@ -1484,6 +1725,22 @@ impl<'a, 'gc> Activation<'a, 'gc> {
                     let value = dictionary.get_property_by_object(name_value.as_object().unwrap());
                     self.push_stack(value);
 
+                    return Ok(FrameControl::Continue);
+                }
+            } else if let (Some(index), Value::Object(object)) =
+                (name_value.as_array_index(), object)
+            {
+                // Holes fall through to the main path, which checks the prototype chain.
+                let value = if multiname.contains_public_namespace() {
+                    object.as_array_storage().and_then(|array| array.get(index))
+                } else {
+                    None
+                };
+                if let Some(value) = value {
+                    let _ = self.pop_stack();
+                    let _ = self.pop_stack();
+                    self.push_stack(value);
+
                     return Ok(FrameControl::Continue);
                 }
             }
@@ -1536,6 +1793,19 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
                     return Ok(FrameControl::Continue);
                 }
+            } else if let (Some(index), Value::Object(object)) =
+                (name_value.as_array_index(), object)
+            {
+                if multiname.contains_public_namespace() {
+                    if let Some(mut array) = object.as_array_storage_mut(self.context.gc_context) {
+                        array.set(index, value);
+                        drop(array);
+                        let _ = self.pop_stack();
+                        let _ = self.pop_stack();
+
+                        return Ok(FrameControl::Continue);
+                    }
+                }
             }
         }
 
@@ -1580,7 +1850,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             return Ok(FrameControl::Continue);
         }
 
-        // side path for dictionary/arrays (TODO)
+        // side path for dictionary/arrays
         if multiname.has_lazy_name() && !multiname.has_lazy_ns() {
             // `MultinameL` is the only form of multiname that allows fast-path
             // or alternate-path lookups based on the local name *value*,
@@ -1601,6 +1871,21 @@ impl<'a, 'gc> Activation<'a, 'gc> {
                     self.push_stack(true);
                     return Ok(FrameControl::Continue);
                 }
+            } else if let (Some(index), Value::Object(object)) =
+                (name_value.as_array_index(), object)
+            {
+                // Deleting an element leaves a hole; the length is unchanged.
+                if multiname.contains_public_namespace() {
+                    if let Some(mut array) = object.as_array_storage_mut(self.context.gc_context) {
+                        array.delete(index);
+                        drop(array);
+                        let _ = self.pop_stack();
+                        let _ = self.pop_stack();
+
+                        self.push_stack(true);
+                        return Ok(FrameControl::Continue);
+                    }
+                }
             }
         }
 
@@ -1731,6 +2016,8 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
         if let Some(scope) = scope {
             self.push_stack(scope.values());
+        } else if let (0, Some(globals)) = (index, self.script_globals) {
+            self.push_stack(globals);
         } else {
             self.push_stack(Value::Undefined);
         };
@@ -2328,8 +2615,8 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             (Value::Integer(n1), Value::Integer(n2)) => (n1 - n2).into(),
             (Value::Number(n1), Value::Number(n2)) => (n1 - n2).into(),
             _ => {
-                let value2 = value2.coerce_to_number(self)?;
                 let value1 = value1.coerce_to_number(self)?;
+                let value2 = value2.coerce_to_number(self)?;
                 (value1 - value2).into()
             }
         };
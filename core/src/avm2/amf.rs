@@ -1,5 +1,6 @@
+use crate::amf::{GraphObject, GraphValue, ObjectGraph, ObjectId};
 use crate::avm2::bytearray::ByteArrayStorage;
-use crate::avm2::object::{ByteArrayObject, TObject};
+use crate::avm2::object::{ByteArrayObject, ObjectPtr, TObject};
 use crate::avm2::ArrayObject;
 use crate::avm2::ArrayStorage;
 use crate::avm2::Multiname;
@@ -8,6 +9,7 @@ use crate::string::AvmString;
 use enumset::EnumSet;
 use flash_lso::types::{AMFVersion, Element, Lso};
 use flash_lso::types::{Attribute, ClassDefinition, Value as AmfValue};
+use fnv::FnvHashMap;
 
 /// Serialize a Value to an AmfValue
 pub fn serialize_value<'gc>(
@@ -215,6 +217,193 @@ pub fn deserialize_value<'gc>(
     })
 }
 
+/// Serialize a Value into an AMF3 object graph.
+///
+/// Objects that were already serialized into `graph` are referred to by their existing
+/// `ObjectId` in `ids`, so that shared and cyclic references are kept.
+pub fn serialize_graph<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    elem: Value<'gc>,
+    graph: &mut ObjectGraph,
+    ids: &mut FnvHashMap<*const ObjectPtr, ObjectId>,
+) -> Result<Option<GraphValue>, Error<'gc>> {
+    let o = match elem {
+        Value::Undefined => return Ok(Some(GraphValue::Undefined)),
+        Value::Null => return Ok(Some(GraphValue::Null)),
+        Value::Bool(b) => return Ok(Some(GraphValue::Bool(b))),
+        Value::Number(f) => return Ok(Some(GraphValue::Number(f))),
+        Value::Integer(num) => return Ok(Some(GraphValue::Integer(num))),
+        Value::String(s) => return Ok(Some(GraphValue::String(s.to_string()))),
+        Value::Object(o) => o,
+    };
+
+    if let Some(id) = ids.get(&o.as_ptr()) {
+        return Ok(Some(GraphValue::Object(*id)));
+    }
+    if o.as_executable().is_some() {
+        return Ok(None);
+    }
+    if o.as_display_object().is_some() {
+        return Ok(Some(GraphValue::Undefined));
+    }
+    if let Some(date) = o.as_date_object() {
+        return Ok(date.date_time().map(|date_time| {
+            let id = graph.add(GraphObject::Date(date_time.timestamp_millis() as f64));
+            ids.insert(o.as_ptr(), id);
+            GraphValue::Object(id)
+        }));
+    }
+
+    let is_array = o.as_array_storage().is_some();
+    let is_object = o
+        .instance_of()
+        .map_or(false, |c| c == activation.avm2().classes().object);
+    if !is_array && !is_object {
+        tracing::warn!(
+            "Serialization is not implemented for class other than Object: {:?}",
+            o
+        );
+        return Ok(None);
+    }
+
+    // Register the object before serializing its properties, which may refer back to it.
+    let id = graph.reserve();
+    ids.insert(o.as_ptr(), id);
+
+    let mut properties = Vec::new();
+    let mut last_index = o.get_next_enumerant(0, activation)?;
+    while let Some(index) = last_index {
+        let name = o
+            .get_enumerant_name(index, activation)?
+            .coerce_to_string(activation)?;
+        let value = o.get_property(&Multiname::public(name), activation)?;
+        if let Some(value) = serialize_graph(activation, value, graph, ids)? {
+            properties.push((name.to_utf8_lossy().into_owned(), value));
+        }
+        last_index = o.get_next_enumerant(index, activation)?;
+    }
+
+    let object = if is_array {
+        let mut dense = Vec::new();
+        let mut associative = Vec::new();
+        for (name, value) in properties {
+            if associative.is_empty() && name == dense.len().to_string() {
+                dense.push(value);
+            } else {
+                associative.push((name, value));
+            }
+        }
+        GraphObject::Array { dense, associative }
+    } else {
+        GraphObject::Object {
+            class_name: "".to_string(),
+            properties,
+        }
+    };
+    graph.set(id, object);
+    Ok(Some(GraphValue::Object(id)))
+}
+
+/// Deserialize a value of an AMF3 object graph.
+///
+/// Objects that were already deserialized are looked up in `objects`, so that shared and
+/// cyclic references resolve to the same object.
+pub fn deserialize_graph<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    graph: &ObjectGraph,
+    val: &GraphValue,
+    objects: &mut FnvHashMap<ObjectId, Object<'gc>>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = match val {
+        GraphValue::Undefined => return Ok(Value::Undefined),
+        GraphValue::Null => return Ok(Value::Null),
+        GraphValue::Bool(b) => return Ok((*b).into()),
+        GraphValue::Integer(num) => return Ok((*num).into()),
+        GraphValue::Number(f) => return Ok((*f).into()),
+        GraphValue::String(s) => {
+            return Ok(AvmString::new_utf8(activation.context.gc_context, s).into())
+        }
+        GraphValue::Object(id) => *id,
+    };
+    if let Some(object) = objects.get(&id) {
+        return Ok((*object).into());
+    }
+
+    Ok(match graph.get(id) {
+        GraphObject::Object {
+            class_name,
+            properties,
+        } => {
+            if !class_name.is_empty() && class_name != "Object" {
+                tracing::warn!("Deserializing class {:?} is not supported!", class_name);
+            }
+
+            let mut obj = activation
+                .avm2()
+                .classes()
+                .object
+                .construct(activation, &[])?;
+            objects.insert(id, obj);
+            for (name, value) in properties {
+                let value = deserialize_graph(activation, graph, value, objects)?;
+                obj.set_property(
+                    &Multiname::public(AvmString::new_utf8(activation.context.gc_context, name)),
+                    value,
+                    activation,
+                )?;
+            }
+            obj.into()
+        }
+        GraphObject::Array { dense, associative } => {
+            let mut array = ArrayObject::from_storage(activation, ArrayStorage::new(0))?;
+            objects.insert(id, array);
+            for (index, value) in dense.iter().enumerate() {
+                let value = deserialize_graph(activation, graph, value, objects)?;
+                if let Some(mut storage) = array.as_array_storage_mut(activation.context.gc_context)
+                {
+                    storage.set(index, value);
+                }
+            }
+            for (name, value) in associative {
+                let value = deserialize_graph(activation, graph, value, objects)?;
+                array.set_property(
+                    &Multiname::public(AvmString::new_utf8(activation.context.gc_context, name)),
+                    value,
+                    activation,
+                )?;
+            }
+            array.into()
+        }
+        GraphObject::Date(time) => {
+            let date = activation
+                .avm2()
+                .classes()
+                .date
+                .construct(activation, &[(*time).into()])?;
+            objects.insert(id, date);
+            date.into()
+        }
+        GraphObject::ByteArray(bytes) => {
+            let storage = ByteArrayStorage::from_vec(bytes.clone());
+            let bytearray = ByteArrayObject::from_storage(activation, storage)?;
+            objects.insert(id, bytearray);
+            bytearray.into()
+        }
+        GraphObject::Xml(content) => {
+            let xml = activation.avm2().classes().xml.construct(
+                activation,
+                &[AvmString::new_utf8(activation.context.gc_context, content).into()],
+            )?;
+            objects.insert(id, xml);
+            xml.into()
+        }
+        GraphObject::Unsupported => {
+            tracing::error!("Deserialization not yet implemented: {:?}", val);
+            Value::Undefined
+        }
+    })
+}
+
 /// Deserializes a Lso into an object containing the properties stored
 pub fn deserialize_lso<'gc>(
     activation: &mut Activation<'_, 'gc>,
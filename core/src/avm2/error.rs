@@ -1,6 +1,7 @@
 use crate::avm2::object::TObject;
 use crate::avm2::Activation;
 use crate::avm2::AvmString;
+use crate::avm2::Multiname;
 use crate::avm2::Value;
 
 use super::ClassObject;
@@ -75,6 +76,45 @@ pub fn reference_error<'gc>(
     error_constructor(activation, class, message, code)
 }
 
+/// Construct a `ReferenceError` for an illegal access of a property on an
+/// instance of the given class.
+///
+/// The message follows Flash Player's format, i.e. for a `description` of
+/// "Cannot create property", `Error #1056: Cannot create property foo on Bar.`
+#[inline(never)]
+#[cold]
+pub fn property_reference_error<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    description: &str,
+    name: &Multiname<'gc>,
+    class: Option<ClassObject<'gc>>,
+    code: u32,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let mc = activation.context.gc_context;
+    let local_name = name.local_name().unwrap_or_else(|| "*".into());
+    let class_name = class
+        .map(|cls| {
+            cls.inner_class_definition()
+                .read()
+                .name()
+                .to_qualified_name_err_message(mc)
+        })
+        .unwrap_or_else(|| "<UNKNOWN>".into());
+    let message = format!("Error #{code}: {description} {local_name} on {class_name}.");
+    reference_error(activation, &message, code)
+}
+
+#[inline(never)]
+#[cold]
+pub fn uri_error<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    message: &str,
+    code: u32,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let class = activation.avm2().classes().urierror;
+    error_constructor(activation, class, message, code)
+}
+
 #[inline(never)]
 #[cold]
 pub fn verify_error<'gc>(
@@ -108,6 +148,17 @@ pub fn eof_error<'gc>(
     error_constructor(activation, class, message, code)
 }
 
+#[inline(never)]
+#[cold]
+pub fn script_timeout_error<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    message: &str,
+    code: u32,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let class = activation.avm2().classes().scripttimeouterror;
+    error_constructor(activation, class, message, code)
+}
+
 fn error_constructor<'gc>(
     activation: &mut Activation<'_, 'gc>,
     class: ClassObject<'gc>,
@@ -1,6 +1,6 @@
 //! AVM2 executables.
 
-use crate::avm2::activation::Activation;
+use crate::avm2::activation::{Activation, BudgetedExecution};
 use crate::avm2::method::{BytecodeMethod, Method, NativeMethod};
 use crate::avm2::object::{ClassObject, Object};
 use crate::avm2::scope::ScopeChain;
@@ -178,6 +178,56 @@ impl<'gc> Executable<'gc> {
         ret
     }
 
+    /// Execute a method for at most `budget` instructions.
+    ///
+    /// Bytecode methods that do not return within the budget are suspended
+    /// instead, and can be continued later. Native methods always run to
+    /// completion.
+    pub fn exec_with_budget(
+        &self,
+        unbound_receiver: Option<Object<'gc>>,
+        mut arguments: &[Value<'gc>],
+        activation: &mut Activation<'_, 'gc>,
+        callee: Object<'gc>,
+        budget: u32,
+    ) -> Result<BudgetedExecution<'gc>, Error<'gc>> {
+        let bm = match self {
+            Executable::Action(bm) => bm,
+            Executable::Native(_) => {
+                return self
+                    .exec(unbound_receiver, arguments, activation, callee)
+                    .map(BudgetedExecution::Complete);
+            }
+        };
+
+        if bm.method.is_unchecked() {
+            let max_args = bm.method.signature().len();
+            if arguments.len() > max_args && !bm.method.is_variadic() {
+                arguments = &arguments[..max_args];
+            }
+        }
+
+        let mut activation = Activation::from_method(
+            activation.context.reborrow(),
+            bm.method,
+            bm.scope,
+            bm.receiver.or(unbound_receiver),
+            arguments,
+            bm.bound_superclass,
+            callee,
+        )?;
+        activation
+            .context
+            .avm2
+            .push_call(activation.context.gc_context, self.clone());
+        let ret = activation.run_actions_with_budget(bm.method, self.clone(), budget);
+        activation
+            .context
+            .avm2
+            .pop_call(activation.context.gc_context);
+        ret
+    }
+
     pub fn bound_superclass(&self) -> Option<ClassObject<'gc>> {
         match self {
             Executable::Native(NativeExecutable {
@@ -99,14 +99,17 @@ pub struct SystemClasses<'gc> {
     pub eventdispatcher: ClassObject<'gc>,
     pub rectangle: ClassObject<'gc>,
     pub keyboardevent: ClassObject<'gc>,
+    pub contextmenuevent: ClassObject<'gc>,
     pub point: ClassObject<'gc>,
     pub rangeerror: ClassObject<'gc>,
     pub referenceerror: ClassObject<'gc>,
     pub argumenterror: ClassObject<'gc>,
     pub typeerror: ClassObject<'gc>,
+    pub urierror: ClassObject<'gc>,
     pub verifyerror: ClassObject<'gc>,
     pub ioerror: ClassObject<'gc>,
     pub eoferror: ClassObject<'gc>,
+    pub scripttimeouterror: ClassObject<'gc>,
     pub uncaughterrorevents: ClassObject<'gc>,
     pub statictext: ClassObject<'gc>,
     pub textlinemetrics: ClassObject<'gc>,
@@ -184,14 +187,17 @@ impl<'gc> SystemClasses<'gc> {
             eventdispatcher: object,
             rectangle: object,
             keyboardevent: object,
+            contextmenuevent: object,
             point: object,
             rangeerror: object,
             referenceerror: object,
             argumenterror: object,
             typeerror: object,
+            urierror: object,
             verifyerror: object,
             ioerror: object,
             eoferror: object,
+            scripttimeouterror: object,
             uncaughterrorevents: object,
             statictext: object,
             textlinemetrics: object,
@@ -425,6 +431,15 @@ pub fn load_player_globals<'gc>(
     function(activation, "", "parseInt", toplevel::parse_int, script)?;
     function(activation, "", "parseFloat", toplevel::parse_float, script)?;
     function(activation, "", "escape", toplevel::escape, script)?;
+    function(activation, "", "unescape", toplevel::unescape, script)?;
+    function(activation, "", "encodeURI", toplevel::encode_uri, script)?;
+    function(
+        activation,
+        "",
+        "encodeURIComponent",
+        toplevel::encode_uri_component,
+        script,
+    )?;
 
     avm2_system_class!(regexp, activation, regexp::create_class(mc), script);
     avm2_system_class!(vector, activation, vector::create_class(mc), script);
@@ -641,6 +656,7 @@ fn load_playerglobal<'gc>(
             ("", "RangeError", rangeerror),
             ("", "ReferenceError", referenceerror),
             ("", "TypeError", typeerror),
+            ("", "URIError", urierror),
             ("", "VerifyError", verifyerror),
             ("", "XML", xml),
             ("", "XMLList", xml_list),
@@ -658,10 +674,12 @@ fn load_playerglobal<'gc>(
             ),
             ("flash.errors", "IOError", ioerror),
             ("flash.errors", "EOFError", eoferror),
+            ("flash.errors", "ScriptTimeoutError", scripttimeouterror),
             ("flash.events", "Event", event),
             ("flash.events", "TextEvent", textevent),
             ("flash.events", "ErrorEvent", errorevent),
             ("flash.events", "KeyboardEvent", keyboardevent),
+            ("flash.events", "ContextMenuEvent", contextmenuevent),
             ("flash.events", "ProgressEvent", progressevent),
             ("flash.events", "SecurityErrorEvent", securityerrorevent),
             ("flash.events", "IOErrorEvent", ioerrorevent),
@@ -58,7 +58,7 @@ const PUBLIC_INSTANCE_AND_PROTO_METHODS: &[(&str, NativeMethodImpl)] = &[
     ("toTimeString", to_time_string),
     ("toLocaleTimeString", to_locale_time_string),
     ("toDateString", to_date_string),
-    ("toLocaleDateString", to_date_string),
+    ("toLocaleDateString", to_locale_date_string),
 ];
 
 struct DateAdjustment<'builder, 'activation_a: 'builder, 'gc: 'activation_a, T: TimeZone + 'builder>
@@ -1015,7 +1015,8 @@ pub fn to_locale_string<'gc>(
         {
             return Ok(AvmString::new_utf8(
                 activation.context.gc_context,
-                date.format("%a %b %-d %-Y %T %p").to_string(),
+                date.format(activation.context.locale.date_time_format())
+                    .to_string(),
             )
             .into());
         } else {
@@ -1063,7 +1064,8 @@ pub fn to_locale_time_string<'gc>(
         {
             return Ok(AvmString::new_utf8(
                 activation.context.gc_context,
-                date.format("%T %p").to_string(),
+                date.format(activation.context.locale.time_format())
+                    .to_string(),
             )
             .into());
         } else {
@@ -1074,7 +1076,32 @@ pub fn to_locale_time_string<'gc>(
     Ok(Value::Undefined)
 }
 
-/// Implements the `toDateString` & `toLocaleDateString` method.
+/// Implements the `toLocaleDateString` method.
+pub fn to_locale_date_string<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this.and_then(|this| this.as_date_object()) {
+        if let Some(date) = this
+            .date_time()
+            .map(|date| date.with_timezone(&get_timezone()))
+        {
+            return Ok(AvmString::new_utf8(
+                activation.context.gc_context,
+                date.format(activation.context.locale.date_format())
+                    .to_string(),
+            )
+            .into());
+        } else {
+            return Ok("Invalid Date".into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements the `toDateString` method.
 pub fn to_date_string<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Option<Object<'gc>>,
@@ -414,6 +414,96 @@ pub fn set_pixel32<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `BitmapData.threshold`.
+pub fn threshold<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(bitmap_data) = this.and_then(|t| t.as_bitmap_data()) {
+        bitmap_data.read().check_valid(activation)?;
+        let source_bitmap = args
+            .get(0)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+
+        let source_rect = args
+            .get(1)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+
+        let src_min_x = source_rect
+            .get_property(&Multiname::public("x"), activation)?
+            .coerce_to_i32(activation)?;
+        let src_min_y = source_rect
+            .get_property(&Multiname::public("y"), activation)?
+            .coerce_to_i32(activation)?;
+        let src_width = source_rect
+            .get_property(&Multiname::public("width"), activation)?
+            .coerce_to_i32(activation)?;
+        let src_height = source_rect
+            .get_property(&Multiname::public("height"), activation)?
+            .coerce_to_i32(activation)?;
+
+        let dest_point = args
+            .get(2)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_object(activation)?;
+
+        let dest_x = dest_point
+            .get_property(&Multiname::public("x"), activation)?
+            .coerce_to_i32(activation)?;
+        let dest_y = dest_point
+            .get_property(&Multiname::public("y"), activation)?
+            .coerce_to_i32(activation)?;
+
+        let operation = args
+            .get(3)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_string(activation)?;
+        let threshold = args
+            .get(4)
+            .unwrap_or(&Value::Undefined)
+            .coerce_to_u32(activation)?;
+        let colour = args.get(5).unwrap_or(&0.into()).coerce_to_u32(activation)?;
+        let mask = args
+            .get(6)
+            .unwrap_or(&0xFFFFFFFFu32.into())
+            .coerce_to_u32(activation)?;
+        let copy_source = args.get(7).unwrap_or(&false.into()).coerce_to_boolean();
+
+        if let Some(src_bitmap) = source_bitmap.as_bitmap_data() {
+            src_bitmap.read().check_valid(activation)?;
+            // dealing with object aliasing...
+            let src_bitmap_clone: BitmapData; // only initialized if source is the same object as self
+            let src_bitmap_gc_ref; // only initialized if source is a different object than self
+            let source_bitmap_ref = // holds the reference to either of the ones above
+                if GcCell::ptr_eq(src_bitmap, bitmap_data) {
+                    src_bitmap_clone = src_bitmap.read().clone();
+                    &src_bitmap_clone
+                } else {
+                    src_bitmap_gc_ref = src_bitmap.read();
+                    &src_bitmap_gc_ref
+                };
+
+            let modified_count = bitmap_data.write(activation.context.gc_context).threshold(
+                source_bitmap_ref,
+                (src_min_x, src_min_y, src_width, src_height),
+                (dest_x, dest_y),
+                &operation,
+                threshold,
+                colour,
+                mask,
+                copy_source,
+            );
+
+            return Ok(modified_count.into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
 /// Implements `BitmapData.setPixels`.
 pub fn set_pixels<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -1003,6 +1093,7 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         ("setPixel", set_pixel),
         ("setPixel32", set_pixel32),
         ("setPixels", set_pixels),
+        ("threshold", threshold),
         ("copyChannel", copy_channel),
         ("floodFill", flood_fill),
         ("noise", noise),
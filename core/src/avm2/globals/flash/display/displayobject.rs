@@ -20,7 +20,7 @@ use crate::vminterface::Instantiator;
 use gc_arena::{GcCell, MutationContext};
 use std::str::FromStr;
 use swf::Twips;
-use swf::{BlendMode, Rectangle};
+use swf::{BlendMode, Filter, Fixed16, Rectangle};
 
 /// Implements `flash.display.DisplayObject`'s instance constructor.
 pub fn instance_init<'gc>(
@@ -253,11 +253,88 @@ pub fn filters<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
-        return Ok(ArrayObject::from_storage(activation, dobj.filters())?.into());
+        let mut filters = dobj.filters();
+        if filters.length() == 0 && !dobj.bitmap_filters().is_empty() {
+            // Filters placed by the timeline don't have script objects yet.
+            for filter in dobj.bitmap_filters() {
+                if let Some(filter) = filter_to_object(activation, &filter)? {
+                    filters.push(filter.into());
+                }
+            }
+            dobj.set_filters(
+                activation.context.gc_context,
+                ArrayStorage::from_storage(filters.iter().collect()),
+            );
+        }
+
+        // Scripts get copies, so modifying a filter has no effect until `filters` is assigned.
+        let mut copies = ArrayStorage::new(0);
+        for filter in filters.iter().flatten() {
+            let copy = match filter {
+                Value::Object(filter) => {
+                    filter.call_property(&Multiname::public("clone"), &[], activation)?
+                }
+                filter => filter,
+            };
+            copies.push(copy);
+        }
+        return Ok(ArrayObject::from_storage(activation, copies)?.into());
     }
     Ok(ArrayObject::empty(activation)?.into())
 }
 
+fn blur_filter_name<'gc>() -> Multiname<'gc> {
+    Multiname::new(Namespace::package("flash.filters"), "BlurFilter")
+}
+
+/// Builds the script object for a filter, or `None` if the filter type isn't supported yet.
+fn filter_to_object<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    filter: &Filter,
+) -> Result<Option<Object<'gc>>, Error<'gc>> {
+    match filter {
+        Filter::BlurFilter(blur) => {
+            let class = activation.resolve_class(&blur_filter_name())?;
+            let object = class.construct(
+                activation,
+                &[
+                    blur.blur_x.to_f64().into(),
+                    blur.blur_y.to_f64().into(),
+                    blur.num_passes.into(),
+                ],
+            )?;
+            Ok(Some(object))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Converts a filter object to the filter used for rendering, or `None` if the filter
+/// type isn't supported yet.
+fn object_to_filter<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    object: Object<'gc>,
+) -> Result<Option<Filter>, Error<'gc>> {
+    let blur_class = activation.resolve_class(&blur_filter_name())?;
+    if object.is_of_type(blur_class, activation) {
+        let blur_x = object
+            .get_property(&Multiname::public("blurX"), activation)?
+            .coerce_to_number(activation)?;
+        let blur_y = object
+            .get_property(&Multiname::public("blurY"), activation)?
+            .coerce_to_number(activation)?;
+        let quality = object
+            .get_property(&Multiname::public("quality"), activation)?
+            .coerce_to_i32(activation)?;
+        return Ok(Some(Filter::BlurFilter(Box::new(swf::BlurFilter {
+            blur_x: Fixed16::from_f64(blur_x.clamp(0.0, 255.0)),
+            blur_y: Fixed16::from_f64(blur_y.clamp(0.0, 255.0)),
+            num_passes: quality.clamp(0, 15) as u8,
+        }))));
+    }
+    Ok(None)
+}
+
 fn build_argument_type_error<'gc>(
     activation: &mut Activation<'_, 'gc>,
 ) -> Result<Value<'gc>, Error<'gc>> {
@@ -279,6 +356,7 @@ pub fn set_filters<'gc>(
         if matches!(new_filters, Value::Undefined | Value::Null) {
             let new_storage = ArrayStorage::new(0);
             dobj.set_filters(activation.context.gc_context, new_storage);
+            dobj.set_bitmap_filters(activation.context.gc_context, Vec::new());
         } else {
             let new_filters = new_filters.coerce_to_object(activation)?;
 
@@ -302,7 +380,16 @@ pub fn set_filters<'gc>(
                     }
                     let new_storage = ArrayStorage::from_storage(filters_storage.iter().collect());
 
+                    let mut bitmap_filters = Vec::new();
+                    for filter in filters_storage.iter().flatten() {
+                        let filter_object = filter.coerce_to_object(activation)?;
+                        if let Some(filter) = object_to_filter(activation, filter_object)? {
+                            bitmap_filters.push(filter);
+                        }
+                    }
+
                     dobj.set_filters(activation.context.gc_context, new_storage);
+                    dobj.set_bitmap_filters(activation.context.gc_context, bitmap_filters);
                 }
             }
         }
@@ -909,6 +996,36 @@ fn set_scroll_rect<'gc>(
     Ok(Value::Undefined)
 }
 
+fn scale_nine_grid<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        if let Some(rectangle) = dobj.scale9_grid() {
+            return Ok(new_rectangle(activation, rectangle.into())?.into());
+        } else {
+            return Ok(Value::Null);
+        }
+    }
+    Ok(Value::Undefined)
+}
+
+fn set_scale_nine_grid<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let rectangle = match args[0].as_object() {
+            Some(rectangle) => Some(object_to_rectangle(activation, rectangle)?),
+            None => None,
+        };
+        dobj.set_scale9_grid(activation.context.gc_context, rectangle);
+    }
+    Ok(Value::Undefined)
+}
+
 fn local_to_global<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Option<Object<'gc>>,
@@ -977,6 +1094,25 @@ fn get_bounds<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Option<Object<'gc>>,
     args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    bounds_in_target_space(activation, this, args, |dobj| dobj.bounds())
+}
+
+fn get_rect<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    bounds_in_target_space(activation, this, args, |dobj| dobj.rect())
+}
+
+/// Shared implementation of `getBounds` and `getRect`, which only differ in
+/// whether strokes are included in the untransformed `bounds`.
+fn bounds_in_target_space<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+    bounds: fn(DisplayObject<'gc>) -> BoundingBox,
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
         // TODO: add typing `(target: DisplayObject)` for proper type errors
@@ -984,7 +1120,7 @@ fn get_bounds<'gc>(
             Value::Undefined | Value::Null => Some(dobj),
             _ => value.as_object().and_then(|o| o.as_display_object()),
         }) {
-            let bounds = dobj.bounds();
+            let bounds = bounds(dobj);
             let out_bounds = if DisplayObject::ptr_eq(dobj, target) {
                 // Getting the clips bounds in its own coordinate space; no AABB transform needed.
                 bounds
@@ -1007,16 +1143,6 @@ fn get_bounds<'gc>(
     Ok(Value::Undefined)
 }
 
-fn get_rect<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    this: Option<Object<'gc>>,
-    args: &[Value<'gc>],
-) -> Result<Value<'gc>, Error<'gc>> {
-    // TODO: This should get the bounds ignoring strokes. Always equal to or smaller than getBounds.
-    // Just defer to getBounds for now. Will have to store edge_bounds vs. shape_bounds in Graphic.
-    get_bounds(activation, this, args)
-}
-
 fn mask<'gc>(
     _activation: &mut Activation<'_, 'gc>,
     this: Option<Object<'gc>>,
@@ -1133,6 +1259,11 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         ("filters", Some(filters), Some(set_filters)),
         ("transform", Some(transform), Some(set_transform)),
         ("scrollRect", Some(scroll_rect), Some(set_scroll_rect)),
+        (
+            "scale9Grid",
+            Some(scale_nine_grid),
+            Some(set_scale_nine_grid),
+        ),
         ("mask", Some(mask), Some(set_mask)),
         (
             "cacheAsBitmap",
@@ -2,6 +2,7 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::class::Class;
+use crate::avm2::error::{argument_error, range_error};
 use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::object::{Object, TObject};
 use crate::avm2::value::Value;
@@ -45,13 +46,40 @@ pub fn class_init<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Construct the `RangeError` thrown for child indices outside of the child
+/// list.
+fn index_out_of_bounds<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    match range_error(
+        activation,
+        "Error #2006: The supplied index is out of bounds.",
+        2006,
+    ) {
+        Ok(err) => Error::AvmError(err),
+        Err(err) => err,
+    }
+}
+
+/// Construct the `ArgumentError` thrown for display objects that are not
+/// children of the container being operated on.
+fn not_a_child<'gc>(activation: &mut Activation<'_, 'gc>) -> Error<'gc> {
+    match argument_error(
+        activation,
+        "Error #2025: The supplied DisplayObject must be a child of the caller.",
+        2025,
+    ) {
+        Ok(err) => Error::AvmError(err),
+        Err(err) => err,
+    }
+}
+
 /// Validate if we can add a child to a parent at a given index.
 ///
 /// There are several conditions which should cause an add operation to fail:
 ///
 ///  * The index is off the end of the child list of the proposed parent.
-///  * The child is already a transitive child of the proposed parent.
+///  * The child is the proposed parent, or one of its ancestors.
 fn validate_add_operation<'gc>(
+    activation: &mut Activation<'_, 'gc>,
     new_parent: DisplayObject<'gc>,
     proposed_child: DisplayObject<'gc>,
     proposed_index: usize,
@@ -60,21 +88,30 @@ fn validate_add_operation<'gc>(
         .as_container()
         .ok_or("ArgumentError: Parent is not a DisplayObjectContainer")?;
 
-    let mut checking_parent = Some(new_parent);
+    if DisplayObject::ptr_eq(new_parent, proposed_child) {
+        return Err(Error::AvmError(argument_error(
+            activation,
+            "Error #2024: An object cannot be added as a child of itself.",
+            2024,
+        )?));
+    }
+
+    let mut checking_parent = new_parent.parent();
 
     while let Some(tp) = checking_parent {
         if DisplayObject::ptr_eq(tp, proposed_child) {
-            return Err(
-                "ArgumentError: Proposed child is an ancestor of the proposed parent, you cannot add the child to the parent"
-                    .into(),
-            );
+            return Err(Error::AvmError(argument_error(
+                activation,
+                "Error #2150: An object cannot be added as a child to one of it's children (or children's children, etc.).",
+                2150,
+            )?));
         }
 
         checking_parent = tp.parent();
     }
 
     if proposed_index > ctr.num_children() {
-        return Err("RangeError: Index position does not exist in the child list".into());
+        return Err(index_out_of_bounds(activation));
     }
 
     Ok(())
@@ -86,6 +123,7 @@ fn validate_add_operation<'gc>(
 ///
 ///  * The child is not a child of the parent
 fn validate_remove_operation<'gc>(
+    activation: &mut Activation<'_, 'gc>,
     old_parent: DisplayObject<'gc>,
     proposed_child: DisplayObject<'gc>,
 ) -> Result<(), Error<'gc>> {
@@ -99,7 +137,7 @@ fn validate_remove_operation<'gc>(
         }
     }
 
-    Err("ArgumentError: Cannot remove object from display list it is not a child of.".into())
+    Err(not_a_child(activation))
 }
 
 /// Remove an element from it's parent display list.
@@ -142,11 +180,13 @@ pub fn get_child_at<'gc>(
             .cloned()
             .unwrap_or(Value::Undefined)
             .coerce_to_i32(activation)?;
-        let child = dobj.child_by_index(index as usize).ok_or_else(|| {
-            format!("RangeError: Display object container has no child with id {index}")
-        })?;
-
-        return Ok(child.object2());
+        return match usize::try_from(index)
+            .ok()
+            .and_then(|index| dobj.child_by_index(index))
+        {
+            Some(child) => Ok(child.object2()),
+            None => Err(index_out_of_bounds(activation)),
+        };
     }
 
     Ok(Value::Undefined)
@@ -195,7 +235,7 @@ pub fn add_child<'gc>(
                 .ok_or("ArgumentError: Child not a valid display object")?;
             let target_index = ctr.num_children();
 
-            validate_add_operation(parent, child, target_index)?;
+            validate_add_operation(activation, parent, child, target_index)?;
             add_child_to_displaylist(&mut activation.context, parent, child, target_index);
 
             return Ok(child.object2());
@@ -225,7 +265,7 @@ pub fn add_child_at<'gc>(
             .ok_or("ArgumentError: Index to add child at not specified")?
             .coerce_to_i32(activation)? as usize;
 
-        validate_add_operation(parent, child, target_index)?;
+        validate_add_operation(activation, parent, child, target_index)?;
         add_child_to_displaylist(&mut activation.context, parent, child, target_index);
 
         return Ok(child.object2());
@@ -249,7 +289,7 @@ pub fn remove_child<'gc>(
             .and_then(|o| o.as_display_object())
             .ok_or("ArgumentError: Child not a valid display object")?;
 
-        validate_remove_operation(parent, child)?;
+        validate_remove_operation(activation, parent, child)?;
         remove_child_from_displaylist(&mut activation.context, child);
 
         return Ok(child.object2());
@@ -306,7 +346,7 @@ pub fn contains<'gc>(
 
 /// Implements `DisplayObjectContainer.getChildIndex`
 pub fn get_child_index<'gc>(
-    _activation: &mut Activation<'_, 'gc>,
+    activation: &mut Activation<'_, 'gc>,
     this: Option<Object<'gc>>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
@@ -329,7 +369,7 @@ pub fn get_child_index<'gc>(
         }
     }
 
-    Err("ArgumentError: Child is not a child of this object".into())
+    Err(not_a_child(activation))
 }
 
 /// Implements `DisplayObjectContainer.removeChildAt`
@@ -347,12 +387,7 @@ pub fn remove_child_at<'gc>(
                 .coerce_to_i32(activation)?;
 
             if target_child >= ctr.num_children() as i32 || target_child < 0 {
-                return Err(format!(
-                    "RangeError: {} does not exist in the child list (valid range is 0 to {})",
-                    target_child,
-                    ctr.num_children()
-                )
-                .into());
+                return Err(index_out_of_bounds(activation));
             }
 
             let child = ctr.child_by_index(target_child as usize).unwrap();
@@ -385,26 +420,12 @@ pub fn remove_children<'gc>(
                 .unwrap_or_else(|| i32::MAX.into())
                 .coerce_to_i32(activation)?;
 
-            if from >= ctr.num_children() as i32 || from < 0 {
-                return Err(format!(
-                    "RangeError: Starting position {} does not exist in the child list (valid range is 0 to {})",
-                    from,
-                    ctr.num_children()
-                )
-                .into());
-            }
-
-            if (to >= ctr.num_children() as i32 || to < 0) && to != i32::MAX {
-                return Err(format!(
-                    "RangeError: Ending position {} does not exist in the child list (valid range is 0 to {})",
-                    to,
-                    ctr.num_children()
-                )
-                .into());
-            }
-
-            if from > to {
-                return Err(format!("RangeError: Range {from} to {to} is invalid").into());
+            if from >= ctr.num_children() as i32
+                || from < 0
+                || ((to >= ctr.num_children() as i32 || to < 0) && to != i32::MAX)
+                || from > to
+            {
+                return Err(index_out_of_bounds(activation));
             }
 
             ctr.remove_range(
@@ -435,14 +456,22 @@ pub fn set_child_index<'gc>(
             .get(1)
             .cloned()
             .ok_or("ArgumentError: Index to add child at not specified")?
-            .coerce_to_i32(activation)? as usize;
+            .coerce_to_i32(activation)?;
 
         let child_parent = child.parent();
         if child_parent.is_none() || !DisplayObject::ptr_eq(child_parent.unwrap(), parent) {
-            return Err("ArgumentError: Given child is not a child of this display object".into());
+            return Err(not_a_child(activation));
         }
 
-        validate_add_operation(parent, child, target_index)?;
+        // Unlike `addChildAt`, the child cannot be moved past the end of the
+        // child list, as it is already in it.
+        let num_children = parent.as_container().map_or(0, |ctr| ctr.num_children());
+        let target_index = match usize::try_from(target_index) {
+            Ok(index) if index < num_children => index,
+            _ => return Err(index_out_of_bounds(activation)),
+        };
+
+        validate_add_operation(activation, parent, child, target_index)?;
         add_child_to_displaylist(&mut activation.context, parent, child, target_index);
 
         return Ok(child.object2());
@@ -471,12 +500,8 @@ pub fn swap_children_at<'gc>(
                 .coerce_to_i32(activation)?;
             let bounds = ctr.num_children();
 
-            if index0 < 0 || index0 as usize >= bounds {
-                return Err(format!("RangeError: Index {index0} is out of bounds").into());
-            }
-
-            if index1 < 0 || index1 as usize >= bounds {
-                return Err(format!("RangeError: Index {index1} is out of bounds").into());
+            if index0 < 0 || index0 as usize >= bounds || index1 < 0 || index1 as usize >= bounds {
+                return Err(index_out_of_bounds(activation));
             }
 
             let child0 = ctr.child_by_index(index0 as usize).unwrap();
@@ -517,12 +542,14 @@ pub fn swap_children<'gc>(
 
             let index0 = ctr
                 .iter_render_list()
-                .position(|a| DisplayObject::ptr_eq(a, child0))
-                .ok_or("ArgumentError: Child is not a child of this display object")?;
+                .position(|a| DisplayObject::ptr_eq(a, child0));
             let index1 = ctr
                 .iter_render_list()
-                .position(|a| DisplayObject::ptr_eq(a, child1))
-                .ok_or("ArgumentError: Child is not a child of this display object")?;
+                .position(|a| DisplayObject::ptr_eq(a, child1));
+            let (index0, index1) = match (index0, index1) {
+                (Some(index0), Some(index1)) => (index0, index1),
+                _ => return Err(not_a_child(activation)),
+            };
 
             child0.set_placed_by_script(activation.context.gc_context, true);
             child1.set_placed_by_script(activation.context.gc_context, true);
@@ -2,6 +2,7 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::class::{Class, ClassAttributes};
+use crate::avm2::error::argument_error;
 use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::object::{stage_allocator, Object, TObject};
 use crate::avm2::value::Value;
@@ -13,6 +14,8 @@ use crate::display_object::TDisplayObject;
 use crate::drawing::Drawing;
 use crate::string::WStr;
 use gc_arena::{GcCell, MutationContext};
+use ruffle_render::bitmap::BitmapInfo;
+use ruffle_render::matrix::Matrix;
 use ruffle_render::shape_utils::DrawCommand;
 use std::f64::consts::FRAC_1_SQRT_2;
 use swf::{Color, FillStyle, Fixed8, LineCapStyle, LineJoinStyle, LineStyle, Twips};
@@ -82,11 +85,61 @@ fn begin_fill<'gc>(
 
 /// Implements `Graphics.beginBitmapFill`.
 fn begin_bitmap_fill<'gc>(
-    _activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    tracing::warn!("Graphics.beginBitmapFill: not yet implemented");
+    if let Some(this) = this.and_then(|t| t.as_display_object()) {
+        let bitmap_data = match args
+            .get(0)
+            .and_then(|bitmap| bitmap.as_object())
+            .and_then(|bitmap| bitmap.as_bitmap_data())
+        {
+            Some(bitmap_data) => bitmap_data,
+            None => return Ok(Value::Undefined),
+        };
+        let mut matrix = match args.get(1) {
+            Some(Value::Object(matrix)) => {
+                crate::avm2::globals::flash::geom::transform::object_to_matrix(*matrix, activation)?
+            }
+            _ => Matrix::default(),
+        };
+        // Flash matrix is in pixels. Scale from pixels to twips.
+        matrix *= Matrix::scale(Twips::TWIPS_PER_PIXEL as f32, Twips::TWIPS_PER_PIXEL as f32);
+        let is_repeating = args
+            .get(2)
+            .cloned()
+            .unwrap_or_else(|| true.into())
+            .coerce_to_boolean();
+        let is_smoothed = args
+            .get(3)
+            .cloned()
+            .unwrap_or_else(|| false.into())
+            .coerce_to_boolean();
+
+        let mut bitmap_data = bitmap_data.write(activation.context.gc_context);
+        let handle = match bitmap_data.bitmap_handle(activation.context.renderer) {
+            Some(handle) => handle,
+            None => return Ok(Value::Undefined),
+        };
+        let bitmap = BitmapInfo {
+            handle,
+            width: bitmap_data.width() as u16,
+            height: bitmap_data.height() as u16,
+        };
+        drop(bitmap_data);
+
+        if let Some(mut draw) = this.as_drawing(activation.context.gc_context) {
+            let id = draw.add_bitmap(bitmap);
+            draw.set_fill_style(Some(FillStyle::Bitmap {
+                id,
+                matrix: matrix.into(),
+                is_smoothed,
+                is_repeating,
+            }));
+        }
+    }
+
     Ok(Value::Undefined)
 }
 
@@ -155,6 +208,113 @@ fn curve_to<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Reads the numbers out of a `Vector.<Number>` or `Vector.<int>` argument.
+fn vector_numbers<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    value: Option<&Value<'gc>>,
+) -> Result<Option<Vec<f64>>, Error<'gc>> {
+    let values: Vec<Value<'gc>> =
+        match value
+            .and_then(|value| value.as_object())
+            .and_then(|object| {
+                object
+                    .as_vector_storage()
+                    .map(|vector| vector.iter().collect())
+            }) {
+            Some(values) => values,
+            None => return Ok(None),
+        };
+    let mut numbers = Vec::with_capacity(values.len());
+    for value in values {
+        numbers.push(value.coerce_to_number(activation)?);
+    }
+    Ok(Some(numbers))
+}
+
+/// Implements `Graphics.drawTriangles`.
+fn draw_triangles<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this.and_then(|t| t.as_display_object()) {
+        let vertices: Vec<(f32, f32)> = vector_numbers(activation, args.get(0))?
+            .unwrap_or_default()
+            .chunks_exact(2)
+            .map(|xy| (xy[0] as f32, xy[1] as f32))
+            .collect();
+        let num_vertices = vertices.len() as u32;
+
+        let indices: Vec<u32> = match vector_numbers(activation, args.get(1))? {
+            Some(indices) => {
+                let mut valid_indices = Vec::with_capacity(indices.len());
+                for index in indices {
+                    if !(0.0..=u32::MAX as f64).contains(&index) || index.fract() != 0.0 {
+                        return Err(Error::AvmError(argument_error(
+                            activation,
+                            "Error #2004: One of the parameters is invalid.",
+                            2004,
+                        )?));
+                    }
+                    valid_indices.push(index as u32);
+                }
+                valid_indices
+            }
+            None => (0..num_vertices).collect(),
+        };
+
+        // Texture coordinates come as (u, v) pairs, or as (u, v, t) triples when the
+        // mesh is drawn with perspective.
+        let uvs = vector_numbers(activation, args.get(2))?.and_then(|uvt| {
+            let stride = if uvt.len() == vertices.len() * 3 {
+                3
+            } else {
+                2
+            };
+            let uvs: Vec<_> = uvt
+                .chunks_exact(stride)
+                .map(|uv| (uv[0] as f32, uv[1] as f32))
+                .collect();
+            (uvs.len() == vertices.len()).then_some(uvs)
+        });
+
+        let culling = match args.get(3) {
+            Some(culling @ Value::String(_)) => culling.coerce_to_string(activation)?,
+            _ => "none".into(),
+        };
+        let indices = indices
+            .chunks_exact(3)
+            .filter(|triangle| {
+                let corners = match triangle
+                    .iter()
+                    .map(|&i| vertices.get(i as usize))
+                    .collect::<Option<Vec<_>>>()
+                {
+                    Some(corners) => corners,
+                    None => return false,
+                };
+                let [(x0, y0), (x1, y1), (x2, y2)] = [*corners[0], *corners[1], *corners[2]];
+                let winding = (x1 - x0) * (y2 - y0) - (x2 - x0) * (y1 - y0);
+                if &culling == b"positive" {
+                    winding <= 0.0
+                } else if &culling == b"negative" {
+                    winding >= 0.0
+                } else {
+                    true
+                }
+            })
+            .flatten()
+            .copied()
+            .collect();
+
+        if let Some(mut draw) = this.as_drawing(activation.context.gc_context) {
+            draw.draw_triangles(vertices, indices, uvs);
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
 /// Implements `Graphics.endFill`.
 fn end_fill<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -820,6 +980,7 @@ pub fn create_class<'gc>(mc: MutationContext<'gc, '_>) -> GcCell<'gc, Class<'gc>
         ("drawRoundRect", draw_round_rect),
         ("drawCircle", draw_circle),
         ("drawEllipse", draw_ellipse),
+        ("drawTriangles", draw_triangles),
     ];
     write.define_public_builtin_instance_methods(mc, PUBLIC_INSTANCE_METHODS);
 
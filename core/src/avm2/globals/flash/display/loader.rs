@@ -1,6 +1,7 @@
 //! `flash.display.Loader` builtin/prototype
 
 use crate::avm2::activation::Activation;
+use crate::avm2::error::type_error;
 use crate::avm2::object::LoaderInfoObject;
 use crate::avm2::object::TObject;
 use crate::avm2::value::Value;
@@ -55,7 +56,16 @@ pub fn load<'gc>(
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(this) = this {
-        let url_request = args[0].as_object().unwrap();
+        let url_request = match args.get(0).and_then(|v| v.as_object()) {
+            Some(url_request) => url_request,
+            None => {
+                return Err(Error::AvmError(type_error(
+                    activation,
+                    "Error #2007: Parameter request must be non-null.",
+                    2007,
+                )?));
+            }
+        };
         let context = args
             .get(1)
             .and_then(|v| v.coerce_to_object(activation).ok());
@@ -98,8 +108,20 @@ pub fn load_bytes<'gc>(
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(this) = this {
-        let arg0 = args[0].as_object().unwrap();
-        let bytearray = arg0.as_bytearray().unwrap();
+        let bytes = match args
+            .get(0)
+            .and_then(|v| v.as_object())
+            .and_then(|o| o.as_bytearray().map(|b| b.bytes().to_vec()))
+        {
+            Some(bytes) => bytes,
+            None => {
+                return Err(Error::AvmError(type_error(
+                    activation,
+                    "Error #2007: Parameter bytes must be non-null.",
+                    2007,
+                )?));
+            }
+        };
         let context = args
             .get(1)
             .and_then(|v| v.coerce_to_object(activation).ok());
@@ -121,7 +143,7 @@ pub fn load_bytes<'gc>(
         let future = activation.context.load_manager.load_movie_into_clip_bytes(
             activation.context.player.clone(),
             content.into(),
-            bytearray.bytes().to_vec(),
+            bytes,
             Some(MovieLoaderEventHandler::Avm2LoaderInfo(loader_info)),
             context,
         );
@@ -3,6 +3,7 @@
 use crate::avm2::activation::Activation;
 use crate::avm2::array::ArrayStorage;
 use crate::avm2::class::Class;
+use crate::avm2::error::argument_error;
 use crate::avm2::method::{Method, NativeMethodImpl};
 use crate::avm2::object::{ArrayObject, Object, TObject};
 use crate::avm2::value::Value;
@@ -405,18 +406,34 @@ pub fn goto_frame<'gc>(
                     //the requested frame exists within that scene.
                     let scene = scene.coerce_to_string(activation)?;
                     if !mc.frame_exists_within_scene(&frame_or_label, &scene, &activation.context) {
-                        return Err(format!(
-                            "ArgumentError: Frame label {frame_or_label} not found in scene {scene}",
-                        )
-                        .into());
+                        return Err(Error::AvmError(argument_error(
+                            activation,
+                            &format!(
+                                "Error #2109: Frame label {frame_or_label} not found in scene {scene}.",
+                            ),
+                            2109,
+                        )?));
                     }
                 }
 
                 let frame = mc.frame_label_to_number(&frame_or_label, &activation.context);
                 if activation.context.swf.version() >= 11 {
-                    frame.ok_or_else(|| {
-                        format!("ArgumentError: {frame_or_label} is not a valid frame label.")
-                    })? as i32
+                    match frame {
+                        Some(frame) => frame as i32,
+                        None => {
+                            let scene = mc
+                                .current_scene()
+                                .map(|scene| scene.name)
+                                .unwrap_or_else(|| WString::from_utf8("Scene 1"));
+                            return Err(Error::AvmError(argument_error(
+                                activation,
+                                &format!(
+                                    "Error #2109: Frame label {frame_or_label} not found in scene {scene}.",
+                                ),
+                                2109,
+                            )?));
+                        }
+                    }
                 } else {
                     frame.unwrap_or(0) as i32 // Old swf versions silently jump to frame 1 for invalid labels.
                 }
@@ -494,7 +494,10 @@ pub fn set_frame_rate<'gc>(
         .cloned()
         .unwrap_or(Value::Undefined)
         .coerce_to_number(activation)?;
-    *activation.context.frame_rate = new_frame_rate;
+    // Flash ignores invalid frame rates, and clamps the rest to 0.01-1000 fps.
+    if !new_frame_rate.is_nan() {
+        *activation.context.frame_rate = new_frame_rate.clamp(0.01, 1000.0);
+    }
 
     Ok(Value::Undefined)
 }
@@ -692,10 +695,11 @@ pub fn set_quality<'gc>(
         .coerce_to_string(activation)?
         .parse()
     {
-        activation
-            .context
-            .stage
-            .set_quality(activation.context.gc_context, quality);
+        activation.context.stage.set_quality(
+            activation.context.gc_context,
+            activation.context.renderer,
+            quality,
+        );
     }
     Ok(Value::Undefined)
 }
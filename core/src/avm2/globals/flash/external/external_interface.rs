@@ -6,7 +6,7 @@ pub fn call<'gc>(
     _this: Option<Object<'gc>>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    if args.is_empty() {
+    if args.is_empty() || !activation.context.system.can_call_external_interface() {
         return Ok(Value::Null);
     }
 
@@ -33,7 +33,9 @@ pub fn get_available<'gc>(
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    Ok(activation.context.external_interface.available().into())
+    let available = activation.context.external_interface.available()
+        && activation.context.system.can_call_external_interface();
+    Ok(available.into())
 }
 
 pub fn add_callback<'gc>(
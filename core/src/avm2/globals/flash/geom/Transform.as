@@ -15,5 +15,6 @@ package flash.geom {
 
 		public native function get concatenatedColorTransform():ColorTransform;
 		public native function get concatenatedMatrix():Matrix;
+		public native function get pixelBounds():Rectangle;
 	}
 }
@@ -123,13 +123,49 @@ pub fn get_concatenated_matrix<'gc>(
     }
 }
 
+pub fn get_pixel_bounds<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.unwrap();
+
+    // The bounds of the object on the stage, rounded outwards to whole pixels.
+    let bounds = get_display_object(this, activation)?.world_bounds();
+    let x_min = bounds.x_min.to_pixels().floor();
+    let y_min = bounds.y_min.to_pixels().floor();
+    let x_max = bounds.x_max.to_pixels().ceil();
+    let y_max = bounds.y_max.to_pixels().ceil();
+    let args = [
+        x_min.into(),
+        y_min.into(),
+        (x_max - x_min).into(),
+        (y_max - y_min).into(),
+    ];
+    let object = activation
+        .avm2()
+        .classes()
+        .rectangle
+        .construct(activation, &args)?;
+    Ok(object.into())
+}
+
 pub fn get_concatenated_color_transform<'gc>(
-    _activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    tracing::warn!("Transform.concatenatedColorTransform: not yet implemented");
-    Ok(Value::Undefined)
+    let this = this.unwrap();
+
+    // Walk through parents to get combined color transform.
+    let dobj = get_display_object(this, activation)?;
+    let mut color_transform = *dobj.base().color_transform();
+    let mut node = dobj.parent();
+    while let Some(display_object) = node {
+        color_transform = *display_object.base().color_transform() * color_transform;
+        node = display_object.parent();
+    }
+    color_transform_to_object(&color_transform, activation)
 }
 
 // FIXME - handle clamping. We're throwing away precision here in converting to an integer:
@@ -0,0 +1,117 @@
+//! `flash.net.LocalConnection` native function definitions
+
+use crate::amf;
+use crate::avm2::activation::Activation;
+use crate::avm2::amf::{deserialize_value, serialize_value};
+use crate::avm2::error::argument_error;
+use crate::avm2::value::Value;
+use crate::avm2::{Error, Object};
+use crate::backend::local_connection::LocalConnectionMessage;
+use crate::local_connection::LocalConnections;
+use flash_lso::types::{AMFVersion, Value as AmfValue};
+
+/// Encodes the arguments of a `LocalConnection.send` call as a sequence of AMF0 values.
+fn encode_arguments<'gc>(activation: &mut Activation<'_, 'gc>, args: &[Value<'gc>]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    for &arg in args {
+        let value =
+            serialize_value(activation, arg, AMFVersion::AMF0).unwrap_or(AmfValue::Undefined);
+        if let Some(encoded) = amf::encode_value(value, AMFVersion::AMF0) {
+            bytes.extend(encoded);
+        }
+    }
+    bytes
+}
+
+/// Decodes the arguments of a received `LocalConnection` message.
+pub fn decode_arguments<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    bytes: &[u8],
+) -> Result<Vec<Value<'gc>>, Error<'gc>> {
+    let mut args = Vec::new();
+    let mut remaining = bytes;
+    while !remaining.is_empty() {
+        match amf::decode_value(remaining, AMFVersion::AMF0) {
+            Some((rest, value)) => {
+                args.push(deserialize_value(activation, &value)?);
+                remaining = rest;
+            }
+            None => {
+                tracing::warn!("LocalConnection: Unable to decode message arguments");
+                break;
+            }
+        }
+    }
+    Ok(args)
+}
+
+/// Native function definition for `LocalConnection.connect`
+pub fn connect<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this {
+        let name = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+
+        if name.is_empty()
+            || !LocalConnections::connect(&mut activation.context, name.to_string(), this.into())
+        {
+            return Err(Error::AvmError(argument_error(
+                activation,
+                "Error #2082: Connect failed because the object is already connected.",
+                2082,
+            )?));
+        }
+    }
+    Ok(Value::Undefined)
+}
+
+/// Native function definition for `LocalConnection.send`
+pub fn send<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this {
+        let name = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+        let method_name = args
+            .get(1)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_string(activation)?;
+
+        let arguments = encode_arguments(activation, args.get(2..).unwrap_or_default());
+        let message = LocalConnectionMessage {
+            method_name: method_name.to_string(),
+            arguments,
+        };
+        LocalConnections::send(
+            &mut activation.context,
+            this.into(),
+            &name.to_string(),
+            message,
+        );
+    }
+    Ok(Value::Undefined)
+}
+
+/// Native function definition for `LocalConnection.close`
+pub fn close<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this {
+        LocalConnections::close(&mut activation.context, this.into());
+    }
+    Ok(Value::Undefined)
+}
@@ -16,11 +16,14 @@ pub fn get_sandbox_type<'gc>(
 }
 
 pub fn allow_domain<'gc>(
-    _activation: &mut Activation<'_, 'gc>,
+    activation: &mut Activation<'_, 'gc>,
     _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    tracing::warn!("Security.allowDomain not implemented");
+    for domain in args {
+        let domain = domain.coerce_to_string(activation)?.to_string();
+        activation.context.system.allow_domain(domain);
+    }
     Ok(Value::Undefined)
 }
 
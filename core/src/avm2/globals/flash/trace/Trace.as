@@ -0,0 +1,40 @@
+package flash.trace {
+    public final class Trace {
+        public static const OFF:int = 0;
+        public static const METHODS:int = 1;
+        public static const METHODS_WITH_ARGS:int = 2;
+        public static const METHODS_AND_LINES:int = 3;
+        public static const METHODS_AND_LINES_WITH_ARGS:int = 4;
+
+        public static const FILE:int = 1;
+        public static const LISTENER:int = 2;
+
+        private static var fileLevel:int = OFF;
+        private static var listenerLevel:int = OFF;
+        private static var listener:Function = null;
+
+        // Method tracing is a debugger feature; we only remember the settings.
+        public static function setLevel(l:int, target:int = LISTENER):* {
+            if (target == FILE) {
+                fileLevel = l;
+            } else {
+                listenerLevel = l;
+            }
+        }
+
+        public static function getLevel(target:int = LISTENER):int {
+            if (target == FILE) {
+                return fileLevel;
+            }
+            return listenerLevel;
+        }
+
+        public static function setListener(f:Function):* {
+            listener = f;
+        }
+
+        public static function getListener():Function {
+            return listener;
+        }
+    }
+}
@@ -22,8 +22,7 @@ package flash.ui
 
         public static function get isSupported() : Boolean
         {
-            // TODO: return true when implementation actually affects the context menu
-            return false;
+            return true;
         }
     }
 }
@@ -1,4 +1,6 @@
 package flash.utils {
+	// FIXME - this should return `XML` once our XML implementation can represent the description
+	public native function describeType(value:*):*;
 	public native function getDefinitionByName(name:String):Object;
 	public native function getQualifiedClassName(value:*):String;
 	public native function getQualifiedSuperclassName(value:*):String;
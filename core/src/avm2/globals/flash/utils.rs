@@ -2,10 +2,9 @@
 
 use crate::avm2::object::TObject;
 use crate::avm2::QName;
-use crate::avm2::{Activation, Error, Object, Value};
+use crate::avm2::{Activation, ClassObject, Error, Object, Value};
 use crate::string::AvmString;
 use crate::string::WString;
-use instant::Instant;
 use std::fmt::Write;
 
 pub mod byte_array;
@@ -22,10 +21,7 @@ pub fn get_timer<'gc>(
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    Ok((Instant::now()
-        .duration_since(activation.context.start_time)
-        .as_millis() as u32)
-        .into())
+    Ok(activation.context.get_timer().into())
 }
 
 /// Implements `flash.utils.setInterval`
@@ -263,3 +259,91 @@ pub fn get_definition_by_name<'gc>(
     let qname = QName::from_qualified_name(name, activation.context.gc_context);
     appdomain.get_defined_value(activation, qname)
 }
+
+/// Implements `flash.utils.describeType`
+///
+/// Only the class hierarchy is described for now. Since our `XML` class can't
+/// hold content yet, the description is returned as an XML string.
+pub fn describe_type<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let val = args.get(0).copied().unwrap_or(Value::Undefined);
+    if matches!(val, Value::Null | Value::Undefined) {
+        return Ok(Value::Null);
+    }
+    let obj = val.coerce_to_object(activation)?;
+
+    let (class, is_static) = match obj.as_class_object() {
+        Some(class) => (class, true),
+        None => match obj.instance_of() {
+            Some(cls) => (cls, false),
+            None => return Ok(Value::Null),
+        },
+    };
+    let mc = activation.context.gc_context;
+    let class_name = |class: ClassObject<'gc>| {
+        let name = class.inner_class_definition().read().name();
+        escape_xml_attribute(&name.to_qualified_name(mc).to_utf8_lossy())
+    };
+
+    let class_def = class.inner_class_definition();
+    let (is_dynamic, is_final) = if is_static {
+        (true, true)
+    } else {
+        let read = class_def.read();
+        (!read.is_sealed(), read.is_final())
+    };
+    let base = if is_static {
+        Some("Class".to_string())
+    } else {
+        class.superclass_object().map(class_name)
+    };
+
+    let mut xml = format!(r#"<type name="{}""#, class_name(class));
+    if let Some(base) = &base {
+        let _ = write!(xml, r#" base="{base}""#);
+    }
+    let _ = writeln!(
+        xml,
+        r#" isDynamic="{is_dynamic}" isFinal="{is_final}" isStatic="{is_static}">"#
+    );
+
+    if is_static {
+        xml.push_str("  <extendsClass type=\"Class\"/>\n");
+        xml.push_str("  <extendsClass type=\"Object\"/>\n");
+    } else {
+        let mut interfaces = Vec::new();
+        let mut current = Some(class);
+        while let Some(cls) = current {
+            if !Object::ptr_eq(cls, class) {
+                let _ = writeln!(xml, r#"  <extendsClass type="{}"/>"#, class_name(cls));
+            }
+            for interface in cls.interfaces() {
+                if !interfaces.iter().any(|i| Object::ptr_eq(*i, interface)) {
+                    interfaces.push(interface);
+                }
+            }
+            current = cls.superclass_object();
+        }
+        for interface in interfaces {
+            let _ = writeln!(
+                xml,
+                r#"  <implementsInterface type="{}"/>"#,
+                class_name(interface)
+            );
+        }
+    }
+    xml.push_str("</type>");
+
+    Ok(AvmString::new_utf8(mc, xml).into())
+}
+
+fn escape_xml_attribute(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
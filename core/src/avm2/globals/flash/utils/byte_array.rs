@@ -1,3 +1,4 @@
+use crate::amf::{self, ObjectGraph};
 use crate::avm2::activation::Activation;
 use crate::avm2::bytearray::{Endian, ObjectEncoding};
 pub use crate::avm2::object::byte_array_allocator;
@@ -8,9 +9,7 @@ use crate::character::Character;
 use crate::string::AvmString;
 use encoding_rs::Encoding;
 use encoding_rs::UTF_8;
-use flash_lso::amf0::read::AMF0Decoder;
-use flash_lso::amf3::read::AMF3Decoder;
-use flash_lso::types::{AMFVersion, Element};
+use flash_lso::types::AMFVersion;
 
 /// Implements `flash.utils.ByteArray`'s instance constructor.
 pub fn init<'gc>(
@@ -759,23 +758,24 @@ pub fn read_object<'gc>(
             let bytes = bytearray.read_at(bytearray.bytes_available(), bytearray.position())?;
             let (bytes_left, value) = match bytearray.object_encoding() {
                 ObjectEncoding::Amf0 => {
-                    let mut decoder = AMF0Decoder::default();
-                    let (extra, amf) = decoder
-                        .parse_single_element(bytes)
-                        .map_err(|_| "Error: Invalid object")?;
+                    let (extra, amf) = amf::decode_value(bytes, AMFVersion::AMF0)
+                        .ok_or("Error: Invalid object")?;
                     (
                         extra.len(),
                         crate::avm2::amf::deserialize_value(activation, &amf)?,
                     )
                 }
                 ObjectEncoding::Amf3 => {
-                    let mut decoder = AMF3Decoder::default();
-                    let (extra, amf) = decoder
-                        .parse_single_element(bytes)
-                        .map_err(|_| "Error: Invalid object")?;
+                    let (extra, graph, amf) =
+                        amf::decode_graph(bytes).ok_or("Error: Invalid object")?;
                     (
                         extra.len(),
-                        crate::avm2::amf::deserialize_value(activation, &amf)?,
+                        crate::avm2::amf::deserialize_graph(
+                            activation,
+                            &graph,
+                            &amf,
+                            &mut Default::default(),
+                        )?,
                     )
                 }
             };
@@ -796,25 +796,29 @@ pub fn write_object<'gc>(
     if let Some(this) = this {
         if let Some(mut bytearray) = this.as_bytearray_mut(activation.context.gc_context) {
             let obj = args.get(0).cloned().unwrap_or(Value::Undefined);
-            let amf_version = match bytearray.object_encoding() {
-                ObjectEncoding::Amf0 => AMFVersion::AMF0,
-                ObjectEncoding::Amf3 => AMFVersion::AMF3,
-            };
-            if let Some(amf) = crate::avm2::amf::serialize_value(activation, obj, amf_version) {
-                let element = Element::new("", amf);
-                let mut lso = flash_lso::types::Lso::new(vec![element], "", amf_version);
-                let bytes = flash_lso::write::write_to_bytes(&mut lso)
-                    .map_err(|_| "Failed to serialize object")?;
-                // This is kind of hacky: We need to strip out the header and any padding so that we only write
-                // the value. In the future, there should be a method to do this in the flash_lso crate.
-                let element_padding = match amf_version {
-                    AMFVersion::AMF0 => 8,
-                    AMFVersion::AMF3 => 7,
-                };
-                bytearray.write_bytes(
-                    &bytes[flash_lso::write::header_length(&lso.header) + element_padding
-                        ..bytes.len() - 1],
-                )?;
+            match bytearray.object_encoding() {
+                ObjectEncoding::Amf0 => {
+                    if let Some(amf) =
+                        crate::avm2::amf::serialize_value(activation, obj, AMFVersion::AMF0)
+                    {
+                        let bytes = amf::encode_value(amf, AMFVersion::AMF0)
+                            .ok_or("Failed to serialize object")?;
+                        bytearray.write_bytes(&bytes)?;
+                    }
+                }
+                ObjectEncoding::Amf3 => {
+                    // Objects are written through the AMF3 reference table, so that
+                    // shared and cyclic references can be read back.
+                    let mut graph = ObjectGraph::new();
+                    if let Some(amf) = crate::avm2::amf::serialize_graph(
+                        activation,
+                        obj,
+                        &mut graph,
+                        &mut Default::default(),
+                    )? {
+                        bytearray.write_bytes(&amf::encode_graph(&graph, &amf))?;
+                    }
+                }
             }
         }
     }
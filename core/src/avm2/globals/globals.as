@@ -216,6 +216,8 @@ include "flash/text/TextFormatAlign.as"
 include "flash/text/TextInteractionMode.as"
 include "flash/text/TextLineMetrics.as"
 
+include "flash/trace/Trace.as"
+
 include "flash/ui/ContextMenu.as"
 include "flash/ui/ContextMenuBuiltInItems.as"
 include "flash/ui/ContextMenuItem.as"
@@ -130,6 +130,17 @@ fn class_init<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Returns the value held by a boxed `int`.
+///
+/// Values outside of the range of `Value::Integer` are held as numbers.
+fn int_value(value: Value<'_>) -> Option<i32> {
+    match value {
+        Value::Integer(number) => Some(number),
+        Value::Number(number) => Some(number as i32),
+        _ => None,
+    }
+}
+
 /// Implements `int.toExponential`
 fn to_exponential<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -138,7 +149,7 @@ fn to_exponential<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(this) = this {
         if let Some(this) = this.as_primitive() {
-            if let Value::Integer(number) = *this {
+            if let Some(number) = int_value(*this) {
                 let digits = args
                     .get(0)
                     .cloned()
@@ -172,7 +183,7 @@ fn to_fixed<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(this) = this {
         if let Some(this) = this.as_primitive() {
-            if let Value::Integer(number) = *this {
+            if let Some(number) = int_value(*this) {
                 let digits = args
                     .get(0)
                     .cloned()
@@ -203,7 +214,7 @@ fn to_precision<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(this) = this {
         if let Some(this) = this.as_primitive() {
-            if let Value::Integer(number) = *this {
+            if let Some(number) = int_value(*this) {
                 let wanted_digits = args
                     .get(0)
                     .cloned()
@@ -230,7 +241,7 @@ fn to_string<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(this) = this {
         if let Some(this) = this.as_primitive() {
-            if let Value::Integer(number) = *this {
+            if let Some(number) = int_value(*this) {
                 let radix = args
                     .get(0)
                     .cloned()
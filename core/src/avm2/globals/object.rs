@@ -224,14 +224,17 @@ pub fn is_prototype_of<'gc>(
     let search_proto: Result<Object<'gc>, Error<'gc>> =
         this.ok_or_else(|| "No valid this parameter".into());
     let search_proto = search_proto?;
-    let mut target_proto = args.get(0).cloned().unwrap_or(Value::Undefined);
+    let mut target_proto = match args.get(0) {
+        Some(Value::Object(target)) => target.proto(),
+        _ => None,
+    };
 
-    while let Value::Object(proto) = target_proto {
+    while let Some(proto) = target_proto {
         if Object::ptr_eq(search_proto, proto) {
             return Ok(true.into());
         }
 
-        target_proto = proto.proto().map(|o| o.into()).unwrap_or(Value::Undefined);
+        target_proto = proto.proto();
     }
 
     Ok(false.into())
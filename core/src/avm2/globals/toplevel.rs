@@ -1,6 +1,7 @@
 //! Global scope built-ins
 
 use crate::avm2::activation::Activation;
+use crate::avm2::error::uri_error;
 use crate::avm2::object::Object;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
@@ -145,3 +146,112 @@ pub fn escape<'gc>(
 
     Ok(AvmString::new(activation.context.gc_context, output).into())
 }
+
+pub fn unescape<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let value = match args.first() {
+        None => return Ok("undefined".into()),
+        Some(Value::Undefined) => return Ok("null".into()),
+        Some(value) => value.coerce_to_string(activation)?,
+    };
+
+    let mut output = WString::new();
+    let mut index = 0;
+    while let Some(unit) = value.get(index) {
+        if unit == u16::from(b'%') {
+            // `%uXXXX` and `%XX` each decode to a single unit.
+            let (digits, len) = if value.get(index + 1) == Some(u16::from(b'u')) {
+                (value.slice(index + 2..index + 6), 6)
+            } else {
+                (value.slice(index + 1..index + 3), 3)
+            };
+            if let Some(unit) = digits.and_then(parse_hex_units) {
+                output.push(unit);
+                index += len;
+                continue;
+            }
+        }
+
+        // Anything else, including malformed escapes, is kept as is.
+        output.push(unit);
+        index += 1;
+    }
+
+    Ok(AvmString::new(activation.context.gc_context, output).into())
+}
+
+/// Parses a string of hexadecimal digits, such as those of an escape sequence.
+fn parse_hex_units(digits: &WStr) -> Option<u16> {
+    digits.iter().try_fold(0, |value, unit| {
+        let digit = u8::try_from(unit)
+            .ok()
+            .and_then(|c| char::from(c).to_digit(16))?;
+        Some((value << 4) | digit as u16)
+    })
+}
+
+/// Characters that are never escaped by `encodeURI` and `encodeURIComponent`.
+const URI_UNRESERVED: &[u8] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ-_.!~*'()";
+
+/// Characters with a special meaning in URIs, which `encodeURI` doesn't escape.
+const URI_RESERVED: &[u8] = b";/?:@&=+$,#";
+
+pub fn encode_uri<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    encode_uri_with(activation, args, URI_RESERVED, "encodeURI")
+}
+
+pub fn encode_uri_component<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    encode_uri_with(activation, args, b"", "encodeURIComponent")
+}
+
+/// Percent-encodes the UTF-8 bytes of a string, except for unreserved
+/// characters and the characters in `keep`.
+fn encode_uri_with<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    args: &[Value<'gc>],
+    keep: &[u8],
+    function_name: &str,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let value = match args.first() {
+        None => return Ok("undefined".into()),
+        Some(Value::Undefined) => return Ok("null".into()),
+        Some(value) => value.coerce_to_string(activation)?,
+    };
+
+    let mut output = WString::new();
+    for c in value.chars() {
+        let c = match c {
+            Ok(c) => c,
+            // Unpaired surrogates can't be encoded as UTF-8.
+            Err(_) => {
+                let message =
+                    format!("Error #1052: Invalid URI passed to {function_name} function.");
+                return Err(Error::AvmError(uri_error(activation, &message, 1052)?));
+            }
+        };
+
+        let is_kept =
+            u8::try_from(c).map_or(false, |c| URI_UNRESERVED.contains(&c) || keep.contains(&c));
+        if is_kept {
+            output.push_char(c);
+        } else {
+            for byte in c.encode_utf8(&mut [0; 4]).bytes() {
+                output.push_str(WStr::from_units(format!("%{byte:02X}").as_bytes()));
+            }
+        }
+    }
+
+    Ok(AvmString::new(activation.context.gc_context, output).into())
+}
@@ -129,6 +129,17 @@ fn class_init<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Returns the value held by a boxed `uint`.
+///
+/// Values outside of the range of `Value::Integer` are held as numbers.
+fn uint_value(value: Value<'_>) -> Option<u32> {
+    match value {
+        Value::Integer(number) => Some(number as u32),
+        Value::Number(number) => Some(number as u32),
+        _ => None,
+    }
+}
+
 /// Implements `uint.toExponential`
 fn to_exponential<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -137,7 +148,7 @@ fn to_exponential<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(this) = this {
         if let Some(this) = this.as_primitive() {
-            if let Value::Integer(number) = *this {
+            if let Some(number) = uint_value(*this) {
                 let digits = args
                     .get(0)
                     .cloned()
@@ -171,7 +182,7 @@ fn to_fixed<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(this) = this {
         if let Some(this) = this.as_primitive() {
-            if let Value::Integer(number) = *this {
+            if let Some(number) = uint_value(*this) {
                 let digits = args
                     .get(0)
                     .cloned()
@@ -202,7 +213,7 @@ fn to_precision<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(this) = this {
         if let Some(this) = this.as_primitive() {
-            if let Value::Integer(number) = *this {
+            if let Some(number) = uint_value(*this) {
                 let wanted_digits = args
                     .get(0)
                     .cloned()
@@ -229,7 +240,7 @@ fn to_string<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     if let Some(this) = this {
         if let Some(this) = this.as_primitive() {
-            if let Value::Integer(number) = *this {
+            if let Some(number) = uint_value(*this) {
                 let radix = args
                     .get(0)
                     .cloned()
@@ -5,6 +5,7 @@ use crate::avm2::array::ArrayStorage;
 use crate::avm2::bytearray::ByteArrayStorage;
 use crate::avm2::class::Class;
 use crate::avm2::domain::Domain;
+use crate::avm2::error::property_reference_error;
 use crate::avm2::events::{DispatchList, Event};
 use crate::avm2::function::Executable;
 use crate::avm2::property::Property;
@@ -182,7 +183,13 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
                 self.call_method(get, &[], activation)
             }
             Some(Property::Virtual { get: None, .. }) => {
-                Err("Illegal read of write-only property".into())
+                Err(Error::AvmError(property_reference_error(
+                    activation,
+                    "Illegal read of write-only property",
+                    multiname,
+                    self.instance_of(),
+                    1077,
+                )?))
             }
             None => self.get_property_local(multiname, activation),
         }
@@ -228,13 +235,31 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
                     activation.context.gc_context,
                 )
             }
-            Some(Property::ConstSlot { .. }) => Err("Illegal write to read-only property".into()),
-            Some(Property::Method { .. }) => Err("Cannot assign to a method".into()),
+            Some(Property::ConstSlot { .. }) => Err(Error::AvmError(property_reference_error(
+                activation,
+                "Illegal write to read-only property",
+                multiname,
+                self.instance_of(),
+                1074,
+            )?)),
+            Some(Property::Method { .. }) => Err(Error::AvmError(property_reference_error(
+                activation,
+                "Cannot assign to a method",
+                multiname,
+                self.instance_of(),
+                1037,
+            )?)),
             Some(Property::Virtual { set: Some(set), .. }) => {
                 self.call_method(set, &[value], activation).map(|_| ())
             }
             Some(Property::Virtual { set: None, .. }) => {
-                Err("Illegal write to read-only property".into())
+                Err(Error::AvmError(property_reference_error(
+                    activation,
+                    "Illegal write to read-only property",
+                    multiname,
+                    self.instance_of(),
+                    1074,
+                )?))
             }
             None => self.set_property_local(multiname, value, activation),
         }
@@ -281,12 +306,24 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
                     activation.context.gc_context,
                 )
             }
-            Some(Property::Method { .. }) => Err("Cannot assign to a method".into()),
+            Some(Property::Method { .. }) => Err(Error::AvmError(property_reference_error(
+                activation,
+                "Cannot assign to a method",
+                multiname,
+                self.instance_of(),
+                1037,
+            )?)),
             Some(Property::Virtual { set: Some(set), .. }) => {
                 self.call_method(set, &[value], activation).map(|_| ())
             }
             Some(Property::Virtual { set: None, .. }) => {
-                Err("Illegal write to read-only property".into())
+                Err(Error::AvmError(property_reference_error(
+                    activation,
+                    "Illegal write to read-only property",
+                    multiname,
+                    self.instance_of(),
+                    1074,
+                )?))
             }
             None => self.init_property_local(multiname, value, activation),
         }
@@ -380,7 +417,13 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
                 obj.call(Some(self.into()), arguments, activation)
             }
             Some(Property::Virtual { get: None, .. }) => {
-                Err("Illegal read of write-only property".into())
+                Err(Error::AvmError(property_reference_error(
+                    activation,
+                    "Illegal read of write-only property",
+                    multiname,
+                    self.instance_of(),
+                    1077,
+                )?))
             }
             None => self.call_property_local(multiname, arguments, activation),
         }
@@ -458,12 +501,17 @@ pub trait TObject<'gc>: 'gc + Collect + Debug + Into<Object<'gc>> + Clone + Copy
     /// Indicates whether or not a property exists on an object.
     fn has_property(self, name: &Multiname<'gc>) -> bool {
         if self.has_own_property(name) {
-            true
-        } else if let Some(proto) = self.proto() {
-            proto.has_own_property(name)
-        } else {
-            false
+            return true;
+        }
+
+        let mut proto = self.proto();
+        while let Some(this_proto) = proto {
+            if this_proto.has_own_property(name) {
+                return true;
+            }
+            proto = this_proto.proto();
         }
+        false
     }
 
     /// Indicates whether or not a property or trait exists on an object and is
@@ -333,7 +333,12 @@ impl<'gc> ClassObject<'gc> {
                 )
                 .into());
             }
-            interfaces.push(iface_class);
+            // Interfaces inherited through this one are implemented too.
+            for iface in std::iter::once(iface_class).chain(iface_class.interfaces()) {
+                if !interfaces.iter().any(|i| Object::ptr_eq(*i, iface)) {
+                    interfaces.push(iface);
+                }
+            }
         }
 
         if !interfaces.is_empty() {
@@ -1,6 +1,7 @@
 //! Default AVM2 object impl
 
 use crate::avm2::activation::Activation;
+use crate::avm2::error::property_reference_error;
 use crate::avm2::object::{ClassObject, FunctionObject, Object, ObjectPtr, TObject};
 use crate::avm2::value::Value;
 use crate::avm2::vtable::VTable;
@@ -226,13 +227,13 @@ impl<'gc> ScriptObjectData<'gc> {
             .map(|cls| cls.inner_class_definition().read().is_sealed())
             .unwrap_or(false)
         {
-            return Err(format!(
-                "Cannot set undefined property {} on {:?}",
-                multiname.to_qualified_name(activation.context.gc_context),
-                self.instance_of()
-                    .map(|cls| cls.inner_class_definition().read().name()),
-            )
-            .into());
+            return Err(Error::AvmError(property_reference_error(
+                activation,
+                "Cannot create property",
+                multiname,
+                self.instance_of(),
+                1056,
+            )?));
         }
 
         if !multiname.contains_public_namespace() {
@@ -138,13 +138,18 @@ impl<'gc> ScopeChain<'gc> {
                 let values = scope.values();
 
                 // We search the dynamic properties if either conditions are met:
-                // 1. Scope is a `with` scope
+                // 1. Scope is a `with` scope, in which case the prototype chain
+                //    is searched as well
                 // 2. We are at depth 0 (global scope)
                 //
                 // But no matter what, we always search traits first.
                 if values.has_trait(multiname) {
                     return Ok(Some(values));
-                } else if scope.with() || depth == 0 {
+                } else if scope.with() {
+                    if values.has_property(multiname) {
+                        return Ok(Some(values));
+                    }
+                } else if depth == 0 {
                     if values.has_own_property(multiname) {
                         return Ok(Some(values));
                     }
@@ -186,10 +191,15 @@ pub fn search_scope_stack<'gc>(
 
         if values.has_trait(multiname) {
             return Ok(Some(values));
-        } else if scope.with() || (global && depth == 0) {
-            // We search the dynamic properties if either conditions are met:
-            // 1. Scope is a `with` scope
-            // 2. We are at depth 0 AND we are at global$init (script initializer).
+        } else if scope.with() {
+            // `with` scopes search dynamic properties, including those on the
+            // prototype chain.
+            if values.has_property(multiname) {
+                return Ok(Some(values));
+            }
+        } else if global && depth == 0 {
+            // We are at depth 0 AND we are at global$init (script initializer),
+            // so the global scope's own dynamic properties are searched too.
             if values.has_own_property(multiname) {
                 return Ok(Some(values));
             }
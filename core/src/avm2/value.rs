@@ -1,6 +1,7 @@
 //! AVM2 values
 
 use crate::avm2::activation::Activation;
+use crate::avm2::error::type_error;
 use crate::avm2::globals::NS_VECTOR;
 use crate::avm2::object::{ClassObject, NamespaceObject, Object, PrimitiveObject, TObject};
 use crate::avm2::script::TranslationUnit;
@@ -593,6 +594,20 @@ impl<'gc> Value<'gc> {
         !matches!(self, Value::Object(_))
     }
 
+    /// Get the array index this value names, if it is a non-negative integer.
+    ///
+    /// This allows array accesses to skip converting the index to a string
+    /// and back.
+    pub fn as_array_index(&self) -> Option<usize> {
+        match *self {
+            Value::Integer(i) if i >= 0 => Some(i as usize),
+            Value::Number(n) if n >= 0.0 && n <= u32::MAX as f64 && n.fract() == 0.0 => {
+                Some(n as usize)
+            }
+            _ => None,
+        }
+    }
+
     /// Coerce the value to a boolean.
     ///
     /// Boolean coercion happens according to the rules specified in the ES4
@@ -655,7 +670,14 @@ impl<'gc> Value<'gc> {
                     return Ok(prim);
                 }
 
-                Err("TypeError: cannot convert object to string".into())
+                Err(Error::AvmError(type_error(
+                    activation,
+                    &format!(
+                        "Error #1050: Cannot convert {} to primitive.",
+                        object.instance_of_class_name(activation.context.gc_context)
+                    ),
+                    1050,
+                )?))
             }
             Value::Object(o) if hint == Hint::Number => {
                 let mut prim = *self;
@@ -681,7 +703,14 @@ impl<'gc> Value<'gc> {
                     return Ok(prim);
                 }
 
-                Err("TypeError: cannot convert object to number".into())
+                Err(Error::AvmError(type_error(
+                    activation,
+                    &format!(
+                        "Error #1050: Cannot convert {} to primitive.",
+                        object.instance_of_class_name(activation.context.gc_context)
+                    ),
+                    1050,
+                )?))
             }
             _ => Ok(*self),
         }
@@ -992,9 +1021,21 @@ impl<'gc> Value<'gc> {
             }
         }
 
-        let name = class.inner_class_definition().read().name();
+        let from = match self {
+            Value::Object(o) => o.instance_of_class_name(activation.context.gc_context),
+            _ => self.coerce_to_string(activation)?,
+        };
+        let name = class
+            .inner_class_definition()
+            .read()
+            .name()
+            .to_qualified_name(activation.context.gc_context);
 
-        Err(format!("Cannot coerce {self:?} to an {name:?}").into())
+        Err(Error::AvmError(type_error(
+            activation,
+            &format!("Error #1034: Type Coercion failed: cannot convert {from} to {name}."),
+            1034,
+        )?))
     }
 
     /// Determine if this value is any kind of number.
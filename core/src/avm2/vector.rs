@@ -123,6 +123,8 @@ impl<'gc> VectorStorage<'gc> {
             Value::Integer(0)
         } else if Object::ptr_eq(self.value_type, activation.avm2().classes().number) {
             Value::Number(0.0)
+        } else if Object::ptr_eq(self.value_type, activation.avm2().classes().boolean) {
+            Value::Bool(false)
         } else {
             Value::Null
         }
@@ -334,7 +336,14 @@ impl<'gc> VectorStorage<'gc> {
         };
 
         if position >= self.storage.len() {
-            Err(format!("RangeError: Index {position} extends beyond the end of the vector").into())
+            Err(Error::AvmError(range_error(
+                activation,
+                &format!(
+                    "Error #1125: The index {position} is out of range {}.",
+                    self.storage.len()
+                ),
+                1125,
+            )?))
         } else {
             Ok(self.storage.remove(position))
         }
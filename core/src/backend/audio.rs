@@ -707,3 +707,199 @@ impl Default for SoundTransform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::PlayerBuilder;
+    use crate::tag_utils::SwfMovie;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    /// Records the sound transforms set by the audio manager, and reports the
+    /// position of every sound from a clock controlled by the test.
+    #[derive(Default)]
+    struct TestAudioBackend {
+        inner: NullAudioBackend,
+        transforms: Vec<SoundTransform>,
+        position: Rc<Cell<f64>>,
+    }
+
+    impl AudioBackend for TestAudioBackend {
+        fn play(&mut self) {}
+        fn pause(&mut self) {}
+        fn register_sound(&mut self, sound: &swf::Sound) -> Result<SoundHandle, RegisterError> {
+            self.inner.register_sound(sound)
+        }
+        fn register_mp3(&mut self, data: &[u8]) -> Result<SoundHandle, DecodeError> {
+            self.inner.register_mp3(data)
+        }
+        fn start_sound(
+            &mut self,
+            sound: SoundHandle,
+            sound_info: &swf::SoundInfo,
+        ) -> Result<SoundInstanceHandle, DecodeError> {
+            self.inner.start_sound(sound, sound_info)
+        }
+        fn start_stream(
+            &mut self,
+            stream_handle: Option<SoundHandle>,
+            clip_frame: u16,
+            clip_data: crate::tag_utils::SwfSlice,
+            handle: &swf::SoundStreamHead,
+        ) -> Result<SoundInstanceHandle, DecodeError> {
+            self.inner
+                .start_stream(stream_handle, clip_frame, clip_data, handle)
+        }
+        fn stop_sound(&mut self, _sound: SoundInstanceHandle) {}
+        fn stop_all_sounds(&mut self) {}
+        fn get_sound_position(&self, _instance: SoundInstanceHandle) -> Option<f64> {
+            Some(self.position.get())
+        }
+        fn get_sound_duration(&self, sound: SoundHandle) -> Option<f64> {
+            self.inner.get_sound_duration(sound)
+        }
+        fn get_sound_size(&self, sound: SoundHandle) -> Option<u32> {
+            self.inner.get_sound_size(sound)
+        }
+        fn get_sound_format(&self, sound: SoundHandle) -> Option<&swf::SoundFormat> {
+            self.inner.get_sound_format(sound)
+        }
+        fn set_sound_transform(
+            &mut self,
+            _instance: SoundInstanceHandle,
+            transform: SoundTransform,
+        ) {
+            self.transforms.push(transform);
+        }
+        fn get_sound_peak(&mut self, _instance: SoundInstanceHandle) -> Option<[f32; 2]> {
+            None
+        }
+        fn volume(&self) -> f32 {
+            self.inner.volume()
+        }
+        fn set_volume(&mut self, volume: f32) {
+            self.inner.set_volume(volume)
+        }
+        fn get_sample_history(&self) -> [[f32; 2]; 1024] {
+            self.inner.get_sample_history()
+        }
+    }
+
+    /// The left and right output gains of a transform for a signal playing in both channels.
+    fn gains(transform: &SoundTransform) -> [f32; 2] {
+        [
+            transform.left_to_left + transform.right_to_left,
+            transform.left_to_right + transform.right_to_right,
+        ]
+    }
+
+    fn volume_and_pan(volume: i32, pan: i32) -> display_object::SoundTransform {
+        let mut transform = display_object::SoundTransform {
+            volume,
+            ..Default::default()
+        };
+        transform.set_pan(pan);
+        transform
+    }
+
+    #[test]
+    fn volume_and_pan_gains() {
+        let transform: SoundTransform = volume_and_pan(50, -100).into();
+        assert_eq!(gains(&transform), [0.5, 0.0]);
+
+        let transform: SoundTransform = volume_and_pan(50, 50).into();
+        assert_eq!(gains(&transform), [0.25, 0.5]);
+    }
+
+    #[test]
+    fn global_and_local_transforms_compose() {
+        let mut audio = TestAudioBackend::default();
+        let mut manager = AudioManager::<'static>::new();
+        let instance = manager
+            .start_sound(
+                &mut audio,
+                SoundHandle::from_raw_parts(0, 0),
+                &swf::SoundInfo {
+                    event: swf::SoundEvent::Event,
+                    in_sample: None,
+                    out_sample: None,
+                    num_loops: 1,
+                    envelope: None,
+                },
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(gains(&audio.transforms.pop().unwrap()), [1.0, 1.0]);
+
+        manager.set_local_sound_transform(instance, volume_and_pan(50, -100));
+        manager.set_global_sound_transform(volume_and_pan(50, 0));
+        manager.update_sound_transforms(&mut audio);
+        assert_eq!(gains(&audio.transforms.pop().unwrap()), [0.25, 0.0]);
+
+        // Transforms are only sent to the backend when they change.
+        manager.update_sound_transforms(&mut audio);
+        assert!(audio.transforms.is_empty());
+    }
+
+    #[test]
+    fn timeline_follows_stream_sound() {
+        let format = swf::SoundFormat {
+            compression: swf::AudioCompression::Uncompressed,
+            sample_rate: 44100,
+            is_stereo: true,
+            is_16_bit: true,
+        };
+        let stream_head = swf::SoundStreamHead {
+            stream_format: format.clone(),
+            playback_format: format,
+            num_samples_per_block: 4410,
+            latency_seek: 0,
+        };
+        let mut header = swf::Header::default_with_swf_version(10);
+        header.frame_rate = swf::Fixed8::from_f32(10.0);
+        header.num_frames = 30;
+        let mut tags = vec![swf::Tag::SoundStreamHead(Box::new(stream_head))];
+        for _ in 0..header.num_frames {
+            tags.extend([swf::Tag::SoundStreamBlock(&[]), swf::Tag::ShowFrame]);
+        }
+        let mut data = Vec::new();
+        swf::write_swf(&header, &tags, &mut data).unwrap();
+
+        let audio = TestAudioBackend::default();
+        let position = audio.position.clone();
+        let player = PlayerBuilder::new()
+            .with_audio(audio)
+            .with_movie(SwfMovie::from_data(&data, None, None).unwrap())
+            .with_autoplay(true)
+            .build();
+        let mut player = player.lock().unwrap();
+
+        // Frame 1 starts the stream, and frame 2 is 100ms into it.
+        player.tick(100.0);
+        player.tick(100.0);
+        assert_eq!(player.current_frame(), Some(2));
+
+        // The audio is 300ms ahead after frame 3, so the timeline skips ahead to catch up.
+        position.set(500.0);
+        player.tick(100.0);
+        assert_eq!(player.current_frame(), Some(3));
+        position.set(600.0);
+        player.tick(100.0);
+        assert_eq!(player.current_frame(), Some(7));
+
+        // The audio is 400ms behind after frame 8, so the timeline holds until it catches up.
+        position.set(300.0);
+        player.tick(100.0);
+        assert_eq!(player.current_frame(), Some(8));
+        for _ in 0..4 {
+            position.set(position.get() + 100.0);
+            player.tick(100.0);
+            assert_eq!(player.current_frame(), Some(8));
+        }
+        position.set(position.get() + 100.0);
+        player.tick(100.0);
+        assert_eq!(player.current_frame(), Some(9));
+    }
+}
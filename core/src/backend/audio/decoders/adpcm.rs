@@ -108,22 +108,9 @@ impl<R: Read> AdpcmDecoder<R> {
             decoder: SAMPLE_DELTA_CALCULATOR[bits_per_sample - 2],
         })
     }
-}
-
-impl<R: Read> Iterator for AdpcmDecoder<R> {
-    type Item = [i16; 2];
-
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.sample_num == 0 {
-            // The initial sample values are NOT byte-aligned.
-            for channel in &mut self.channels {
-                channel.sample = self.inner.read_signed(16).ok()?;
-                channel.step_index = self.inner.read::<u16>(6).ok()? as i16;
-            }
-        }
-
-        self.sample_num = (self.sample_num + 1) % 4095;
 
+    /// Decodes the next coded sample of each channel.
+    fn decode_sample(&mut self) -> Option<()> {
         for channel in &mut self.channels {
             let step = STEP_TABLE[channel.step_index as usize];
 
@@ -145,6 +132,27 @@ impl<R: Read> Iterator for AdpcmDecoder<R> {
             channel.step_index = channel.step_index.clamp(0, STEP_TABLE.len() as i16 - 1);
         }
 
+        Some(())
+    }
+}
+
+impl<R: Read> Iterator for AdpcmDecoder<R> {
+    type Item = [i16; 2];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.sample_num == 0 {
+            // The initial sample values are NOT byte-aligned.
+            for channel in &mut self.channels {
+                channel.sample = self.inner.read_signed(16).ok()?;
+                channel.step_index = self.inner.read::<u16>(6).ok()? as i16;
+            }
+        } else {
+            self.decode_sample()?;
+        }
+
+        // Each packet holds its initial sample followed by 4095 coded samples.
+        self.sample_num = (self.sample_num + 1) % 4096;
+
         let left = self.channels[0].sample;
         let right = self.channels.get(1).map_or(left, |c| c.sample);
         Some([left, right])
@@ -176,3 +184,48 @@ impl<R: AsRef<[u8]> + Default + Send + Sync> SeekableDecoder for AdpcmDecoder<Cu
             .expect("Existing valid decoder should be valid when recreated");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Packs a string of binary digits into bytes, ignoring whitespace and
+    /// padding the last byte with zeros.
+    fn pack_bits(bits: &str) -> Vec<u8> {
+        let bits: Vec<u8> = bits
+            .bytes()
+            .filter(|b| !b.is_ascii_whitespace())
+            .map(|b| b - b'0')
+            .collect();
+        bits.chunks(8)
+            .map(|chunk| {
+                (0..8).fold(0, |byte, i| {
+                    (byte << 1) | chunk.get(i).copied().unwrap_or(0)
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn decode_mono_4_bit_packet() {
+        // 4-bit codes, an initial sample of 1000 at step index 0, and then the
+        // codes +7, -7 and +0.
+        let data = pack_bits("10 0000001111101000 000000 0111 1111 0000");
+        let decoder = AdpcmDecoder::new(Cursor::new(data), false, 11025).unwrap();
+        let samples: Vec<_> = decoder.take(4).collect();
+        assert_eq!(
+            samples,
+            [[1000, 1000], [1011, 1011], [981, 981], [985, 985]]
+        );
+    }
+
+    #[test]
+    fn decode_stereo_2_bit_packet() {
+        // 2-bit codes, initial samples of -2 and 300 at step index 0, and then
+        // the codes +1 and -1 for the left and right channel.
+        let data = pack_bits("00 1111111111111110 000000 0000000100101100 000000 01 11");
+        let decoder = AdpcmDecoder::new(Cursor::new(data), true, 11025).unwrap();
+        let samples: Vec<_> = decoder.take(2).collect();
+        assert_eq!(samples, [[-2, 300], [8, 290]]);
+    }
+}
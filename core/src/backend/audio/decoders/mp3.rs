@@ -199,3 +199,39 @@ pub fn mp3_metadata(data: &std::sync::Arc<[u8]>) -> Result<Mp3Metadata, Error> {
         sample_rate,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds `num_frames` MPEG-1 Layer III frames (mono, 44.1kHz, 128kbps)
+    /// with empty side info and main data, which decode to silence.
+    fn silent_mp3(num_frames: usize) -> Vec<u8> {
+        // 144 * 128000 / 44100 bytes, without padding.
+        const FRAME_LEN: usize = 417;
+        let mut data = Vec::with_capacity(num_frames * FRAME_LEN);
+        for _ in 0..num_frames {
+            data.extend_from_slice(&[0xFF, 0xFB, 0x90, 0xC0]);
+            data.resize(data.len() + FRAME_LEN - 4, 0);
+        }
+        data
+    }
+
+    #[test]
+    fn decode_silent_frames() {
+        let data = silent_mp3(4);
+        let metadata = mp3_metadata(&data.clone().into()).unwrap();
+        assert_eq!(metadata.sample_rate, 44100);
+
+        let decoder = Mp3Decoder::new_seekable(Cursor::new(data)).unwrap();
+        assert_eq!(decoder.num_channels(), 1);
+        assert_eq!(decoder.sample_rate(), 44100);
+        let samples: Vec<_> = decoder
+            .take(Mp3Decoder::SAMPLE_BUFFER_DURATION as usize)
+            .collect();
+        assert_eq!(
+            samples,
+            vec![[0, 0]; Mp3Decoder::SAMPLE_BUFFER_DURATION as usize]
+        );
+    }
+}
@@ -1130,3 +1130,274 @@ macro_rules! impl_audio_mixer_backend {
         }
     };
 }
+
+/// An `AudioBackend` that mixes audio into memory instead of playing it.
+///
+/// This is useful for headless playback and for testing: each `tick` mixes
+/// one movie frame's worth of audio at `MixerAudioBackend::SAMPLE_RATE`, and
+/// the mixed samples accumulate in `output`.
+pub struct MixerAudioBackend {
+    mixer: AudioMixer,
+
+    /// The mixed audio, as interleaved stereo samples.
+    output: Vec<i16>,
+
+    /// Whether `tick` mixes any audio.
+    is_playing: bool,
+
+    /// The frame rate of the movie, which determines how much audio is mixed
+    /// per tick.
+    frame_rate: f64,
+
+    /// The fractional number of sample frames left over from previous ticks.
+    frame_remainder: f64,
+}
+
+impl MixerAudioBackend {
+    /// The sample rate of the mixed output in Hz.
+    pub const SAMPLE_RATE: u32 = 44100;
+
+    pub fn new() -> Self {
+        Self {
+            mixer: AudioMixer::new(2, Self::SAMPLE_RATE),
+            output: Vec::new(),
+            is_playing: true,
+            frame_rate: 24.0,
+            frame_remainder: 0.0,
+        }
+    }
+
+    /// Mixes the next `num_sample_frames` sample frames of all playing sounds
+    /// onto the end of the output.
+    pub fn mix(&mut self, num_sample_frames: usize) {
+        let start = self.output.len();
+        self.output.resize(start + 2 * num_sample_frames, 0);
+        self.mixer.mix(&mut self.output[start..]);
+    }
+
+    /// The audio mixed so far, as interleaved stereo samples.
+    pub fn output(&self) -> &[i16] {
+        &self.output
+    }
+
+    /// Takes the audio mixed so far, clearing the output.
+    pub fn take_output(&mut self) -> Vec<i16> {
+        std::mem::take(&mut self.output)
+    }
+}
+
+impl Default for MixerAudioBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl super::AudioBackend for MixerAudioBackend {
+    impl_audio_mixer_backend!(mixer);
+
+    fn play(&mut self) {
+        self.is_playing = true;
+    }
+
+    fn pause(&mut self) {
+        self.is_playing = false;
+    }
+
+    fn tick(&mut self) {
+        if self.is_playing {
+            let num_sample_frames =
+                f64::from(Self::SAMPLE_RATE) / self.frame_rate + self.frame_remainder;
+            self.frame_remainder = num_sample_frames.fract();
+            self.mix(num_sample_frames as usize);
+        }
+    }
+
+    fn set_frame_rate(&mut self, frame_rate: f64) {
+        self.frame_rate = frame_rate;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::audio::AudioBackend;
+    use dasp::signal::Signal;
+
+    /// A decoder that outputs its sample frame index in both channels, plus one
+    /// so that it can be told apart from silence.
+    struct CountingDecoder {
+        position: u32,
+        len: u32,
+    }
+
+    impl Iterator for CountingDecoder {
+        type Item = [i16; 2];
+
+        fn next(&mut self) -> Option<[i16; 2]> {
+            if self.position < self.len {
+                let frame = self.position as i16 + 1;
+                self.position += 1;
+                Some([frame, frame])
+            } else {
+                None
+            }
+        }
+    }
+
+    impl Decoder for CountingDecoder {
+        fn num_channels(&self) -> u8 {
+            2
+        }
+
+        fn sample_rate(&self) -> u16 {
+            44100
+        }
+    }
+
+    impl SeekableDecoder for CountingDecoder {
+        fn reset(&mut self) {
+            self.position = 0;
+        }
+
+        fn seek_to_sample_frame(&mut self, frame: u32) {
+            self.position = frame;
+        }
+    }
+
+    fn play(settings: &swf::SoundInfo, len: u32) -> Vec<i16> {
+        let decoder = Box::new(CountingDecoder { position: 0, len });
+        let mut stream = EventSoundStream::new_with_settings(decoder, settings, len, 0);
+        let mut frames = Vec::new();
+        while !stream.is_exhausted() {
+            match stream.next()[0] {
+                0 => (),
+                frame => frames.push(frame - 1),
+            }
+        }
+        frames
+    }
+
+    fn sound_info(
+        in_sample: Option<u32>,
+        out_sample: Option<u32>,
+        num_loops: u16,
+    ) -> swf::SoundInfo {
+        swf::SoundInfo {
+            event: swf::SoundEvent::Event,
+            in_sample,
+            out_sample,
+            num_loops,
+            envelope: None,
+        }
+    }
+
+    #[test]
+    fn event_sound_loops() {
+        assert_eq!(play(&sound_info(None, None, 1), 4), [0, 1, 2, 3]);
+        assert_eq!(
+            play(&sound_info(None, None, 3), 4),
+            [0, 1, 2, 3, 0, 1, 2, 3, 0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn event_sound_in_and_out_points() {
+        assert_eq!(play(&sound_info(Some(2), None, 1), 6), [2, 3, 4, 5]);
+        assert_eq!(
+            play(&sound_info(Some(2), Some(4), 2), 10),
+            [2, 3, 4, 2, 3, 4]
+        );
+    }
+
+    /// Registers an uncompressed stereo sound of `len` sample frames that all
+    /// have the given value.
+    fn register_constant_sound(
+        audio: &mut MixerAudioBackend,
+        value: i16,
+        len: usize,
+    ) -> SoundHandle {
+        let data = value.to_le_bytes().repeat(2 * len);
+        let sound = swf::Sound {
+            id: 1,
+            format: swf::SoundFormat {
+                compression: AudioCompression::Uncompressed,
+                sample_rate: 44100,
+                is_stereo: true,
+                is_16_bit: true,
+            },
+            num_samples: len as u32,
+            data: &data,
+        };
+        audio.register_sound(&sound).unwrap()
+    }
+
+    #[test]
+    fn mixer_backend_sums_overlapping_sounds() {
+        let mut audio = MixerAudioBackend::new();
+        let quiet = register_constant_sound(&mut audio, 1000, 100);
+        let loud = register_constant_sound(&mut audio, 2000, 100);
+
+        audio
+            .start_sound(quiet, &sound_info(None, None, 1))
+            .unwrap();
+        audio.mix(50);
+        audio.start_sound(loud, &sound_info(None, None, 1)).unwrap();
+        audio.mix(150);
+
+        let output = audio.take_output();
+        assert_eq!(output.len(), 2 * 200);
+        let frame = |i: usize| [output[2 * i], output[2 * i + 1]];
+        assert_eq!(frame(25), [1000, 1000]);
+        assert_eq!(frame(75), [3000, 3000]);
+        assert_eq!(frame(125), [2000, 2000]);
+        assert_eq!(frame(175), [0, 0]);
+    }
+
+    #[test]
+    fn mixer_backend_applies_envelope() {
+        let mut audio = MixerAudioBackend::new();
+        let sound = register_constant_sound(&mut audio, 1000, 200);
+
+        // Fade the left channel in and the right channel out over 100 sample frames.
+        let settings = swf::SoundInfo {
+            envelope: Some(vec![
+                swf::SoundEnvelopePoint {
+                    sample: 0,
+                    left_volume: 0.0,
+                    right_volume: 1.0,
+                },
+                swf::SoundEnvelopePoint {
+                    sample: 100,
+                    left_volume: 1.0,
+                    right_volume: 0.0,
+                },
+            ]),
+            ..sound_info(None, None, 1)
+        };
+        audio.start_sound(sound, &settings).unwrap();
+        audio.mix(200);
+
+        let output = audio.take_output();
+        let frame = |i: usize| [output[2 * i], output[2 * i + 1]];
+        assert_eq!(frame(0), [0, 1000]);
+        assert_eq!(frame(25), [250, 750]);
+        assert_eq!(frame(50), [500, 500]);
+        assert_eq!(frame(75), [750, 250]);
+        // The last point's volume holds until the end of the sound.
+        assert_eq!(frame(100), [1000, 0]);
+        assert_eq!(frame(150), [1000, 0]);
+    }
+
+    #[test]
+    fn mixer_backend_ticks_one_frame() {
+        let mut audio = MixerAudioBackend::new();
+        audio.set_frame_rate(30.0);
+        audio.tick();
+        assert_eq!(audio.output().len(), 2 * 1470);
+
+        // Paused backends don't mix.
+        audio.pause();
+        audio.tick();
+        assert_eq!(audio.output().len(), 2 * 1470);
+    }
+}
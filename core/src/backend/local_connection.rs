@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// A method call sent over a `LocalConnection`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalConnectionMessage {
+    /// The name of the method to call on the receiving connection.
+    pub method_name: String,
+
+    /// The arguments of the call, encoded as a sequence of AMF0 values.
+    pub arguments: Vec<u8>,
+}
+
+/// A message bus that routes `LocalConnection` calls between movies.
+pub trait LocalConnectionBackend {
+    /// Starts listening for messages sent to `name`.
+    ///
+    /// Returns `false` if another connection is already listening on that name.
+    fn connect(&mut self, name: &str) -> bool;
+
+    /// Stops listening on `name`, discarding any messages that weren't received yet.
+    fn close(&mut self, name: &str);
+
+    /// Queues a message for the connection listening on `name`.
+    ///
+    /// Returns `false` if no connection is listening on that name.
+    fn send(&mut self, name: &str, message: LocalConnectionMessage) -> bool;
+
+    /// Takes all messages queued for `name`, in the order they were sent.
+    fn receive(&mut self, name: &str) -> Vec<LocalConnectionMessage>;
+}
+
+/// Local connection backend that routes messages between players in the same process.
+///
+/// Clones of this backend share the same connections, so players built with clones
+/// of one backend can message each other.
+#[derive(Clone, Default)]
+pub struct InProcessLocalConnectionBackend {
+    connections: Arc<Mutex<HashMap<String, Vec<LocalConnectionMessage>>>>,
+}
+
+impl InProcessLocalConnectionBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl LocalConnectionBackend for InProcessLocalConnectionBackend {
+    fn connect(&mut self, name: &str) -> bool {
+        let mut connections = self.connections.lock().unwrap();
+        if connections.contains_key(name) {
+            return false;
+        }
+        connections.insert(name.to_string(), Vec::new());
+        true
+    }
+
+    fn close(&mut self, name: &str) {
+        self.connections.lock().unwrap().remove(name);
+    }
+
+    fn send(&mut self, name: &str, message: LocalConnectionMessage) -> bool {
+        match self.connections.lock().unwrap().get_mut(name) {
+            Some(queue) => {
+                queue.push(message);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn receive(&mut self, name: &str) -> Vec<LocalConnectionMessage> {
+        self.connections
+            .lock()
+            .unwrap()
+            .get_mut(name)
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+}
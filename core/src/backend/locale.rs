@@ -0,0 +1,53 @@
+pub trait LocaleBackend {
+    /// The `strftime`-style format used by `Date.toLocaleString`.
+    fn date_time_format(&self) -> &str;
+
+    /// The `strftime`-style format used by `Date.toLocaleDateString`.
+    fn date_format(&self) -> &str;
+
+    /// The `strftime`-style format used by `Date.toLocaleTimeString`.
+    fn time_format(&self) -> &str;
+
+    /// The character placed between groups of thousands when formatting numbers.
+    fn grouping_separator(&self) -> char;
+
+    /// The character separating the integer and fractional parts of a number.
+    fn decimal_separator(&self) -> char;
+}
+
+/// Locale backend that formats everything the way Flash Player does in a US-English locale.
+pub struct DefaultLocaleBackend {}
+
+impl DefaultLocaleBackend {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl LocaleBackend for DefaultLocaleBackend {
+    fn date_time_format(&self) -> &str {
+        "%a %b %-d %-Y %T %p"
+    }
+
+    fn date_format(&self) -> &str {
+        "%a %b %-d %-Y"
+    }
+
+    fn time_format(&self) -> &str {
+        "%T %p"
+    }
+
+    fn grouping_separator(&self) -> char {
+        ','
+    }
+
+    fn decimal_separator(&self) -> char {
+        '.'
+    }
+}
+
+impl Default for DefaultLocaleBackend {
+    fn default() -> Self {
+        DefaultLocaleBackend::new()
+    }
+}
@@ -955,6 +955,7 @@ impl<'gc> BitmapData<'gc> {
     ) -> u32 {
         // Pre-compute the masked threshold
         let masked_threshold = threshold & mask;
+        let colour = Color(colour as i32).to_premultiplied_alpha(self.transparency());
 
         // Extract coords
         let (src_min_x, src_min_y, src_width, src_height) = src_rect;
@@ -985,14 +986,13 @@ impl<'gc> BitmapData<'gc> {
                 // If the test, as defined by the operation pass then set to input colour
                 if operation(source_color.0 as u32 & mask, masked_threshold) {
                     modified_count += 1;
-                    self.set_pixel32_raw(dest_x as u32, dest_y as u32, Color(colour as _));
+                    self.set_pixel32_raw(dest_x as u32, dest_y as u32, colour);
                 } else {
                     // If the test fails, but copy_source is true then take the colour from the source
                     if copy_source {
                         let new_color = source_bitmap
-                            .get_pixel_raw(dest_x as u32, dest_y as u32)
-                            .unwrap()
-                            .to_un_multiplied_alpha();
+                            .get_pixel_raw(src_x as u32, src_y as u32)
+                            .unwrap();
 
                         self.set_pixel32_raw(dest_x as u32, dest_y as u32, new_color);
                     }
@@ -1149,6 +1149,7 @@ impl<'gc> BitmapData<'gc> {
             stage: context.stage,
             clip_depth_stack: vec![],
             allow_mask: true,
+            caret_visible: false,
         };
 
         // Make the screen opacity match the opacity of this bitmap
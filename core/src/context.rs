@@ -6,6 +6,8 @@ use crate::avm1::{Object as Avm1Object, Value as Avm1Value};
 use crate::avm2::{Avm2, Object as Avm2Object, SoundChannelObject, Value as Avm2Value};
 use crate::backend::{
     audio::{AudioBackend, AudioManager, SoundHandle, SoundInstanceHandle},
+    local_connection::LocalConnectionBackend,
+    locale::LocaleBackend,
     log::LogBackend,
     navigator::NavigatorBackend,
     storage::StorageBackend,
@@ -18,6 +20,7 @@ use crate::focus_tracker::FocusTracker;
 use crate::frame_lifecycle::FramePhase;
 use crate::library::Library;
 use crate::loader::LoadManager;
+use crate::local_connection::LocalConnections;
 use crate::player::Player;
 use crate::prelude::*;
 use crate::tag_utils::{SwfMovie, SwfSlice};
@@ -85,6 +88,12 @@ pub struct UpdateContext<'a, 'gc> {
     /// **DO NOT** use this field directly, use the `avm_trace` method instead.
     pub log: &'a mut dyn LogBackend,
 
+    /// The locale backend, used to format dates and numbers for the user's locale.
+    pub locale: &'a mut dyn LocaleBackend,
+
+    /// The local connection backend, used to send messages between movies.
+    pub local_connection: &'a mut dyn LocalConnectionBackend,
+
     /// The video backend, used for video decoding
     pub video: &'a mut dyn VideoBackend,
 
@@ -139,6 +148,9 @@ pub struct UpdateContext<'a, 'gc> {
     /// Timed callbacks created with `setInterval`/`setTimeout`.
     pub timers: &'a mut Timers<'gc>,
 
+    /// `LocalConnection` objects that are listening for or sending messages.
+    pub local_connections: &'a mut LocalConnections<'gc>,
+
     pub current_context_menu: &'a mut Option<ContextMenuState<'gc>>,
 
     /// The AVM1 global state.
@@ -153,6 +165,10 @@ pub struct UpdateContext<'a, 'gc> {
     /// The instant at which the SWF was launched.
     pub start_time: Instant,
 
+    /// The simulated time passed since the SWF was launched, in milliseconds.
+    /// When set, this is used instead of the wall-clock time by `getTimer`.
+    pub simulated_time: Option<f64>,
+
     /// The instant at which the current update started.
     pub update_start: Instant,
 
@@ -283,6 +299,15 @@ impl<'a, 'gc> UpdateContext<'a, 'gc> {
     pub fn set_sound_transforms_dirty(&mut self) {
         self.audio_manager.set_sound_transforms_dirty()
     }
+
+    /// The number of milliseconds passed since the SWF was launched, as
+    /// returned by `getTimer`.
+    pub fn get_timer(&self) -> u32 {
+        match self.simulated_time {
+            Some(time) => time as u32,
+            None => Instant::now().duration_since(self.start_time).as_millis() as u32,
+        }
+    }
 }
 
 impl<'a, 'gc> UpdateContext<'a, 'gc> {
@@ -309,6 +334,8 @@ impl<'a, 'gc> UpdateContext<'a, 'gc> {
             navigator: self.navigator,
             renderer: self.renderer,
             log: self.log,
+            locale: self.locale,
+            local_connection: self.local_connection,
             ui: self.ui,
             video: self.video,
             storage: self.storage,
@@ -327,11 +354,13 @@ impl<'a, 'gc> UpdateContext<'a, 'gc> {
             avm2_shared_objects: self.avm2_shared_objects,
             unbound_text_fields: self.unbound_text_fields,
             timers: self.timers,
+            local_connections: self.local_connections,
             current_context_menu: self.current_context_menu,
             avm1: self.avm1,
             avm2: self.avm2,
             external_interface: self.external_interface,
             start_time: self.start_time,
+            simulated_time: self.simulated_time,
             update_start: self.update_start,
             max_execution_duration: self.max_execution_duration,
             focus_tracker: self.focus_tracker,
@@ -453,6 +482,10 @@ pub struct RenderContext<'a, 'gc> {
     /// Whether to allow pushing a new mask. A masker-inside-a-masker does not work in Flash, instead
     /// causing the inner mask to be included as part of the outer mask. Maskee-inside-a-maskee works as one expects.
     pub allow_mask: bool,
+
+    /// Whether the caret of a focused text field is visible in this frame.
+    /// Toggled periodically by the player to make the caret blink.
+    pub caret_visible: bool,
 }
 
 /// The type of action being run.
@@ -19,7 +19,7 @@ use ruffle_render::transform::Transform;
 use std::cell::{Ref, RefMut};
 use std::fmt::Debug;
 use std::sync::Arc;
-use swf::{BlendMode, Fixed8, Rectangle};
+use swf::{BlendMode, Filter, Fixed8, Rectangle};
 
 mod avm1_button;
 mod avm2_button;
@@ -66,6 +66,11 @@ pub struct DisplayObjectBase<'gc> {
     filters: Avm2ArrayStorage<'gc>,
     clip_depth: Depth,
 
+    /// The filters applied when rendering this display object.
+    /// These are set by `PlaceObject3` tags, or by scripts assigning the `filters` property.
+    #[collect(require_static)]
+    bitmap_filters: Vec<Filter>,
+
     // Cached transform properties `_xscale`, `_yscale`, `_rotation`.
     // These are expensive to calculate, so they will be calculated and cached
     // when AS requests one of these properties.
@@ -117,6 +122,12 @@ pub struct DisplayObjectBase<'gc> {
     /// changes immediately (without needing wait for a render)
     #[collect(require_static)]
     next_scroll_rect: Rectangle<Twips>,
+
+    /// The 9-slice scaling grid of this display object, in local coordinates.
+    /// When set, the corners of the object are not scaled, and its edges are only scaled
+    /// along one axis.
+    #[collect(require_static)]
+    scale9_grid: Option<Rectangle<Twips>>,
 }
 
 impl<'gc> Default for DisplayObjectBase<'gc> {
@@ -129,6 +140,7 @@ impl<'gc> Default for DisplayObjectBase<'gc> {
             name: Default::default(),
             filters: Avm2ArrayStorage::new(0),
             clip_depth: Default::default(),
+            bitmap_filters: Vec::new(),
             rotation: Degrees::from_radians(0.0),
             scale_x: Percent::from_unit(1.0),
             scale_y: Percent::from_unit(1.0),
@@ -142,6 +154,7 @@ impl<'gc> Default for DisplayObjectBase<'gc> {
             flags: DisplayObjectFlags::VISIBLE,
             scroll_rect: None,
             next_scroll_rect: Default::default(),
+            scale9_grid: None,
         }
     }
 }
@@ -320,6 +333,14 @@ impl<'gc> DisplayObjectBase<'gc> {
         self.filters = filters;
     }
 
+    fn bitmap_filters(&self) -> &[Filter] {
+        &self.bitmap_filters
+    }
+
+    fn set_bitmap_filters(&mut self, filters: Vec<Filter>) {
+        self.bitmap_filters = filters;
+    }
+
     fn alpha(&self) -> f64 {
         f64::from(self.color_transform().a_mult)
     }
@@ -511,6 +532,12 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
     } else {
         None
     };
+    let filters = this.bitmap_filters();
+    let unfiltered_commands = if !filters.is_empty() {
+        Some(std::mem::take(&mut context.commands))
+    } else {
+        None
+    };
 
     let scroll_rect_matrix = if let Some(rect) = this.scroll_rect() {
         let cur_transform = context.transform_stack.transform();
@@ -567,7 +594,9 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
         context.commands.activate_mask();
     }
 
-    this.render_self(context);
+    if !render_scale9_grid(this, context) {
+        this.render_self(context);
+    }
 
     if let Some(rect_mat) = scroll_rect_matrix {
         // Draw the rectangle again after deactivating the mask,
@@ -587,6 +616,11 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
         context.commands.pop_mask();
     }
 
+    if let Some(unfiltered_commands) = unfiltered_commands {
+        let sub_commands = std::mem::replace(&mut context.commands, unfiltered_commands);
+        context.commands.apply_filters(sub_commands, filters);
+    }
+
     if let Some(original_commands) = original_commands {
         let sub_commands = std::mem::replace(&mut context.commands, original_commands);
         context.commands.blend(sub_commands, blend_mode);
@@ -600,6 +634,203 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
     context.transform_stack.pop();
 }
 
+/// Renders an object with a 9-slice scaling grid as nine separately transformed slices,
+/// each drawing only the content within its region of the grid.
+///
+/// Flash ignores the grid for rotated, skewed or flipped objects. If the grid doesn't apply,
+/// nothing is rendered and `false` is returned.
+fn render_scale9_grid<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_, 'gc>) -> bool {
+    let grid = match this.scale9_grid() {
+        Some(grid) => grid,
+        None => return false,
+    };
+    let matrix = *this.base().matrix();
+    if matrix.b != 0.0 || matrix.c != 0.0 || matrix.a <= 0.0 || matrix.d <= 0.0 {
+        return false;
+    }
+    if this.scroll_rect().is_some() {
+        return false;
+    }
+    let bounds = this.bounds();
+    if !bounds.valid {
+        return false;
+    }
+
+    let columns = scale9_slices(
+        bounds.x_min.to_pixels(),
+        grid.x_min.to_pixels(),
+        grid.x_max.to_pixels(),
+        bounds.x_max.to_pixels(),
+        matrix.a.into(),
+        matrix.tx.to_pixels(),
+    );
+    let rows = scale9_slices(
+        bounds.y_min.to_pixels(),
+        grid.y_min.to_pixels(),
+        grid.y_max.to_pixels(),
+        bounds.y_max.to_pixels(),
+        matrix.d.into(),
+        matrix.ty.to_pixels(),
+    );
+
+    // The transform stack already contains our own matrix, which each slice replaces.
+    let mut inverse = matrix;
+    inverse.invert();
+
+    for (x_min, x_max, dest_x_min, dest_x_max) in columns {
+        for (y_min, y_max, dest_y_min, dest_y_max) in rows {
+            if x_max <= x_min || y_max <= y_min {
+                continue;
+            }
+            if dest_x_max <= dest_x_min || dest_y_max <= dest_y_min {
+                continue;
+            }
+            let scale_x = (dest_x_max - dest_x_min) / (x_max - x_min);
+            let scale_y = (dest_y_max - dest_y_min) / (y_max - y_min);
+            let slice_matrix = Matrix {
+                a: scale_x as f32,
+                b: 0.0,
+                c: 0.0,
+                d: scale_y as f32,
+                tx: Twips::from_pixels(dest_x_min - x_min * scale_x),
+                ty: Twips::from_pixels(dest_y_min - y_min * scale_y),
+            };
+            context.transform_stack.push(&Transform {
+                matrix: inverse * slice_matrix,
+                color_transform: Default::default(),
+            });
+
+            let slice = BoundingBox {
+                x_min: Twips::from_pixels(x_min),
+                y_min: Twips::from_pixels(y_min),
+                x_max: Twips::from_pixels(x_max),
+                y_max: Twips::from_pixels(y_max),
+                valid: true,
+            };
+            render_scale9_slice(this, context, &slice);
+
+            context.transform_stack.pop();
+        }
+    }
+
+    true
+}
+
+/// Renders the part of an object that lies within `slice`, a rectangle in its local coordinates.
+///
+/// The children of a container are only drawn in the slices they overlap, and are only
+/// cropped when they cross the edge of the slice. Containers with clipping layers are
+/// drawn and cropped as a whole.
+fn render_scale9_slice<'gc>(
+    this: DisplayObject<'gc>,
+    context: &mut RenderContext<'_, 'gc>,
+    slice: &BoundingBox,
+) {
+    let container = this.as_container().filter(|container| {
+        container
+            .iter_render_list()
+            .all(|child| child.clip_depth() == 0)
+    });
+    let container = match container {
+        Some(container) => container,
+        None => {
+            render_cropped(context, slice, |context| this.render_self(context));
+            return;
+        }
+    };
+
+    if let Some(clip) = this.as_movie_clip() {
+        if clip.self_bounds().intersects(slice) {
+            render_cropped(context, slice, |context| clip.render_drawing(context));
+        }
+    }
+
+    for child in container.iter_render_list() {
+        child.pre_render(context);
+        if !child.visible() {
+            continue;
+        }
+
+        let bounds = child.bounds_with_transform(child.base().matrix());
+        if !bounds.intersects(slice) {
+            continue;
+        }
+        if bounds.x_min >= slice.x_min
+            && bounds.x_max <= slice.x_max
+            && bounds.y_min >= slice.y_min
+            && bounds.y_max <= slice.y_max
+        {
+            child.render(context);
+        } else {
+            render_cropped(context, slice, |context| child.render(context));
+        }
+    }
+}
+
+/// Calls `render`, cropping everything it draws to `slice` with a rectangular mask.
+fn render_cropped<'gc>(
+    context: &mut RenderContext<'_, 'gc>,
+    slice: &BoundingBox,
+    render: impl FnOnce(&mut RenderContext<'_, 'gc>),
+) {
+    let crop_matrix = context.transform_stack.transform().matrix
+        * Matrix {
+            a: slice.width().to_pixels() as f32,
+            b: 0.0,
+            c: 0.0,
+            d: slice.height().to_pixels() as f32,
+            tx: slice.x_min,
+            ty: slice.y_min,
+        };
+    context.commands.push_mask();
+    context.commands.draw_rect(Color::WHITE, crop_matrix);
+    context.commands.activate_mask();
+    render(context);
+    context.commands.deactivate_mask();
+    context.commands.draw_rect(Color::WHITE, crop_matrix);
+    context.commands.pop_mask();
+}
+
+/// Splits one axis of an object's bounds into the three slices of a 9-slice grid.
+///
+/// Returns the start and end of each slice in local coordinates, followed by its start and
+/// end in the parent's coordinates. The outer slices keep their size, and the center slice
+/// takes up the rest of the scaled size. If the object is scaled down too far for that, the
+/// outer slices shrink proportionally instead.
+fn scale9_slices(
+    min: f64,
+    grid_min: f64,
+    grid_max: f64,
+    max: f64,
+    scale: f64,
+    translate: f64,
+) -> [(f64, f64, f64, f64); 3] {
+    let grid_min = grid_min.clamp(min, max);
+    let grid_max = grid_max.clamp(grid_min, max);
+
+    let size = (max - min) * scale;
+    let mut start = grid_min - min;
+    let mut end = max - grid_max;
+    if start + end > size {
+        let shrink = size / (start + end);
+        start *= shrink;
+        end *= shrink;
+    }
+    let center = size - start - end;
+
+    let dest_min = translate + min * scale;
+    [
+        (min, grid_min, dest_min, dest_min + start),
+        (
+            grid_min,
+            grid_max,
+            dest_min + start,
+            dest_min + start + center,
+        ),
+        (grid_max, max, dest_min + start + center, dest_min + size),
+    ]
+}
+
 #[enum_trait_object(
     #[derive(Clone, Collect, Debug, Copy)]
     #[collect(no_drop)]
@@ -694,6 +925,46 @@ pub trait TDisplayObject<'gc>:
         bounds
     }
 
+    /// The untransformed bounding box of this object, excluding children and strokes.
+    ///
+    /// Leaf DisplayObjects that draw strokes should override this; by default
+    /// it is the same as `self_bounds`.
+    fn self_rect(&self) -> BoundingBox {
+        self.self_bounds()
+    }
+
+    /// The untransformed bounding box of this object including children,
+    /// excluding strokes.
+    fn rect(&self) -> BoundingBox {
+        self.rect_with_transform(&Matrix::default())
+    }
+
+    /// Gets the bounds of this object and all children excluding strokes,
+    /// transformed by a given matrix. See `bounds_with_transform`.
+    fn rect_with_transform(&self, matrix: &Matrix) -> BoundingBox {
+        if let Some(scroll_rect) = self.scroll_rect() {
+            return BoundingBox {
+                x_min: Twips::from_pixels(0.0),
+                y_min: Twips::from_pixels(0.0),
+                x_max: scroll_rect.width(),
+                y_max: scroll_rect.height(),
+                valid: true,
+            }
+            .transform(matrix);
+        }
+
+        let mut bounds = self.self_rect().transform(matrix);
+
+        if let Some(ctr) = self.as_container() {
+            for child in ctr.iter_render_list() {
+                let matrix = *matrix * *child.base().matrix();
+                bounds.union(&child.rect_with_transform(&matrix));
+            }
+        }
+
+        bounds
+    }
+
     fn place_frame(&self) -> u16 {
         self.base().place_frame()
     }
@@ -956,6 +1227,16 @@ pub trait TDisplayObject<'gc>:
         self.base_mut(gc_context).set_filters(filters)
     }
 
+    /// The filters applied when rendering this display object.
+    fn bitmap_filters(&self) -> Vec<Filter> {
+        self.base().bitmap_filters().to_vec()
+    }
+
+    /// Sets the filters applied when rendering this display object.
+    fn set_bitmap_filters(&self, gc_context: MutationContext<'gc, '_>, filters: Vec<Filter>) {
+        self.base_mut(gc_context).set_bitmap_filters(filters)
+    }
+
     /// Returns the dot-syntax path to this display object, e.g. `_level0.foo.clip`
     fn path(&self) -> WString {
         if let Some(parent) = self.avm1_parent() {
@@ -1093,6 +1374,18 @@ pub trait TDisplayObject<'gc>:
         self.base_mut(gc_context).next_scroll_rect = rectangle;
     }
 
+    fn scale9_grid(&self) -> Option<Rectangle<Twips>> {
+        self.base().scale9_grid.clone()
+    }
+
+    fn set_scale9_grid(
+        &self,
+        gc_context: MutationContext<'gc, '_>,
+        rectangle: Option<Rectangle<Twips>>,
+    ) {
+        self.base_mut(gc_context).scale9_grid = rectangle;
+    }
+
     fn removed(&self) -> bool {
         self.base().removed()
     }
@@ -1528,6 +1821,11 @@ pub trait TDisplayObject<'gc>:
             if let Some(blend_mode) = place_object.blend_mode {
                 self.set_blend_mode(context.gc_context, blend_mode);
             }
+            if let Some(filters) = &place_object.filters {
+                self.set_bitmap_filters(context.gc_context, filters.clone());
+                // Scripts will see filter objects built from the new filters.
+                self.set_filters(context.gc_context, Avm2ArrayStorage::new(0));
+            }
             if self.swf_version() >= 11 {
                 if let Some(visible) = place_object.is_visible {
                     self.set_visible(context.gc_context, visible);
@@ -1547,7 +1845,6 @@ pub trait TDisplayObject<'gc>:
             // name, clip_depth, clip_actions
             // These properties are only set on initial placement in `MovieClip::instantiate_child`
             // and can not be modified by subsequent PlaceObject tags.
-            // TODO: Filters need to be applied here.
         }
     }
 
@@ -18,18 +18,18 @@ use crate::display_object::interactive::{
 use crate::display_object::{DisplayObjectBase, DisplayObjectPtr, TDisplayObject};
 use crate::drawing::Drawing;
 use crate::events::{ButtonKeyCode, ClipEvent, ClipEventResult, KeyCode};
-use crate::font::{round_down_to_pixel, Glyph, TextRenderSettings};
+use crate::font::{round_down_to_pixel, Glyph, TextGridFit, TextRenderSettings};
 use crate::html::{BoxBounds, FormatSpans, LayoutBox, LayoutContent, LayoutMetrics, TextFormat};
 use crate::prelude::*;
 use crate::string::{utils as string_utils, AvmString, WStr, WString};
 use crate::tag_utils::SwfMovie;
 use crate::vminterface::{AvmObject, Instantiator};
-use chrono::Utc;
 use core::fmt;
 use gc_arena::{Collect, Gc, GcCell, MutationContext};
 use ruffle_render::commands::CommandHandler;
 use ruffle_render::shape_utils::DrawCommand;
 use ruffle_render::transform::Transform;
+use std::ops::Range;
 use std::{cell::Ref, cell::RefMut, sync::Arc};
 use swf::{Color, Twips};
 
@@ -121,6 +121,13 @@ pub struct EditTextData<'gc> {
     /// The selected portion of the text, or None if the text is not selected.
     selection: Option<TextSelection>,
 
+    /// The range of text being composed by an input method, if any.
+    ///
+    /// This text is displayed underlined, and isn't reported as a change until it's
+    /// committed.
+    #[collect(require_static)]
+    composition: Option<Range<usize>>,
+
     /// Which rendering engine this text field will use.
     render_settings: TextRenderSettings,
 
@@ -130,9 +137,18 @@ pub struct EditTextData<'gc> {
     /// Information about the layout's current lines. Used by scroll properties.
     line_data: Vec<LineData>,
 
+    /// The width of the bounds at the time of the last layout.
+    ///
+    /// Resizing the text field cannot relayout it immediately, so this is used
+    /// to lazily relayout once an update context is available.
+    layout_width: Twips,
+
     /// How many lines down the text is offset by. 1-based index.
     scroll: usize,
 
+    /// The maximum number of characters the user can enter. 0 means unlimited.
+    max_chars: i32,
+
     /// Flags indicating the text field's settings.
     flags: EditTextFlag,
 }
@@ -274,10 +290,13 @@ impl<'gc> EditText<'gc> {
                 variable: variable.map(|s| s.to_string_lossy(encoding)),
                 bound_stage_object: None,
                 selection: None,
+                composition: None,
                 render_settings: Default::default(),
                 hscroll: 0.0,
                 line_data,
+                layout_width: bounds.width(),
                 scroll: 1,
+                max_chars: swf_tag.max_length().unwrap_or_default() as i32,
             },
         ));
 
@@ -409,6 +428,18 @@ impl<'gc> EditText<'gc> {
             .set(EditTextFlag::READ_ONLY, !is_editable);
     }
 
+    /// The maximum number of characters the user can enter into this text
+    /// field. 0 means there is no limit.
+    ///
+    /// This does not restrict text set by ActionScript.
+    pub fn max_chars(self) -> i32 {
+        self.0.read().max_chars
+    }
+
+    pub fn set_max_chars(self, value: i32, context: &mut UpdateContext<'_, 'gc>) {
+        self.0.write(context.gc_context).max_chars = value;
+    }
+
     pub fn is_multiline(self) -> bool {
         self.0.read().flags.contains(EditTextFlag::MULTILINE)
     }
@@ -686,6 +717,14 @@ impl<'gc> EditText<'gc> {
     /// have already been calculated and applied to HTML trees lowered into the
     /// text-span representation.
     fn relayout(self, context: &mut UpdateContext<'_, 'gc>) {
+        self.lay_out(context, false);
+    }
+
+    /// Lay out the text spans of the `EditText`.
+    ///
+    /// When `is_resize` is set, the layout is only being refreshed for a new
+    /// width: the scroll position is kept and the width is left alone.
+    fn lay_out(self, context: &mut UpdateContext<'_, 'gc>, is_resize: bool) {
         let mut edit_text = self.0.write(context.gc_context);
         let autosize = edit_text.autosize;
         let is_word_wrap = edit_text.flags.contains(EditTextFlag::WORD_WRAP);
@@ -712,9 +751,11 @@ impl<'gc> EditText<'gc> {
         edit_text.line_data = get_line_data(&new_layout);
         edit_text.layout = new_layout;
         edit_text.intrinsic_bounds = intrinsic_bounds;
-        // reset scroll
-        edit_text.hscroll = 0.0;
-        edit_text.scroll = 1;
+        if !is_resize {
+            // reset scroll
+            edit_text.hscroll = 0.0;
+            edit_text.scroll = 1;
+        }
 
         if autosize != AutoSizeMode::None {
             if !is_word_wrap {
@@ -730,21 +771,46 @@ impl<'gc> EditText<'gc> {
                 };
                 edit_text.bounds.set_x(new_x);
                 edit_text.bounds.set_width(width);
-            } else {
+            } else if !is_resize {
                 let width = edit_text.static_data.bounds.width();
                 edit_text.bounds.set_width(width);
             }
             let height = intrinsic_bounds.height() + padding;
             edit_text.bounds.set_height(height);
+            edit_text.layout_width = edit_text.bounds.width();
             drop(edit_text);
             self.redraw_border(context.gc_context);
+        } else {
+            edit_text.layout_width = edit_text.bounds.width();
+        }
+    }
+
+    /// Relayout the `EditText` if its bounds have been resized since the last layout.
+    ///
+    /// Setting the width of a text field does not relayout the text, as no
+    /// update context is available at that point; this picks up the change.
+    fn relayout_if_resized(self, context: &mut UpdateContext<'_, 'gc>) {
+        let is_resized = {
+            let edit_text = self.0.read();
+            edit_text.bounds.width() != edit_text.layout_width
+        };
+
+        if is_resized {
+            self.lay_out(context, true);
+
+            // The text may now fit in fewer lines than the current scroll position.
+            let maxscroll = self.maxscroll();
+            let mut edit_text = self.0.write(context.gc_context);
+            edit_text.scroll = edit_text.scroll.min(maxscroll);
         }
     }
 
     /// Measure the width and height of the `EditText`'s current text load.
     ///
     /// The returned tuple should be interpreted as width, then height.
-    pub fn measure_text(self, _context: &mut UpdateContext<'_, 'gc>) -> (Twips, Twips) {
+    pub fn measure_text(self, context: &mut UpdateContext<'_, 'gc>) -> (Twips, Twips) {
+        self.relayout_if_resized(context);
+
         let edit_text = self.0.read();
 
         (
@@ -832,6 +898,7 @@ impl<'gc> EditText<'gc> {
 
         let edit_text = self.0.read();
         let selection = edit_text.selection;
+        let composition = edit_text.composition.clone();
 
         let caret = if let LayoutContent::Text { start, end, .. } = &lbox.content() {
             if let Some(selection) = selection {
@@ -839,7 +906,7 @@ impl<'gc> EditText<'gc> {
                     && !edit_text.flags.contains(EditTextFlag::READ_ONLY)
                     && selection.start() >= *start
                     && selection.end() <= *end
-                    && Utc::now().timestamp_subsec_millis() / 500 == 0
+                    && context.caret_visible
                 {
                     Some((selection.start() - start, end - start))
                 } else {
@@ -867,25 +934,28 @@ impl<'gc> EditText<'gc> {
         {
             let baseline_adjustment =
                 font.get_baseline_for_height(params.height()) - params.height();
+            // Advanced rendering pixel-hints fonts with alignment zones, as long as
+            // the glyphs land on the stage's pixel grid unscaled.
+            // The viewport scale is left out, as it applies to the whole stage.
+            let matrix =
+                context.stage.inverse_view_matrix() * context.transform_stack.transform().matrix;
+            let is_close = |value: f32, expected: f32| (value - expected).abs() < 1.0e-5;
+            let pixel_hinting = edit_text.render_settings.is_advanced()
+                && edit_text.render_settings.grid_fit() == TextGridFit::Pixel
+                && font.has_align_zones()
+                && is_close(matrix.a, 1.0)
+                && is_close(matrix.b, 0.0)
+                && is_close(matrix.c, 0.0)
+                && is_close(matrix.d, 1.0);
             font.evaluate(
                 text,
                 self.text_transform(color.clone(), baseline_adjustment),
-                params,
+                params.with_pixel_hinting(pixel_hinting),
                 |pos, transform, glyph: &Glyph, advance, x| {
                     // If it's highlighted, override the color.
+                    // The highlight itself is drawn behind the text in `render_self`.
                     match selection {
                         Some(selection) if selection.contains(start + pos) => {
-                            // Draw black selection rect
-                            let selection_box = context.transform_stack.transform().matrix
-                                * Matrix::create_box(
-                                    advance.to_pixels() as f32,
-                                    params.height().to_pixels() as f32,
-                                    0.0,
-                                    x + Twips::from_pixels(-1.0),
-                                    Twips::from_pixels(2.0),
-                                );
-                            context.commands.draw_rect(Color::BLACK, selection_box);
-
                             // Set text color to white
                             context.transform_stack.push(&Transform {
                                 matrix: transform.matrix,
@@ -904,6 +974,22 @@ impl<'gc> EditText<'gc> {
                         .render_shape(glyph_shape_handle, context.transform_stack.transform());
                     context.transform_stack.pop();
 
+                    // Underline text that an input method is still composing.
+                    let composing = composition
+                        .as_ref()
+                        .map_or(false, |composition| composition.contains(&(start + pos)));
+                    if composing {
+                        let underline = context.transform_stack.transform().matrix
+                            * Matrix::create_box(
+                                advance.to_pixels() as f32,
+                                1.0,
+                                0.0,
+                                x,
+                                params.height() + Twips::from_pixels(1.0),
+                            );
+                        context.commands.draw_rect(color.clone(), underline);
+                    }
+
                     if let Some((caret_pos, length)) = caret {
                         if caret_pos == pos {
                             let caret = context.transform_stack.transform().matrix
@@ -1174,10 +1260,13 @@ impl<'gc> EditText<'gc> {
             return;
         }
 
-        if let Some(selection) = self.selection() {
+        if let Some(mut selection) = self.selection() {
+            // The text may have been replaced by scripts since the selection was made.
+            selection.clamp(self.text_length());
+
             let mut changed = false;
-            match character as u8 {
-                8 | 127 if !selection.is_caret() => {
+            match character {
+                '\u{8}' | '\u{7f}' if !selection.is_caret() => {
                     // Backspace or delete with multiple characters selected
                     self.replace_text(selection.start(), selection.end(), WStr::empty(), context);
                     self.set_selection(
@@ -1186,7 +1275,7 @@ impl<'gc> EditText<'gc> {
                     );
                     changed = true;
                 }
-                8 => {
+                '\u{8}' => {
                     // Backspace with caret
                     if selection.start() > 0 {
                         // Delete previous character
@@ -1200,7 +1289,7 @@ impl<'gc> EditText<'gc> {
                         changed = true;
                     }
                 }
-                127 => {
+                '\u{7f}' => {
                     // Delete with caret
                     if selection.end() < self.text_length() {
                         // Delete next character
@@ -1211,14 +1300,20 @@ impl<'gc> EditText<'gc> {
                         changed = true;
                     }
                 }
-                code if !(code as char).is_control() => {
-                    self.replace_text(
-                        selection.start(),
-                        selection.end(),
-                        &WString::from_char(character),
-                        context,
-                    );
-                    let new_start = selection.start() + character.len_utf8();
+                _ if !character.is_control() => {
+                    let max_chars = self.max_chars();
+                    if max_chars > 0
+                        && self.text_length() - (selection.end() - selection.start())
+                            >= max_chars as usize
+                    {
+                        // The text field is full.
+                        return;
+                    }
+
+                    // Positions are in string units, which a character may take several of.
+                    let inserted = WString::from_char(character);
+                    self.replace_text(selection.start(), selection.end(), &inserted, context);
+                    let new_start = selection.start() + inserted.len();
                     self.set_selection(
                         Some(TextSelection::for_position(new_start)),
                         context.gc_context,
@@ -1229,17 +1324,110 @@ impl<'gc> EditText<'gc> {
             }
 
             if changed {
-                let mut activation = Avm1Activation::from_nothing(
-                    context.reborrow(),
-                    ActivationIdentifier::root("[Propagate Text Binding]"),
-                    self.into(),
-                );
-                self.propagate_text_binding(&mut activation);
-                self.on_changed(&mut activation);
+                self.on_user_edit(context);
             }
         }
     }
 
+    /// Displays the in-progress composition of an input method in place of the
+    /// selection, or of the previous composition.
+    ///
+    /// `cursor` is a byte range within `text`; the caret is placed at its start.
+    pub fn ime_preedit(
+        self,
+        text: &str,
+        cursor: Option<(usize, usize)>,
+        context: &mut UpdateContext<'_, 'gc>,
+    ) {
+        if self.0.read().flags.contains(EditTextFlag::READ_ONLY) {
+            return;
+        }
+
+        let range = match self.composition_or_selection() {
+            Some(range) => range,
+            None => return,
+        };
+
+        let preedit = WString::from_utf8(text);
+        self.replace_text(range.start, range.end, &preedit, context);
+
+        // Convert the byte offset of the cursor into string units.
+        let caret = cursor
+            .and_then(|(start, _)| text.get(..start))
+            .map_or(preedit.len(), |before| WString::from_utf8(before).len());
+        self.0.write(context.gc_context).composition = if preedit.is_empty() {
+            None
+        } else {
+            Some(range.start..range.start + preedit.len())
+        };
+        self.set_selection(
+            Some(TextSelection::for_position(range.start + caret)),
+            context.gc_context,
+        );
+    }
+
+    /// Replaces the composition of an input method, or the selection if there is
+    /// none, with the text it committed.
+    pub fn ime_commit(self, text: &str, context: &mut UpdateContext<'_, 'gc>) {
+        if self.0.read().flags.contains(EditTextFlag::READ_ONLY) {
+            return;
+        }
+
+        let range = match self.composition_or_selection() {
+            Some(range) => range,
+            None => return,
+        };
+        self.0.write(context.gc_context).composition = None;
+
+        // Only commit as many whole characters as the field has room for.
+        let max_chars = self.max_chars();
+        let room = if max_chars > 0 {
+            (max_chars as usize).saturating_sub(self.text_length() - range.len())
+        } else {
+            usize::MAX
+        };
+        let mut committed = WString::new();
+        for c in text.chars() {
+            let c = WString::from_char(c);
+            if committed.len() + c.len() > room {
+                break;
+            }
+            committed.push_str(&c);
+        }
+
+        self.replace_text(range.start, range.end, &committed, context);
+        self.set_selection(
+            Some(TextSelection::for_position(range.start + committed.len())),
+            context.gc_context,
+        );
+        self.on_user_edit(context);
+    }
+
+    /// Returns the range of text that input method text replaces.
+    fn composition_or_selection(self) -> Option<Range<usize>> {
+        let text_length = self.text_length();
+        let read = self.0.read();
+        match (&read.composition, read.selection) {
+            (Some(composition), _) if composition.end <= text_length => Some(composition.clone()),
+            (_, Some(mut selection)) => {
+                selection.clamp(text_length);
+                Some(selection.start()..selection.end())
+            }
+            _ => None,
+        }
+    }
+
+    /// Notifies scripts that the user changed the text.
+    fn on_user_edit(self, context: &mut UpdateContext<'_, 'gc>) {
+        let mut activation = Avm1Activation::from_nothing(
+            context.reborrow(),
+            ActivationIdentifier::root("[Propagate Text Binding]"),
+            self.into(),
+        );
+        self.propagate_text_binding(&mut activation);
+        self.on_changed(&mut activation);
+    }
+
     /// Listens for keyboard text control commands.
     ///
     /// TODO: Add explicit text control events (#4452).
@@ -1287,6 +1475,19 @@ impl<'gc> EditText<'gc> {
                         edit_text.selection = Some(selection);
                         return ClipEventResult::Handled;
                     }
+                    ButtonKeyCode::Home | ButtonKeyCode::End => {
+                        selection.to = if key_code == ButtonKeyCode::Home {
+                            0
+                        } else {
+                            length
+                        };
+                        if !context.input.is_key_down(KeyCode::Shift) {
+                            selection.from = selection.to;
+                        }
+                        selection.clamp(length);
+                        edit_text.selection = Some(selection);
+                        return ClipEventResult::Handled;
+                    }
                     _ => (),
                 }
             }
@@ -1383,6 +1584,53 @@ impl<'gc> EditText<'gc> {
         }
     }
 
+    /// Calculate the highlight rectangles covering the selected text, one
+    /// for each layout box containing selected characters.
+    ///
+    /// The rectangles are in layout coordinates, before the internal padding
+    /// and scroll offset are applied. A caret selection has no highlight.
+    pub fn selection_bounds(self) -> Vec<BoundingBox> {
+        let edit_text = self.0.read();
+        let selection = match edit_text.selection {
+            Some(selection) if !selection.is_caret() => selection,
+            _ => return vec![],
+        };
+
+        let mut selection_bounds = vec![];
+        for layout_box in edit_text.layout.iter() {
+            let start = match layout_box.content() {
+                LayoutContent::Text { start, .. } => *start,
+                _ => continue,
+            };
+
+            if let Some((text, _tf, font, params, _color)) =
+                layout_box.as_renderable_text(edit_text.text_spans.displayed_text())
+            {
+                let origin = layout_box.bounds().origin();
+                let mut bounds = BoundingBox::default();
+                font.evaluate(
+                    text,
+                    Default::default(),
+                    params,
+                    |pos, _transform, _glyph: &Glyph, advance, x| {
+                        if selection.contains(start + pos) {
+                            let x = origin.x() + x - Twips::from_pixels(1.0);
+                            let y = origin.y() + Twips::from_pixels(2.0);
+                            bounds.encompass(x, y);
+                            bounds.encompass(x + advance, y + params.height());
+                        }
+                    },
+                );
+
+                if bounds.valid {
+                    selection_bounds.push(bounds);
+                }
+            }
+        }
+
+        selection_bounds
+    }
+
     /// Count the number of lines in the text box's layout.
     pub fn layout_lines(self) -> usize {
         self.0.read().line_data.len()
@@ -1489,8 +1737,8 @@ impl<'gc> TDisplayObject<'gc> for EditText<'gc> {
         }
     }
 
-    fn run_frame(&self, _context: &mut UpdateContext) {
-        // Noop
+    fn run_frame(&self, context: &mut UpdateContext<'_, 'gc>) {
+        self.relayout_if_resized(context);
     }
 
     fn as_edit_text(&self) -> Option<EditText<'gc>> {
@@ -1673,10 +1921,7 @@ impl<'gc> TDisplayObject<'gc> for EditText<'gc> {
         if edit_text.layout.is_empty() && !edit_text.flags.contains(EditTextFlag::READ_ONLY) {
             let selection = edit_text.selection;
             if let Some(selection) = selection {
-                if selection.is_caret()
-                    && selection.start() == 0
-                    && Utc::now().timestamp_subsec_millis() / 500 == 0
-                {
+                if selection.is_caret() && selection.start() == 0 && context.caret_visible {
                     let caret = context.transform_stack.transform().matrix
                         * Matrix::create_box(
                             1.0,
@@ -1693,6 +1938,18 @@ impl<'gc> TDisplayObject<'gc> for EditText<'gc> {
                 }
             }
         } else {
+            for bounds in self.selection_bounds() {
+                let selection_box = context.transform_stack.transform().matrix
+                    * Matrix::create_box(
+                        bounds.width().to_pixels() as f32,
+                        bounds.height().to_pixels() as f32,
+                        0.0,
+                        bounds.x_min,
+                        bounds.y_min,
+                    );
+                context.commands.draw_rect(Color::BLACK, selection_box);
+            }
+
             for layout_box in edit_text.layout.iter() {
                 self.render_layout_box(context, layout_box);
             }
@@ -1752,6 +2009,7 @@ impl<'gc> TDisplayObject<'gc> for EditText<'gc> {
         text.flags.set(EditTextFlag::HAS_FOCUS, focused);
         if !focused {
             text.selection = None;
+            text.composition = None;
         }
     }
 
@@ -1864,7 +2122,7 @@ struct EditTextStatic {
     initial_text: Option<WString>,
 }
 
-#[derive(Copy, Clone, Debug, Collect)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Collect)]
 #[collect(require_static)]
 pub struct TextSelection {
     from: usize,
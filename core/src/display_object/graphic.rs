@@ -142,6 +142,14 @@ impl<'gc> TDisplayObject<'gc> for Graphic<'gc> {
         }
     }
 
+    fn self_rect(&self) -> BoundingBox {
+        if let Some(drawing) = &self.0.read().drawing {
+            drawing.edge_bounds()
+        } else {
+            (&self.0.read().static_data.shape.edge_bounds).into()
+        }
+    }
+
     fn construct_frame(&self, context: &mut UpdateContext<'_, 'gc>) {
         if context.is_action_script_3() && matches!(self.object2(), Avm2Value::Null) {
             let shape_constr = context.avm2.classes().shape;
@@ -421,7 +421,7 @@ pub trait TInteractiveObject<'gc>:
                     "mouseWheel",
                     self.as_displayobject(),
                     None,
-                    delta.lines() as i32,
+                    delta.whole_lines(),
                 );
 
                 if let Err(e) = Avm2::dispatch_event(&mut activation.context, avm2_event, target) {
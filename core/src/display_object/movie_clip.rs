@@ -510,6 +510,10 @@ impl<'gc> MovieClip<'gc> {
                     .0
                     .write(context.gc_context)
                     .define_font_4(context, reader),
+                TagCode::DefineFontAlignZones => self
+                    .0
+                    .write(context.gc_context)
+                    .define_font_align_zones(context, reader),
                 TagCode::DefineMorphShape => self
                     .0
                     .write(context.gc_context)
@@ -677,10 +681,10 @@ impl<'gc> MovieClip<'gc> {
         }
 
         let start = reader.as_slice();
-        // Queue the init actions.
-        // TODO: Init actions are supposed to be executed once, and it gives a
-        // sprite ID... how does that work?
-        let _sprite_id = reader.read_u16()?;
+        // Init actions run as soon as their tag is preloaded, which is always
+        // before the timeline reaches their frame. Each sprite's init actions
+        // only ever run once, so any further blocks for it are ignored.
+        let sprite_id = reader.read_u16()?;
         let num_read = reader.pos(start);
 
         let slice = self
@@ -690,7 +694,11 @@ impl<'gc> MovieClip<'gc> {
             .swf
             .resize_to_reader(reader, tag_len - num_read);
 
-        if !slice.is_empty() {
+        let first_run = context
+            .library
+            .library_for_movie_mut(self.movie())
+            .mark_init_action_run(sprite_id);
+        if first_run && !slice.is_empty() {
             Avm1::run_stack_frame_for_init_action(self.into(), slice, context);
         }
 
@@ -1496,7 +1504,7 @@ impl<'gc> MovieClip<'gc> {
                                 .collect(),
                         );
                     }
-                    // TODO: Missing PlaceObject properties: amf_data, filters
+                    // TODO: Missing PlaceObject properties: amf_data, class_name
 
                     // Run first frame.
                     child.post_instantiation(context, None, Instantiator::Movie, false);
@@ -2146,6 +2154,11 @@ impl<'gc> MovieClip<'gc> {
         RefMut::map(self.0.write(gc_context), |s| &mut s.drawing)
     }
 
+    /// Renders the vector graphics of this clip, without its children.
+    pub fn render_drawing(self, context: &mut RenderContext<'_, 'gc>) {
+        self.0.read().drawing.render(context);
+    }
+
     pub fn is_button_mode(&self, context: &mut UpdateContext<'_, 'gc>) -> bool {
         if self.forced_button_mode()
             || self
@@ -2329,7 +2342,27 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
         let avm2_object = write.object.and_then(|o| o.as_avm2_object());
 
         if let Some(avm2_object) = avm2_object {
-            if let Some(frame_id) = write.queued_script_frame {
+            // A frame script suspended on a previous frame takes the place of
+            // this frame's scripts.
+            if write
+                .flags
+                .contains(MovieClipFlags::EXECUTING_AVM2_FRAME_SCRIPT)
+                && context.avm2.has_suspended_frame_script(self.into())
+            {
+                drop(write);
+                let suspended =
+                    Avm2::resume_frame_script(self.into(), context).unwrap_or_else(|e| {
+                        tracing::error!("Error occured when running AVM2 frame script: {}", e);
+                        false
+                    });
+                write = self.0.write(context.gc_context);
+
+                if !suspended {
+                    write
+                        .flags
+                        .remove(MovieClipFlags::EXECUTING_AVM2_FRAME_SCRIPT);
+                }
+            } else if let Some(frame_id) = write.queued_script_frame {
                 // If we are already executing frame scripts, then we shouldn't
                 // run frame scripts recursively. This is because AVM2 can run
                 // gotos, which will both queue and run frame scripts for the
@@ -2355,19 +2388,28 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
                                     .insert(MovieClipFlags::EXECUTING_AVM2_FRAME_SCRIPT);
 
                                 drop(write);
-                                if let Err(e) = Avm2::run_stack_frame_for_callable(
+                                let suspended = Avm2::run_frame_script(
+                                    self.into(),
                                     callable,
                                     Some(avm2_object),
-                                    &[],
                                     context,
-                                ) {
+                                )
+                                .unwrap_or_else(|e| {
                                     tracing::error!(
                                         "Error occured when running AVM2 frame script: {}",
                                         e
                                     );
-                                }
+                                    false
+                                });
                                 write = self.0.write(context.gc_context);
 
+                                // A suspended script is still executing, so
+                                // no other frame scripts of this clip run
+                                // until it finishes.
+                                if suspended {
+                                    break;
+                                }
+
                                 write
                                     .flags
                                     .remove(MovieClipFlags::EXECUTING_AVM2_FRAME_SCRIPT);
@@ -2415,7 +2457,7 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
     }
 
     fn render_self(&self, context: &mut RenderContext<'_, 'gc>) {
-        self.0.read().drawing.render(context);
+        self.render_drawing(context);
         self.render_children(context);
     }
 
@@ -2423,6 +2465,10 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
         self.0.read().drawing.self_bounds()
     }
 
+    fn self_rect(&self) -> BoundingBox {
+        self.0.read().drawing.edge_bounds()
+    }
+
     fn hit_test_shape(
         &self,
         context: &mut UpdateContext<'_, 'gc>,
@@ -2560,6 +2606,11 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
         {
             let mut mc = self.0.write(context.gc_context);
             mc.stop_audio_stream(context);
+
+            // A frame script suspended on an earlier frame never gets to finish.
+            if context.avm2.cancel_frame_script((*self).into()) {
+                mc.flags.remove(MovieClipFlags::EXECUTING_AVM2_FRAME_SCRIPT);
+            }
         }
         self.event_dispatch(context, ClipEvent::Unload);
         self.set_removed(context.gc_context, true);
@@ -3357,6 +3408,25 @@ impl<'gc, 'a> MovieClipData<'gc> {
         Ok(())
     }
 
+    #[inline]
+    fn define_font_align_zones(
+        &mut self,
+        context: &mut UpdateContext<'_, 'gc>,
+        reader: &mut SwfStream<'a>,
+    ) -> Result<(), Error> {
+        if let swf::Tag::DefineFontAlignZones { id, .. } = reader.read_define_font_align_zones()? {
+            let library = context.library.library_for_movie_mut(self.movie());
+            match library.character_by_id(id) {
+                Some(Character::Font(font)) => font.set_has_align_zones(),
+                _ => tracing::warn!(
+                    "Tried to apply DefineFontAlignZones to non-font character ID {}",
+                    id
+                ),
+            }
+        }
+        Ok(())
+    }
+
     #[inline]
     fn define_sound(
         &mut self,
@@ -4223,3 +4293,184 @@ pub struct Avm2FrameScript<'gc> {
     /// The AVM2 callable object to invoke when the frame script runs.
     pub callable: Avm2Object<'gc>,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::context::RenderContext;
+    use crate::display_object::{TDisplayObject, TDisplayObjectContainer};
+    use crate::player::{Player, PlayerBuilder};
+    use crate::string::WStr;
+    use crate::tag_utils::SwfMovie;
+    use ruffle_render::commands::{Command, CommandList};
+    use ruffle_render::transform::TransformStack;
+    use swf::{BlendMode, Filter, PlaceObject, PlaceObjectAction, SwfStr, Tag};
+
+    fn place_object(action: PlaceObjectAction) -> PlaceObject<'static> {
+        PlaceObject {
+            version: 3,
+            action,
+            depth: 1,
+            matrix: None,
+            color_transform: None,
+            ratio: None,
+            name: None,
+            clip_depth: None,
+            class_name: None,
+            filters: None,
+            background_color: None,
+            blend_mode: None,
+            clip_actions: None,
+            has_image: false,
+            is_bitmap_cached: None,
+            is_visible: None,
+            amf_data: None,
+        }
+    }
+
+    /// Builds an AVM1 movie that places an empty sprite with the given
+    /// `PlaceObject3` on its first frame and modifies it on its second frame.
+    fn place_object_movie(place: PlaceObject<'static>, modify: PlaceObject<'static>) -> SwfMovie {
+        let header = swf::Header::default_with_swf_version(11);
+        let mut data = Vec::new();
+        swf::write_swf(
+            &header,
+            &[
+                Tag::DefineSprite(swf::Sprite {
+                    id: 1,
+                    num_frames: 1,
+                    tags: vec![Tag::ShowFrame],
+                }),
+                Tag::PlaceObject(Box::new(place)),
+                Tag::ShowFrame,
+                Tag::PlaceObject(Box::new(modify)),
+                Tag::ShowFrame,
+            ],
+            &mut data,
+        )
+        .expect("Failed to write test movie");
+        SwfMovie::from_data(&data, None, None).expect("Failed to parse test movie")
+    }
+
+    /// Returns the `(visible, blend_mode, is_bitmap_cached, name)` of the placed sprite.
+    fn placed_state(player: &mut Player) -> (bool, BlendMode, bool, String) {
+        player.update(|context| {
+            let child = context
+                .stage
+                .root_clip()
+                .as_container()
+                .and_then(|root| root.child_by_depth(1))
+                .expect("Sprite should be placed at depth 1");
+            (
+                child.visible(),
+                child.blend_mode(),
+                child.is_bitmap_cached(),
+                child.name().to_string(),
+            )
+        })
+    }
+
+    #[test]
+    fn place_object_3_applies_display_properties() {
+        let mut place = place_object(PlaceObjectAction::Place(1));
+        place.name = Some(SwfStr::from_utf8_str("clip"));
+        place.blend_mode = Some(BlendMode::Multiply);
+        place.is_bitmap_cached = Some(true);
+        place.is_visible = Some(false);
+
+        let mut modify = place_object(PlaceObjectAction::Modify);
+        modify.blend_mode = Some(BlendMode::Screen);
+        modify.is_visible = Some(true);
+
+        let player = PlayerBuilder::new()
+            .with_movie(place_object_movie(place, modify))
+            .build();
+        let mut player = player.lock().unwrap();
+
+        player.run_frame();
+        assert_eq!(
+            placed_state(&mut player),
+            (false, BlendMode::Multiply, true, "clip".to_string())
+        );
+        assert!(player.update(|context| {
+            context
+                .stage
+                .root_clip()
+                .as_container()
+                .and_then(|root| root.child_by_name(WStr::from_units(b"clip"), true))
+                .is_some()
+        }));
+
+        player.run_frame();
+        assert_eq!(
+            placed_state(&mut player),
+            (true, BlendMode::Screen, true, "clip".to_string())
+        );
+    }
+
+    /// Returns the filters on the placed sprite, and the filters its render commands apply.
+    fn placed_filters(player: &mut Player) -> (Vec<Filter>, Option<Vec<Filter>>) {
+        player.update(|context| {
+            let child = context
+                .stage
+                .root_clip()
+                .as_container()
+                .and_then(|root| root.child_by_depth(1))
+                .expect("Sprite should be placed at depth 1");
+
+            let mut transform_stack = TransformStack::new();
+            let mut render_context = RenderContext {
+                renderer: context.renderer,
+                commands: CommandList::new(),
+                gc_context: context.gc_context,
+                ui: context.ui,
+                library: context.library,
+                transform_stack: &mut transform_stack,
+                is_offscreen: true,
+                stage: context.stage,
+                clip_depth_stack: vec![],
+                allow_mask: true,
+                caret_visible: false,
+            };
+            child.render(&mut render_context);
+            let rendered = render_context
+                .commands
+                .commands
+                .into_iter()
+                .find_map(|command| match command {
+                    Command::Filters(_, filters) => Some(filters),
+                    _ => None,
+                });
+
+            (child.bitmap_filters(), rendered)
+        })
+    }
+
+    #[test]
+    fn place_object_3_applies_filters() {
+        let blur = Filter::BlurFilter(Box::new(swf::BlurFilter {
+            blur_x: swf::Fixed16::from_f64(8.0),
+            blur_y: swf::Fixed16::from_f64(4.0),
+            num_passes: 2,
+        }));
+        let mut place = place_object(PlaceObjectAction::Place(1));
+        place.filters = Some(vec![blur.clone()]);
+
+        let mut modify = place_object(PlaceObjectAction::Modify);
+        modify.filters = Some(vec![]);
+
+        let player = PlayerBuilder::new()
+            .with_movie(place_object_movie(place, modify))
+            .build();
+        let mut player = player.lock().unwrap();
+
+        player.run_frame();
+        assert_eq!(
+            placed_filters(&mut player),
+            (vec![blur.clone()], Some(vec![blur]))
+        );
+
+        // An empty filter list removes the filters.
+        player.run_frame();
+        assert_eq!(placed_filters(&mut player), (vec![], None));
+    }
+}
@@ -24,8 +24,9 @@ use crate::tag_utils::SwfMovie;
 use crate::vminterface::Instantiator;
 use bitflags::bitflags;
 use gc_arena::{Collect, GcCell, MutationContext};
-use ruffle_render::backend::ViewportDimensions;
+use ruffle_render::backend::{RenderBackend, ViewportDimensions};
 use ruffle_render::commands::CommandHandler;
+use ruffle_render::quality::RenderQuality;
 use std::cell::{Ref, RefMut};
 use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
@@ -211,8 +212,8 @@ impl<'gc> Stage<'gc> {
 
     /// Returns the quality setting of the stage.
     ///
-    /// In the Flash Player, the quality setting affects anti-aliasing and smoothing of bitmaps.
-    /// This setting is currently ignored in Ruffle.
+    /// The quality setting affects anti-aliasing and smoothing of bitmaps.
+    /// In Ruffle, it controls the tessellation tolerance and multisampling of the renderer.
     /// Used by AVM1 `stage.quality` and AVM2 `Stage.quality` properties.
     pub fn quality(self) -> StageQuality {
         self.0.read().quality
@@ -220,10 +221,15 @@ impl<'gc> Stage<'gc> {
 
     /// Sets the quality setting of the stage.
     ///
-    /// In the Flash Player, the quality setting affects anti-aliasing and smoothing of bitmaps.
-    /// This setting is currently ignored in Ruffle.
+    /// The quality setting affects anti-aliasing and smoothing of bitmaps.
+    /// The new quality is passed on to the renderer, and applies to shapes registered afterwards.
     /// Used by AVM1 `stage.quality` and AVM2 `Stage.quality` properties.
-    pub fn set_quality(self, gc_context: MutationContext<'gc, '_>, quality: StageQuality) {
+    pub fn set_quality(
+        self,
+        gc_context: MutationContext<'gc, '_>,
+        renderer: &mut dyn RenderBackend,
+        quality: StageQuality,
+    ) {
         let mut this = self.0.write(gc_context);
         this.quality = quality;
         this.use_bitmap_downsampling = matches!(
@@ -234,6 +240,9 @@ impl<'gc> Stage<'gc> {
                 | StageQuality::High16x16
                 | StageQuality::High16x16Linear
         );
+        drop(this);
+
+        renderer.set_quality(quality.render_quality());
     }
 
     pub fn stage3ds(&self) -> Ref<Vec<Avm2Object<'gc>>> {
@@ -1025,7 +1034,7 @@ impl FromWStr for StageAlign {
 /// The quality setting of the `Stage`.
 ///
 /// In the Flash Player, this settings affects anti-aliasing and bitmap smoothing.
-/// These settings currently have no effect in Ruffle, but the active setting is still stored.
+/// In Ruffle, this controls the anti-aliasing and curve flattening of the renderer.
 /// [StageQuality in the AS3 Reference](https://help.adobe.com/en_US/FlashPlatform/reference/actionscript/3/flash/display/StageQuality.html)
 #[derive(Default, Clone, Collect, Copy, Debug, Eq, PartialEq)]
 #[collect(require_static)]
@@ -1064,6 +1073,18 @@ pub enum StageQuality {
 }
 
 impl StageQuality {
+    /// Returns the quality the renderer should use to draw content at this setting.
+    pub fn render_quality(self) -> RenderQuality {
+        match self {
+            StageQuality::Low => RenderQuality::Low,
+            StageQuality::Medium => RenderQuality::Medium,
+            StageQuality::High => RenderQuality::High,
+            StageQuality::Best => RenderQuality::Best,
+            StageQuality::High8x8 | StageQuality::High8x8Linear => RenderQuality::High8x8,
+            StageQuality::High16x16 | StageQuality::High16x16Linear => RenderQuality::High16x16,
+        }
+    }
+
     /// Returns the string representing the quality setting as returned by AVM1 `_quality` and
     /// AVM2 `Stage.quality`.
     pub fn into_avm_str(self) -> &'static str {
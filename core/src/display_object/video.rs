@@ -7,6 +7,7 @@ use crate::avm2::{
 use crate::context::{RenderContext, UpdateContext};
 use crate::display_object::{DisplayObjectBase, DisplayObjectPtr, TDisplayObject};
 use crate::prelude::*;
+use crate::streams::NetStream;
 use crate::tag_utils::{SwfMovie, SwfSlice};
 use crate::vminterface::{AvmObject, Instantiator};
 use core::fmt;
@@ -68,6 +69,12 @@ pub struct VideoData<'gc> {
     /// the prior keyframe. The first frame in the stream will always be
     /// treated as a keyframe regardless of it being flagged as one.
     keyframes: BTreeSet<u32>,
+
+    /// The `NetStream` attached to this video player, if any.
+    ///
+    /// An attached stream supersedes the video's own source: its frames are
+    /// decoded each tick, and timeline seeks are ignored.
+    netstream: Option<NetStream<'gc>>,
 }
 
 /// An optionally-instantiated video stream.
@@ -128,6 +135,7 @@ impl<'gc> Video<'gc> {
                 decoded_frame: None,
                 object: None,
                 keyframes: BTreeSet::new(),
+                netstream: None,
             },
         ))
     }
@@ -160,6 +168,18 @@ impl<'gc> Video<'gc> {
         }
     }
 
+    /// Attach a `NetStream` to play in this video player, or detach the
+    /// current one with `None`.
+    pub fn attach_netstream(
+        self,
+        gc_context: MutationContext<'gc, '_>,
+        netstream: Option<NetStream<'gc>>,
+    ) {
+        let mut write = self.0.write(gc_context);
+        write.netstream = netstream;
+        write.decoded_frame = None;
+    }
+
     /// Seek to a particular frame in the video stream.
     ///
     /// This function ensures that the given `frame_id` is valid by first
@@ -169,6 +189,10 @@ impl<'gc> Video<'gc> {
     /// order. This matches Flash Player behavior.
     pub fn seek(self, context: &mut UpdateContext<'_, 'gc>, mut frame_id: u32) {
         let read = self.0.read();
+        if read.netstream.is_some() {
+            return;
+        }
+
         if let VideoStream::Uninstantiated(_) = &read.stream {
             drop(read);
 
@@ -400,6 +424,13 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
         }
     }
 
+    fn run_frame(&self, context: &mut UpdateContext<'_, 'gc>) {
+        let netstream = self.0.read().netstream;
+        if let Some(frame) = netstream.and_then(|netstream| netstream.tick(context)) {
+            self.0.write(context.gc_context).decoded_frame = Some(frame);
+        }
+    }
+
     fn construct_frame(&self, context: &mut UpdateContext<'_, 'gc>) {
         if context.is_action_script_3() && matches!(self.object2(), Avm2Value::Null) {
             let video_constr = context.avm2.classes().video;
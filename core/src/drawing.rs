@@ -3,7 +3,8 @@ use gc_arena::Collect;
 use ruffle_render::backend::{RenderBackend, ShapeHandle};
 use ruffle_render::bitmap::{BitmapHandle, BitmapInfo, BitmapSize, BitmapSource};
 use ruffle_render::bounding_box::BoundingBox;
-use ruffle_render::commands::CommandHandler;
+use ruffle_render::commands::{CommandHandler, MeshFill, TriangleMesh};
+use ruffle_render::matrix::Matrix;
 use ruffle_render::shape_utils::{DistilledShape, DrawCommand, DrawPath};
 use std::cell::Cell;
 use swf::{FillStyle, LineStyle, Twips};
@@ -17,6 +18,7 @@ pub struct Drawing {
     dirty: Cell<bool>,
     paths: Vec<DrawingPath>,
     bitmaps: Vec<BitmapInfo>,
+    triangles: Vec<TriangleMesh>,
     current_fill: Option<DrawingFill>,
     current_line: Option<DrawingLine>,
     pending_lines: Vec<DrawingLine>,
@@ -39,6 +41,7 @@ impl Drawing {
             dirty: Cell::new(false),
             paths: Vec::new(),
             bitmaps: Vec::new(),
+            triangles: Vec::new(),
             current_fill: None,
             current_line: None,
             pending_lines: Vec::new(),
@@ -55,6 +58,7 @@ impl Drawing {
             dirty: Cell::new(true),
             paths: Vec::new(),
             bitmaps: Vec::new(),
+            triangles: Vec::new(),
             current_fill: None,
             current_line: None,
             pending_lines: Vec::new(),
@@ -132,6 +136,7 @@ impl Drawing {
         self.pending_lines.clear();
         self.paths.clear();
         self.bitmaps.clear();
+        self.triangles.clear();
         self.edge_bounds = BoundingBox::default();
         self.shape_bounds = BoundingBox::default();
         self.dirty.set(true);
@@ -203,6 +208,56 @@ impl Drawing {
         self.dirty.set(true);
     }
 
+    /// Adds a mesh of triangles, filled with the current fill style.
+    ///
+    /// `vertices` are in pixels. `uvs` are normalized texture coordinates for bitmap
+    /// fills; without them, the bitmap is placed by the matrix of the fill.
+    pub fn draw_triangles(
+        &mut self,
+        vertices: Vec<(f32, f32)>,
+        indices: Vec<u32>,
+        uvs: Option<Vec<(f32, f32)>>,
+    ) {
+        let (fill, uvs) = match self.current_fill.as_ref().map(|fill| &fill.style) {
+            Some(FillStyle::Color(color)) => (MeshFill::Color(color.clone()), None),
+            Some(FillStyle::Bitmap {
+                id,
+                matrix,
+                is_smoothed,
+                is_repeating,
+            }) => {
+                let bitmap = match self.bitmaps.get(*id as usize) {
+                    Some(bitmap) => bitmap,
+                    None => return,
+                };
+                let uvs = uvs.unwrap_or_else(|| bitmap_uvs(bitmap, (*matrix).into(), &vertices));
+                let fill = MeshFill::Bitmap {
+                    bitmap: bitmap.handle.clone(),
+                    smoothing: *is_smoothed,
+                    repeating: *is_repeating,
+                };
+                (fill, Some(uvs))
+            }
+            Some(_) => {
+                tracing::warn!("drawTriangles: Gradient fills are not yet implemented");
+                return;
+            }
+            None => return,
+        };
+
+        for &(x, y) in &vertices {
+            let (x, y) = (Twips::from_pixels(x.into()), Twips::from_pixels(y.into()));
+            self.shape_bounds.encompass(x, y);
+            self.edge_bounds.encompass(x, y);
+        }
+        self.triangles.push(TriangleMesh {
+            vertices,
+            indices,
+            uvs,
+            fill,
+        });
+    }
+
     pub fn add_bitmap(&mut self, bitmap: BitmapInfo) -> u16 {
         let id = self.bitmaps.len() as u16;
         self.bitmaps.push(bitmap);
@@ -294,12 +349,23 @@ impl Drawing {
                 .commands
                 .render_shape(handle, context.transform_stack.transform());
         }
+
+        for mesh in &self.triangles {
+            context
+                .commands
+                .draw_triangles(mesh.clone(), context.transform_stack.transform());
+        }
     }
 
     pub fn self_bounds(&self) -> BoundingBox {
         self.shape_bounds.clone()
     }
 
+    /// The bounds of this drawing, excluding strokes.
+    pub fn edge_bounds(&self) -> BoundingBox {
+        self.edge_bounds.clone()
+    }
+
     pub fn hit_test(
         &self,
         point: (Twips, Twips),
@@ -455,3 +521,23 @@ fn stretch_bounding_box(
         }
     }
 }
+
+/// Maps each vertex (in pixels) to normalized coordinates in a bitmap placed by `matrix`.
+fn bitmap_uvs(bitmap: &BitmapInfo, matrix: Matrix, vertices: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    let det = matrix.a * matrix.d - matrix.b * matrix.c;
+    let (width, height) = (f32::from(bitmap.width), f32::from(bitmap.height));
+    if det == 0.0 || width == 0.0 || height == 0.0 {
+        return vec![(0.0, 0.0); vertices.len()];
+    }
+    vertices
+        .iter()
+        .map(|&(x, y)| {
+            // The fill matrix maps bitmap pixels to twips, so invert it.
+            let x = x * Twips::TWIPS_PER_PIXEL as f32 - matrix.tx.get() as f32;
+            let y = y * Twips::TWIPS_PER_PIXEL as f32 - matrix.ty.get() as f32;
+            let u = (matrix.d * x - matrix.c * y) / det;
+            let v = (matrix.a * y - matrix.b * x) / det;
+            (u / width, v / height)
+        })
+        .collect()
+}
@@ -32,6 +32,18 @@ pub enum PlayerEvent {
     TextInput {
         codepoint: char,
     },
+    /// The input method's in-progress composition changed.
+    ///
+    /// `cursor` is the byte range of the input method's cursor within `text`, if it should
+    /// be shown. An empty `text` cancels the composition.
+    ImePreedit {
+        text: String,
+        cursor: Option<(usize, usize)>,
+    },
+    /// The input method committed its composition, replacing any preedit text.
+    ImeCommit {
+        text: String,
+    },
 }
 
 /// The distance scrolled by the mouse wheel.
@@ -52,6 +64,19 @@ impl MouseWheelDelta {
             Self::Pixels(delta) => delta / Self::MOUSE_WHEEL_SCALE,
         }
     }
+
+    /// Returns the number of whole lines reported to ActionScript as the event's `delta`.
+    ///
+    /// Partial lines are rounded away from zero, so that small pixel deltas
+    /// (such as from a touchpad) still scroll in the right direction.
+    pub fn whole_lines(self) -> i32 {
+        let lines = self.lines();
+        if lines > 0.0 {
+            lines.ceil() as i32
+        } else {
+            lines.floor() as i32
+        }
+    }
 }
 
 impl PartialEq for MouseWheelDelta {
@@ -1,6 +1,6 @@
 use crate::html::TextSpan;
 use crate::prelude::*;
-use crate::string::WStr;
+use crate::string::{WStr, WString};
 use gc_arena::{Collect, Gc, MutationContext};
 use ruffle_render::backend::{RenderBackend, ShapeHandle};
 use ruffle_render::transform::Transform;
@@ -9,11 +9,61 @@ use std::cmp::max;
 
 pub use swf::TextGridFit;
 
+/// The maximum number of strings `Font::measure` remembers, across all parameters.
+const MEASURE_CACHE_CAPACITY: usize = 1024;
+
+/// The maximum number of glyph advances `Font::measure` remembers.
+const ADVANCE_CACHE_CAPACITY: usize = 4096;
+
+/// The parameters of a `Font::measure` call, other than the measured text.
+type MeasureParams = (i32, i32, bool, bool, bool);
+
+/// Measured sizes of strings, grouped by the parameters they were measured with.
+#[derive(Debug, Clone, Default)]
+struct MeasureCache {
+    sizes: fnv::FnvHashMap<MeasureParams, fnv::FnvHashMap<WString, (Twips, Twips)>>,
+
+    /// The number of strings in `sizes`, across all parameters.
+    len: usize,
+}
+
+impl MeasureCache {
+    fn get(&self, params: &MeasureParams, text: &WStr) -> Option<(Twips, Twips)> {
+        self.sizes
+            .get(params)
+            .and_then(|strings| strings.get(text))
+            .copied()
+    }
+
+    fn insert(&mut self, params: MeasureParams, text: &WStr, size: (Twips, Twips)) {
+        if self.len >= MEASURE_CACHE_CAPACITY {
+            self.sizes.clear();
+            self.len = 0;
+        }
+        let strings = self.sizes.entry(params).or_default();
+        if strings.insert(text.into(), size).is_none() {
+            self.len += 1;
+        }
+    }
+}
+
+/// Scaled advances of glyphs, keyed by font height, code point, and the code
+/// point of the following character when kerning applies (or 0).
+///
+/// Advances include kerning but not letter spacing. Characters without a
+/// glyph are stored as `None`.
+type AdvanceCache = fnv::FnvHashMap<(i32, u16, u16), Option<Twips>>;
+
 /// Certain Flash routines measure text by rounding down to the nearest whole pixel.
 pub fn round_down_to_pixel(t: Twips) -> Twips {
     Twips::from_pixels(t.to_pixels().floor())
 }
 
+/// Pixel-hinted glyphs are placed on the nearest whole pixel.
+fn round_to_pixel(t: Twips) -> Twips {
+    Twips::from_pixels(t.to_pixels().round())
+}
+
 /// Parameters necessary to evaluate a font.
 #[derive(Copy, Clone, Debug, Collect)]
 #[collect(require_static)]
@@ -31,16 +81,22 @@ pub struct EvalParameters {
     /// pairs of letters, separate from the ordinary width between glyphs. This
     /// parameter allows enabling or disabling that feature.
     kerning: bool,
+
+    /// Whether glyphs are placed on whole pixels.
+    ///
+    /// This should only be enabled when the text is drawn without scaling or
+    /// rotation, as otherwise local pixels don't line up with screen pixels.
+    pixel_hinting: bool,
 }
 
 impl EvalParameters {
     /// Construct eval parameters from their individual parts.
-    #[allow(dead_code)]
-    fn from_parts(height: Twips, letter_spacing: Twips, kerning: bool) -> Self {
+    pub fn from_parts(height: Twips, letter_spacing: Twips, kerning: bool) -> Self {
         Self {
             height,
             letter_spacing,
             kerning,
+            pixel_hinting: false,
         }
     }
 
@@ -51,6 +107,15 @@ impl EvalParameters {
             height: Twips::from_pixels(span.size),
             letter_spacing: Twips::from_pixels(span.letter_spacing),
             kerning: span.kerning,
+            pixel_hinting: false,
+        }
+    }
+
+    /// Enable or disable placing glyphs on whole pixels.
+    pub fn with_pixel_hinting(self, pixel_hinting: bool) -> Self {
+        Self {
+            pixel_hinting,
+            ..self
         }
     }
 
@@ -97,6 +162,23 @@ struct FontData {
 
     /// The identity of the font.
     descriptor: FontDescriptor,
+
+    /// Whether this font has alignment zones, from a `DefineFontAlignZones` tag.
+    /// Only fonts with alignment zones are pixel-hinted by advanced text rendering.
+    ///
+    /// The zones themselves aren't stored: hinting only snaps glyph origins to
+    /// whole pixels, and doesn't fit glyph stems to the zones yet.
+    has_align_zones: Cell<bool>,
+
+    /// Previously measured strings, grouped by the parameters they were measured with.
+    ///
+    /// Text fields re-measure the same text whenever they are laid out, so
+    /// this avoids walking the glyphs of unchanged text again.
+    measure_cache: RefCell<MeasureCache>,
+
+    /// Previously computed glyph advances, used when measuring strings that
+    /// aren't in `measure_cache`.
+    advance_cache: RefCell<AdvanceCache>,
 }
 
 impl<'gc> Font<'gc> {
@@ -159,6 +241,9 @@ impl<'gc> Font<'gc> {
                 descent,
                 leading,
                 descriptor,
+                has_align_zones: Cell::new(false),
+                measure_cache: Default::default(),
+                advance_cache: Default::default(),
             },
         ))
     }
@@ -243,6 +328,17 @@ impl<'gc> Font<'gc> {
         self.0.scale
     }
 
+    /// Returns whether this font has alignment zones for pixel hinting.
+    pub fn has_align_zones(&self) -> bool {
+        self.0.has_align_zones.get()
+    }
+
+    /// Marks this font as having alignment zones, as defined by a
+    /// `DefineFontAlignZones` tag.
+    pub fn set_has_align_zones(&self) {
+        self.0.has_align_zones.set(true);
+    }
+
     /// Evaluate this font against a particular string on a glyph-by-glyph
     /// basis.
     ///
@@ -280,7 +376,16 @@ impl<'gc> Font<'gc> {
                 let twips_advance =
                     Twips::new((advance.get() as f32 * scale) as i32) + params.letter_spacing;
 
-                glyph_func(pos, &transform, glyph, twips_advance, x);
+                if params.pixel_hinting {
+                    // Only the glyph is snapped; advances are unchanged so that
+                    // layout doesn't depend on hinting.
+                    let mut hinted = transform.clone();
+                    hinted.matrix.tx = round_to_pixel(hinted.matrix.tx);
+                    hinted.matrix.ty = round_to_pixel(hinted.matrix.ty);
+                    glyph_func(pos, &hinted, glyph, twips_advance, x);
+                } else {
+                    glyph_func(pos, &transform, glyph, twips_advance, x);
+                }
 
                 // Step horizontally.
                 transform.matrix.tx += twips_advance;
@@ -294,26 +399,59 @@ impl<'gc> Font<'gc> {
     /// The `round` flag causes the returned coordinates to be rounded down to
     /// the nearest pixel.
     pub fn measure(&self, text: &WStr, params: EvalParameters, round: bool) -> (Twips, Twips) {
+        let key = (
+            params.height.get(),
+            params.letter_spacing.get(),
+            params.kerning,
+            params.pixel_hinting,
+            round,
+        );
+        if let Some(size) = self.0.measure_cache.borrow().get(&key, text) {
+            return size;
+        }
+
+        let size = self.measure_uncached(text, params, round);
+        self.0.measure_cache.borrow_mut().insert(key, text, size);
+        size
+    }
+
+    /// Measures a string the same way as `Font::evaluate` would lay it out,
+    /// but only looks at the advance of each glyph.
+    fn measure_uncached(&self, text: &WStr, params: EvalParameters, round: bool) -> (Twips, Twips) {
         let mut width = Twips::ZERO;
         let mut height = Twips::ZERO;
 
-        self.evaluate(
-            text,
-            Default::default(),
-            params,
-            |_pos, transform, _glyph, advance, _x| {
-                let tx = transform.matrix.tx;
-                let ty = transform.matrix.ty;
+        let kerning = params.kerning && self.has_kerning_info();
+        let mut chars = text.chars().peekable();
+        let mut x = Twips::ZERO;
+        while let Some(c) = chars.next() {
+            let c = c.unwrap_or(char::REPLACEMENT_CHARACTER);
+            let next_char = if kerning {
+                let next_char = chars.peek().cloned().unwrap_or(Ok('\0'));
+                next_char.unwrap_or(char::REPLACEMENT_CHARACTER)
+            } else {
+                '\0'
+            };
+            let advance = match self.scaled_advance(c, next_char, params.height) {
+                Some(advance) => advance + params.letter_spacing,
+                None => continue,
+            };
 
-                if round {
-                    width = width.max(round_down_to_pixel(tx + advance));
-                    height = height.max(round_down_to_pixel(ty));
-                } else {
-                    width = width.max(tx + advance);
-                    height = height.max(ty);
-                }
-            },
-        );
+            let (tx, ty) = if params.pixel_hinting {
+                (round_to_pixel(x), round_to_pixel(params.height))
+            } else {
+                (x, params.height)
+            };
+            if round {
+                width = width.max(round_down_to_pixel(tx + advance));
+                height = height.max(round_down_to_pixel(ty));
+            } else {
+                width = width.max(tx + advance);
+                height = height.max(ty);
+            }
+
+            x += advance;
+        }
 
         if text.is_empty() {
             height = max(height, params.height);
@@ -322,6 +460,32 @@ impl<'gc> Font<'gc> {
         (width, height)
     }
 
+    /// Returns the advance of the glyph for `c` at the given height, including
+    /// the kerning between `c` and `next_char`, or `None` if there's no glyph.
+    fn scaled_advance(&self, c: char, next_char: char, height: Twips) -> Option<Twips> {
+        // TODO: Properly handle UTF-16/out-of-bounds code points.
+        let key = (height.get(), c as u16, next_char as u16);
+        if let Some(advance) = self.0.advance_cache.borrow().get(&key) {
+            return *advance;
+        }
+
+        let advance = self.get_glyph_for_char(c).map(|glyph| {
+            let mut advance = Twips::new(glyph.advance);
+            if next_char != '\0' {
+                advance += self.get_kerning_offset(c, next_char);
+            }
+            let scale = height.get() as f32 / self.scale();
+            Twips::new((advance.get() as f32 * scale) as i32)
+        });
+
+        let mut cache = self.0.advance_cache.borrow_mut();
+        if cache.len() >= ADVANCE_CACHE_CAPACITY {
+            cache.clear();
+        }
+        cache.insert(key, advance);
+        advance
+    }
+
     /// Given a line of text, find the first breakpoint within the text.
     ///
     /// This function assumes only `" "` is valid whitespace to split words on,
@@ -686,11 +850,12 @@ impl Default for TextRenderSettings {
 
 #[cfg(test)]
 mod tests {
-    use crate::font::{EvalParameters, Font};
+    use crate::font::{round_down_to_pixel, EvalParameters, Font, MEASURE_CACHE_CAPACITY};
     use crate::player::Player;
-    use crate::string::WStr;
+    use crate::string::{WStr, WString};
     use gc_arena::{rootless_arena, MutationContext};
     use ruffle_render::backend::{null::NullRenderer, ViewportDimensions};
+    use ruffle_render::transform::Transform;
     use swf::Twips;
 
     fn with_device_font<F>(callback: F)
@@ -709,6 +874,262 @@ mod tests {
         })
     }
 
+    /// Loads the device font with an additional kerning pair between "A" and "V".
+    fn with_kerned_device_font<F>(callback: F)
+    where
+        F: for<'gc> FnOnce(MutationContext<'gc, '_>, Font<'gc>),
+    {
+        rootless_arena(|mc| {
+            let mut renderer = NullRenderer::new(ViewportDimensions {
+                width: 0,
+                height: 0,
+                scale_factor: 1.0,
+            });
+            const DEVICE_FONT_TAG: &[u8] = include_bytes!("../assets/noto-sans-definefont3.bin");
+            let mut reader = swf::read::Reader::new(DEVICE_FONT_TAG, 8);
+            let mut tag = reader
+                .read_define_font_2(3)
+                .expect("Built-in font should compile");
+            let layout = tag
+                .layout
+                .as_mut()
+                .expect("Built-in font should have a layout");
+            layout.kerning.push(swf::KerningRecord {
+                left_code: u16::from(b'A'),
+                right_code: u16::from(b'V'),
+                adjustment: Twips::new(-2000),
+            });
+            let font = Font::from_swf_tag(mc, &mut renderer, tag, reader.encoding());
+
+            callback(mc, font);
+        })
+    }
+
+    #[test]
+    fn measure_kerning_pair() {
+        with_kerned_device_font(|_mc, font| {
+            let text = WStr::from_units(b"AV");
+            let kerned = EvalParameters::from_parts(Twips::from_pixels(12.0), Twips::ZERO, true);
+            let unkerned = EvalParameters::from_parts(Twips::from_pixels(12.0), Twips::ZERO, false);
+
+            let (kerned_width, _) = font.measure(text, kerned, false);
+            let (unkerned_width, _) = font.measure(text, unkerned, false);
+            assert!(kerned_width < unkerned_width);
+
+            // Glyph positions must agree with the measured width.
+            let mut kerned_x = Twips::ZERO;
+            font.evaluate(text, Default::default(), kerned, |pos, _, _, _, x| {
+                if pos == 1 {
+                    kerned_x = x;
+                }
+            });
+            let mut unkerned_x = Twips::ZERO;
+            font.evaluate(text, Default::default(), unkerned, |pos, _, _, _, x| {
+                if pos == 1 {
+                    unkerned_x = x;
+                }
+            });
+            assert_eq!(unkerned_x - kerned_x, unkerned_width - kerned_width);
+        });
+    }
+
+    #[test]
+    fn measure_cache_matches_uncached() {
+        with_device_font(|_mc, df| {
+            let text = WStr::from_units(b"The quick brown fox");
+            let params = EvalParameters::from_parts(Twips::from_pixels(12.5), Twips::ZERO, true);
+            let larger = EvalParameters::from_parts(Twips::from_pixels(24.0), Twips::ZERO, true);
+
+            for round in [false, true] {
+                let expected = df.measure_uncached(text, params, round);
+                assert_eq!(df.measure(text, params, round), expected);
+                // The second measurement is served from the cache.
+                assert_eq!(df.measure(text, params, round), expected);
+            }
+            assert_eq!(
+                df.measure(text, larger, false),
+                df.measure_uncached(text, larger, false)
+            );
+            assert!(df.measure(text, larger, false).0 > df.measure(text, params, false).0);
+            assert_eq!(
+                df.measure(WStr::from_units(b"The"), params, false),
+                df.measure_uncached(WStr::from_units(b"The"), params, false)
+            );
+        });
+    }
+
+    /// Measures a string by laying out every glyph with `Font::evaluate`.
+    fn measure_by_evaluate(
+        font: Font<'_>,
+        text: &WStr,
+        params: EvalParameters,
+        round: bool,
+    ) -> (Twips, Twips) {
+        let mut width = Twips::ZERO;
+        let mut height = Twips::ZERO;
+        font.evaluate(
+            text,
+            Default::default(),
+            params,
+            |_, transform, _, advance, _| {
+                let tx = transform.matrix.tx;
+                let ty = transform.matrix.ty;
+                if round {
+                    width = width.max(round_down_to_pixel(tx + advance));
+                    height = height.max(round_down_to_pixel(ty));
+                } else {
+                    width = width.max(tx + advance);
+                    height = height.max(ty);
+                }
+            },
+        );
+        if text.is_empty() {
+            height = height.max(params.height);
+        }
+        (width, height)
+    }
+
+    #[test]
+    fn measure_uncached_matches_evaluate() {
+        with_kerned_device_font(|_mc, font| {
+            let heights = [12.0, 12.5, 31.3];
+            let letter_spacings = [0.0, 0.3, -1.7];
+            let texts = ["", "AVAVA", "The quick brown fox", "ill \u{2603}"];
+            for height in heights {
+                for letter_spacing in letter_spacings {
+                    for (kerning, pixel_hinting, round) in [
+                        (false, false, false),
+                        (true, false, false),
+                        (true, true, false),
+                        (true, false, true),
+                        (false, true, true),
+                    ] {
+                        let params = EvalParameters::from_parts(
+                            Twips::from_pixels(height),
+                            Twips::from_pixels(letter_spacing),
+                            kerning,
+                        )
+                        .with_pixel_hinting(pixel_hinting);
+                        for text in texts {
+                            let text = WString::from_utf8(text);
+                            assert_eq!(
+                                font.measure_uncached(&text, params, round),
+                                measure_by_evaluate(font, &text, params, round),
+                                "{text:?} at {height}px, spacing {letter_spacing}, \
+                                 kerning {kerning}, hinting {pixel_hinting}, round {round}"
+                            );
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn measure_cache_hits() {
+        with_device_font(|_mc, df| {
+            let text = WStr::from_units(b"abc");
+            let params = EvalParameters::from_parts(Twips::from_pixels(12.0), Twips::ZERO, true);
+            df.measure(text, params, false);
+            assert_eq!(df.0.measure_cache.borrow().len, 1);
+
+            // Replace the remembered size; a cache hit returns it unchanged.
+            let fake = (Twips::new(1), Twips::new(2));
+            df.0.measure_cache
+                .borrow_mut()
+                .insert((240, 0, true, false, false), text, fake);
+            assert_eq!(df.measure(text, params, false), fake);
+            assert_eq!(df.0.measure_cache.borrow().len, 1);
+
+            // Different parameters miss the cache.
+            assert_eq!(
+                df.measure(text, params, true),
+                df.measure_uncached(text, params, true)
+            );
+            assert_eq!(df.0.measure_cache.borrow().len, 2);
+
+            // Uncached strings reuse the advances of glyphs measured before.
+            let advance = df.scaled_advance('a', '\0', Twips::new(240)).unwrap();
+            df.0.advance_cache
+                .borrow_mut()
+                .insert((240, u16::from(b'a'), 0), Some(advance + Twips::new(100)));
+            let (width, _) = df.measure(WStr::from_units(b"a"), params, false);
+            assert_eq!(width, advance + Twips::new(100));
+        });
+    }
+
+    #[test]
+    fn measure_cache_is_bounded() {
+        with_device_font(|_mc, df| {
+            for i in 0..MEASURE_CACHE_CAPACITY * 3 {
+                let text = WString::from_utf8(&i.to_string());
+                let height = Twips::from_pixels(10.0 + (i % 7) as f64);
+                let params = EvalParameters::from_parts(height, Twips::ZERO, true);
+                df.measure(&text, params, false);
+
+                let cache = df.0.measure_cache.borrow();
+                assert!(cache.len <= MEASURE_CACHE_CAPACITY);
+                let len: usize = cache.sizes.values().map(|strings| strings.len()).sum();
+                assert_eq!(len, cache.len);
+            }
+        });
+    }
+
+    #[test]
+    fn pixel_hinting_snaps_glyphs() {
+        with_device_font(|_mc, df| {
+            let text = WStr::from_units(b"ill");
+            let params =
+                EvalParameters::from_parts(Twips::from_pixels(12.5), Twips::from_pixels(0.3), true);
+            let mut transform = Transform::default();
+            transform.matrix.tx = Twips::new(7);
+            transform.matrix.ty = Twips::new(-3);
+
+            let mut unhinted = vec![];
+            df.evaluate(text, transform.clone(), params, |_, transform, _, _, x| {
+                unhinted.push((transform.matrix.tx, transform.matrix.ty, x));
+            });
+            let mut hinted = vec![];
+            df.evaluate(
+                text,
+                transform,
+                params.with_pixel_hinting(true),
+                |_, transform, _, _, x| {
+                    hinted.push((transform.matrix.tx, transform.matrix.ty, x));
+                },
+            );
+
+            assert_eq!(hinted.len(), 3);
+            assert!(unhinted
+                .iter()
+                .any(|(tx, ty, _)| tx.get() % 20 != 0 || ty.get() % 20 != 0));
+            for ((tx, ty, x), (unhinted_tx, unhinted_ty, unhinted_x)) in
+                hinted.into_iter().zip(unhinted)
+            {
+                assert_eq!(tx.get() % 20, 0);
+                assert_eq!(ty.get() % 20, 0);
+                assert!((tx - unhinted_tx).get().abs() <= 10);
+                assert!((ty - unhinted_ty).get().abs() <= 10);
+                // Layout positions are unaffected by hinting.
+                assert_eq!(x, unhinted_x);
+            }
+        });
+    }
+
+    #[test]
+    fn measure_letter_spacing() {
+        with_device_font(|_mc, df| {
+            let text = WStr::from_units(b"abcd");
+            let normal = EvalParameters::from_parts(Twips::from_pixels(12.0), Twips::ZERO, true);
+            let spaced =
+                EvalParameters::from_parts(Twips::from_pixels(12.0), Twips::from_pixels(2.0), true);
+
+            let (normal_width, _) = df.measure(text, normal, false);
+            let (spaced_width, _) = df.measure(text, spaced, false);
+            assert_eq!(spaced_width - normal_width, Twips::from_pixels(8.0));
+        });
+    }
+
     #[test]
     fn wrap_line_no_breakpoint() {
         with_device_font(|_mc, df| {
@@ -75,6 +75,7 @@ pub enum FramePhase {
 #[instrument(level = "debug", skip_all)]
 pub fn run_all_phases_avm2(context: &mut UpdateContext<'_, '_>) {
     let stage = context.stage;
+    context.avm2.allow_resuming_frame_scripts();
 
     *context.frame_phase = FramePhase::Enter;
     stage.enter_frame(context);
@@ -429,13 +429,18 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
     ) -> Option<Font<'gc>> {
         let library = context.library.library_for_movie_mut(self.movie.clone());
 
-        // If this text field is set to use device fonts, fallback to using our embedded Noto Sans.
+        // If this text field is set to use device fonts, fallback to the closest registered device font,
+        // or our embedded Noto Sans if none match.
         // Note that the SWF can still contain a DefineFont tag with no glyphs/layout info in this case (see #451).
-        // In an ideal world, device fonts would search for a matching font on the system and render it in some way.
+        let font_name = span.font.to_utf8_lossy();
         if let Some(font) = library
-            .get_font_by_name(&span.font.to_utf8_lossy(), span.bold, span.italic)
+            .get_font_by_name(&font_name, span.bold, span.italic)
             .filter(|f| !is_device_font && f.has_glyphs())
-            .or_else(|| context.library.device_font())
+            .or_else(|| {
+                context
+                    .library
+                    .get_device_font(&font_name, span.bold, span.italic)
+            })
         {
             self.font = Some(font);
             return self.font;
@@ -490,10 +495,15 @@ impl<'a, 'gc> LayoutContext<'a, 'gc> {
     fn append_bullet(&mut self, context: &mut UpdateContext<'_, 'gc>, span: &TextSpan) {
         let library = context.library.library_for_movie_mut(self.movie.clone());
 
+        let font_name = span.font.to_utf8_lossy();
         if let Some(bullet_font) = library
-            .get_font_by_name(&span.font.to_utf8_lossy(), span.bold, span.italic)
+            .get_font_by_name(&font_name, span.bold, span.italic)
             .filter(|f| f.has_glyphs())
-            .or_else(|| context.library.device_font())
+            .or_else(|| {
+                context
+                    .library
+                    .get_device_font(&font_name, span.bold, span.italic)
+            })
             .or(self.font)
         {
             let mut bullet_cursor = self.cursor;
@@ -15,6 +15,7 @@ extern crate num_derive;
 
 #[macro_use]
 mod avm1;
+mod amf;
 mod avm2;
 mod binary_data;
 pub mod bitmap;
@@ -26,15 +27,17 @@ mod ecma_conversions;
 pub(crate) mod either;
 pub mod events;
 pub mod focus_tracker;
-mod font;
+pub mod font;
 mod frame_lifecycle;
 mod html;
 mod library;
 pub mod limits;
 pub mod loader;
+pub mod local_connection;
 mod locale;
 mod player;
 mod prelude;
+mod streams;
 pub mod string;
 pub mod tag_utils;
 pub mod timer;
@@ -46,6 +49,7 @@ pub mod backend;
 pub mod config;
 pub mod external;
 
+pub use avm1::SandboxType;
 pub use context_menu::ContextMenuItem;
 pub use events::PlayerEvent;
 pub use indexmap;
@@ -13,7 +13,7 @@ use ruffle_render::backend::RenderBackend;
 use ruffle_render::bitmap::BitmapHandle;
 use ruffle_render::utils::remove_invalid_jpeg_data;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Weak};
 use swf::CharacterId;
 use weak_table::{traits::WeakElement, PtrWeakKeyHashMap, WeakValueHashMap};
@@ -104,6 +104,7 @@ pub struct MovieLibrary<'gc> {
     jpeg_tables: Option<Vec<u8>>,
     fonts: HashMap<FontDescriptor, Font<'gc>>,
     avm2_domain: Option<Avm2Domain<'gc>>,
+    init_actions: HashSet<CharacterId>,
 }
 
 impl<'gc> MovieLibrary<'gc> {
@@ -114,6 +115,7 @@ impl<'gc> MovieLibrary<'gc> {
             jpeg_tables: None,
             fonts: HashMap::new(),
             avm2_domain: None,
+            init_actions: HashSet::new(),
         }
     }
 
@@ -303,6 +305,14 @@ impl<'gc> MovieLibrary<'gc> {
         self.jpeg_tables.as_ref().map(|data| &data[..])
     }
 
+    /// Records that the `DoInitAction` block of the given sprite is about to run.
+    ///
+    /// Init actions only ever run once per sprite; this returns `false` if
+    /// they have already run.
+    pub fn mark_init_action_run(&mut self, id: CharacterId) -> bool {
+        self.init_actions.insert(id)
+    }
+
     pub fn set_avm2_domain(&mut self, avm2_domain: Avm2Domain<'gc>) {
         self.avm2_domain = Some(avm2_domain);
     }
@@ -357,6 +367,9 @@ pub struct Library<'gc> {
     /// The embedded device font.
     device_font: Option<Font<'gc>>,
 
+    /// Additional device fonts, looked up by family name and style.
+    device_fonts: HashMap<FontDescriptor, Font<'gc>>,
+
     /// A list of the symbols associated with specific AVM2 constructor
     /// prototypes.
     avm2_class_registry: Avm2ClassRegistry<'gc>,
@@ -369,6 +382,7 @@ unsafe impl<'gc> gc_arena::Collect for Library<'gc> {
             val.trace(cc);
         }
         self.device_font.trace(cc);
+        self.device_fonts.trace(cc);
         self.avm2_class_registry.trace(cc);
     }
 }
@@ -378,6 +392,7 @@ impl<'gc> Library<'gc> {
         Self {
             movie_libraries: PtrWeakKeyHashMap::new(),
             device_font: None,
+            device_fonts: HashMap::new(),
             avm2_class_registry: Default::default(),
         }
     }
@@ -402,6 +417,37 @@ impl<'gc> Library<'gc> {
         self.device_font = Some(font);
     }
 
+    /// Registers a device font that can be selected by its family name and style.
+    pub fn register_device_font(&mut self, font: Font<'gc>) {
+        self.device_fonts.insert(font.descriptor().clone(), font);
+    }
+
+    /// Returns the device font that most closely matches the given family name and style.
+    ///
+    /// Family names are compared case-insensitively. If a font with the requested name exists,
+    /// but not in the requested style, the closest style is used instead, preferring to keep
+    /// the boldness over the italic-ness. If no registered font has the requested name, this
+    /// falls back to the default device font.
+    pub fn get_device_font(&self, name: &str, is_bold: bool, is_italic: bool) -> Option<Font<'gc>> {
+        let requested = FontDescriptor::from_parts(name, is_bold, is_italic);
+        self.device_fonts
+            .iter()
+            .filter(|(descriptor, _)| descriptor.class().eq_ignore_ascii_case(requested.class()))
+            .max_by_key(|(descriptor, _)| {
+                // Between families that only differ in case, prefer the exact name,
+                // then the lexically first one, so that the pick doesn't depend on
+                // the iteration order of the map.
+                (
+                    descriptor.bold() == is_bold,
+                    descriptor.italic() == is_italic,
+                    descriptor.class() == requested.class(),
+                    std::cmp::Reverse(descriptor.class()),
+                )
+            })
+            .map(|(_, font)| *font)
+            .or(self.device_font)
+    }
+
     /// Get the AVM2 class registry.
     pub fn avm2_class_registry(&self) -> &Avm2ClassRegistry<'gc> {
         &self.avm2_class_registry
@@ -412,3 +458,109 @@ impl<'gc> Library<'gc> {
         &mut self.avm2_class_registry
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::player::{Player, PlayerBuilder};
+    use gc_arena::rootless_arena;
+    use ruffle_render::backend::{null::NullRenderer, ViewportDimensions};
+
+    /// Loads the built-in device font under a different name and style.
+    fn device_font_named<'gc>(
+        mc: MutationContext<'gc, '_>,
+        renderer: &mut dyn RenderBackend,
+        name: &str,
+        flags: swf::FontFlag,
+    ) -> Font<'gc> {
+        const DEVICE_FONT_TAG: &[u8] = include_bytes!("../assets/noto-sans-definefont3.bin");
+        let mut reader = swf::read::Reader::new(DEVICE_FONT_TAG, 8);
+        let mut tag = reader
+            .read_define_font_2(3)
+            .expect("Built-in font should compile");
+        tag.name = swf::SwfStr::from_utf8_str(name);
+        tag.flags = (tag.flags - swf::FontFlag::IS_BOLD - swf::FontFlag::IS_ITALIC) | flags;
+        Font::from_swf_tag(mc, renderer, tag, reader.encoding())
+    }
+
+    #[test]
+    fn device_font_by_name() {
+        rootless_arena(|mc| {
+            let mut renderer = NullRenderer::new(ViewportDimensions {
+                width: 0,
+                height: 0,
+                scale_factor: 1.0,
+            });
+            let default_font = Player::load_device_font(mc, &mut renderer);
+            let arial = device_font_named(mc, &mut renderer, "Arial", swf::FontFlag::empty());
+            let arial_bold = device_font_named(mc, &mut renderer, "Arial", swf::FontFlag::IS_BOLD);
+            let times = device_font_named(mc, &mut renderer, "Times", swf::FontFlag::empty());
+            let arial_upper = device_font_named(mc, &mut renderer, "ARIAL", swf::FontFlag::empty());
+
+            let mut library = Library::empty();
+            library.set_device_font(default_font);
+            library.register_device_font(arial);
+            library.register_device_font(arial_bold);
+            library.register_device_font(times);
+            library.register_device_font(arial_upper);
+
+            let get = |name, bold, italic| {
+                library
+                    .get_device_font(name, bold, italic)
+                    .map(|font| font.descriptor().clone())
+            };
+
+            assert_eq!(get("Arial", false, false), Some(arial.descriptor().clone()));
+            assert_eq!(
+                get("arial", true, false),
+                Some(arial_bold.descriptor().clone())
+            );
+            assert_eq!(get("Times", false, false), Some(times.descriptor().clone()));
+
+            // Missing styles fall back to the closest style of the same family.
+            assert_eq!(
+                get("Arial", true, true),
+                Some(arial_bold.descriptor().clone())
+            );
+            assert_eq!(get("Times", true, false), Some(times.descriptor().clone()));
+
+            // Case variants of a family resolve to the exact name, then lexically.
+            assert_eq!(
+                get("ARIAL", false, false),
+                Some(arial_upper.descriptor().clone())
+            );
+            assert_eq!(get("Arial", false, false), Some(arial.descriptor().clone()));
+            assert_eq!(
+                get("arial", false, false),
+                Some(arial_upper.descriptor().clone())
+            );
+
+            // Unknown families fall back to the default device font.
+            assert_eq!(
+                get("Courier", false, false),
+                Some(default_font.descriptor().clone())
+            );
+        })
+    }
+
+    #[test]
+    fn device_font_from_builder() {
+        const DEVICE_FONT_TAG: &[u8] = include_bytes!("../assets/noto-sans-definefont3.bin");
+        let player = PlayerBuilder::new()
+            .with_device_font(DEVICE_FONT_TAG.to_vec())
+            .build();
+        player
+            .lock()
+            .unwrap()
+            .mutate_with_update_context(|context| {
+                // Without a movie, the built-in default font hasn't been loaded, so
+                // this can only find the font given to the builder.
+                assert!(context.library.device_font().is_none());
+                let font = context
+                    .library
+                    .get_device_font("noto sans", false, false)
+                    .expect("Device font should be registered");
+                assert_eq!(font.descriptor().class(), "Noto Sans");
+            });
+    }
+}
@@ -22,6 +22,7 @@ use crate::events::ClipEvent;
 use crate::frame_lifecycle::catchup_display_object_to_frame;
 use crate::limits::ExecutionLimit;
 use crate::player::Player;
+use crate::streams::NetStream;
 use crate::string::AvmString;
 use crate::tag_utils::SwfMovie;
 use crate::vminterface::Instantiator;
@@ -164,6 +165,9 @@ pub enum Error {
     #[error("Non-sound loader spawned as sound loader")]
     NotSoundLoader,
 
+    #[error("Non-NetStream loader spawned as NetStream loader")]
+    NotNetStreamLoader,
+
     #[error("Could not fetch: {0}")]
     FetchError(String),
 
@@ -227,7 +231,8 @@ impl<'gc> LoadManager<'gc> {
             | Loader::LoadVars { self_handle, .. }
             | Loader::LoadURLLoader { self_handle, .. }
             | Loader::SoundAvm1 { self_handle, .. }
-            | Loader::SoundAvm2 { self_handle, .. } => *self_handle = Some(handle),
+            | Loader::SoundAvm2 { self_handle, .. }
+            | Loader::NetStream { self_handle, .. } => *self_handle = Some(handle),
         }
         handle
     }
@@ -420,6 +425,24 @@ impl<'gc> LoadManager<'gc> {
         loader.sound_loader_avm2(player, request)
     }
 
+    /// Kick off an FLV load into a `NetStream`.
+    ///
+    /// Returns the loader's async process, which you will need to spawn.
+    pub fn load_netstream(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        target_stream: NetStream<'gc>,
+        request: Request,
+    ) -> OwnedFuture<(), Error> {
+        let loader = Loader::NetStream {
+            self_handle: None,
+            target_stream,
+        };
+        let handle = self.add_loader(loader);
+        let loader = self.get_loader_mut(handle).unwrap();
+        loader.netstream_loader(player, request)
+    }
+
     /// Process tags on all loaders in the Parsing phase.
     ///
     /// Returns true if *all* loaders finished preloading.
@@ -567,6 +590,16 @@ pub enum Loader<'gc> {
         /// The target AVM1 object to load the audio into.
         target_object: Avm2Object<'gc>,
     },
+
+    /// Loader that is loading an FLV into a `NetStream`.
+    NetStream {
+        /// The handle to refer to this loader instance.
+        #[collect(require_static)]
+        self_handle: Option<Handle>,
+
+        /// The stream to play the FLV in.
+        target_stream: NetStream<'gc>,
+    },
 }
 
 impl<'gc> Loader<'gc> {
@@ -690,7 +723,7 @@ impl<'gc> Loader<'gc> {
             .expect("Could not upgrade weak reference to player");
 
         Box::pin(async move {
-            let fetch = player.lock().unwrap().navigator().fetch(request);
+            let fetch = player.lock().unwrap().fetch(request);
 
             let response = fetch.await.map_err(|error| {
                 player
@@ -740,7 +773,7 @@ impl<'gc> Loader<'gc> {
             .expect("Could not upgrade weak reference to player");
 
         Box::pin(async move {
-            let fetch = player.lock().unwrap().navigator().fetch(request);
+            let fetch = player.lock().unwrap().fetch(request);
 
             let mut replacing_root_movie = false;
             player.lock().unwrap().update(|uc| -> Result<(), Error> {
@@ -853,7 +886,7 @@ impl<'gc> Loader<'gc> {
             .expect("Could not upgrade weak reference to player");
 
         Box::pin(async move {
-            let fetch = player.lock().unwrap().navigator().fetch(request);
+            let fetch = player.lock().unwrap().fetch(request);
 
             let response = fetch.await?;
 
@@ -916,7 +949,7 @@ impl<'gc> Loader<'gc> {
             .expect("Could not upgrade weak reference to player");
 
         Box::pin(async move {
-            let fetch = player.lock().unwrap().navigator().fetch(request);
+            let fetch = player.lock().unwrap().fetch(request);
 
             let data = fetch.await;
 
@@ -1012,7 +1045,7 @@ impl<'gc> Loader<'gc> {
             .expect("Could not upgrade weak reference to player");
 
         Box::pin(async move {
-            let fetch = player.lock().unwrap().navigator().fetch(request);
+            let fetch = player.lock().unwrap().fetch(request);
             let response = fetch.await;
 
             player.lock().unwrap().update(|uc| {
@@ -1147,7 +1180,7 @@ impl<'gc> Loader<'gc> {
             .expect("Could not upgrade weak reference to player");
 
         Box::pin(async move {
-            let fetch = player.lock().unwrap().navigator().fetch(request);
+            let fetch = player.lock().unwrap().fetch(request);
             let data = fetch.await;
 
             // Fire the load handler.
@@ -1191,6 +1224,48 @@ impl<'gc> Loader<'gc> {
         })
     }
 
+    /// Creates a future for a NetStream load call.
+    fn netstream_loader(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        request: Request,
+    ) -> OwnedFuture<(), Error> {
+        let handle = match self {
+            Loader::NetStream { self_handle, .. } => {
+                self_handle.expect("Loader not self-introduced")
+            }
+            _ => return Box::pin(async { Err(Error::NotNetStreamLoader) }),
+        };
+
+        let player = player
+            .upgrade()
+            .expect("Could not upgrade weak reference to player");
+
+        Box::pin(async move {
+            let fetch = player.lock().unwrap().fetch(request);
+            let data = fetch.await;
+
+            player.lock().unwrap().update(|uc| {
+                let loader = uc.load_manager.get_loader(handle);
+                let stream = match loader {
+                    Some(&Loader::NetStream { target_stream, .. }) => target_stream,
+                    None => return Err(Error::Cancelled),
+                    _ => return Err(Error::NotNetStreamLoader),
+                };
+
+                match data {
+                    Ok(response) => stream.load_buffer(uc, response.body),
+                    Err(e) => {
+                        tracing::error!("Error during NetStream load: {}", e);
+                        stream.report_error(uc);
+                    }
+                }
+
+                Ok(())
+            })
+        })
+    }
+
     /// Creates a future for a LoadURLLoader load call.
     fn sound_loader_avm2(
         &mut self,
@@ -1209,7 +1284,7 @@ impl<'gc> Loader<'gc> {
             .expect("Could not upgrade weak reference to player");
 
         Box::pin(async move {
-            let fetch = player.lock().unwrap().navigator().fetch(request);
+            let fetch = player.lock().unwrap().fetch(request);
             let response = fetch.await;
 
             player.lock().unwrap().update(|uc| {
@@ -0,0 +1,248 @@
+//! `LocalConnection` handling, shared between AVM1 and AVM2.
+//!
+//! Messages are routed between movies by the `LocalConnectionBackend`. Each player
+//! keeps track of the connections its own movies are listening on, and delivers
+//! their messages (and the status of the messages it sent) once per frame.
+
+use crate::avm1::{
+    Activation as Avm1Activation, ActivationIdentifier, ExecutionReason, Object as Avm1Object,
+    ScriptObject as Avm1ScriptObject, TObject as _,
+};
+use crate::avm2::object::TObject as _;
+use crate::avm2::{
+    Activation as Avm2Activation, Avm2, Error as Avm2Error, Multiname as Avm2Multiname,
+    Namespace as Avm2Namespace, Object as Avm2Object, Value as Avm2Value,
+};
+use crate::backend::local_connection::LocalConnectionMessage;
+use crate::context::UpdateContext;
+use crate::string::AvmString;
+use crate::vminterface::AvmObject;
+use gc_arena::Collect;
+
+/// A `LocalConnection` object that is listening for messages.
+#[derive(Collect)]
+#[collect(no_drop)]
+struct LocalConnection<'gc> {
+    /// The name the connection is listening on.
+    #[collect(require_static)]
+    name: String,
+
+    /// The script object that `connect` was called on.
+    object: AvmObject<'gc>,
+}
+
+/// Manages the `LocalConnection` objects of a player.
+#[derive(Collect, Default)]
+#[collect(no_drop)]
+pub struct LocalConnections<'gc> {
+    /// Connections that are listening for messages.
+    connections: Vec<LocalConnection<'gc>>,
+
+    /// Connections that sent a message, and whether it was delivered to the backend.
+    /// Their `onStatus` handlers are called on the next frame.
+    pending_statuses: Vec<(AvmObject<'gc>, bool)>,
+}
+
+impl<'gc> LocalConnections<'gc> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts listening for messages sent to `name` on `object`.
+    ///
+    /// Returns `false` if the object is already connected, or if the name is already in use.
+    pub fn connect(
+        context: &mut UpdateContext<'_, 'gc>,
+        name: String,
+        object: AvmObject<'gc>,
+    ) -> bool {
+        if context
+            .local_connections
+            .connections
+            .iter()
+            .any(|connection| connection.object.ptr_eq(object))
+        {
+            return false;
+        }
+        if !context.local_connection.connect(&name) {
+            return false;
+        }
+        context
+            .local_connections
+            .connections
+            .push(LocalConnection { name, object });
+        true
+    }
+
+    /// Stops `object` listening for messages.
+    pub fn close(context: &mut UpdateContext<'_, 'gc>, object: AvmObject<'gc>) {
+        let connections = &mut context.local_connections.connections;
+        if let Some(index) = connections
+            .iter()
+            .position(|connection| connection.object.ptr_eq(object))
+        {
+            let connection = connections.remove(index);
+            context.local_connection.close(&connection.name);
+        }
+    }
+
+    /// Returns the names of all connections that are listening for messages.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.connections
+            .iter()
+            .map(|connection| connection.name.as_str())
+    }
+
+    /// Sends a message from `sender` to the connection listening on `name`.
+    pub fn send(
+        context: &mut UpdateContext<'_, 'gc>,
+        sender: AvmObject<'gc>,
+        name: &str,
+        message: LocalConnectionMessage,
+    ) {
+        let delivered = context.local_connection.send(name, message);
+        context
+            .local_connections
+            .pending_statuses
+            .push((sender, delivered));
+    }
+
+    /// Delivers received messages to their connections, and then reports the status
+    /// of sent messages to their senders.
+    pub fn update_connections(context: &mut UpdateContext<'_, 'gc>) {
+        let mut deliveries = Vec::new();
+        for connection in &context.local_connections.connections {
+            for message in context.local_connection.receive(&connection.name) {
+                deliveries.push((connection.object, message));
+            }
+        }
+        for (object, message) in deliveries {
+            match object {
+                AvmObject::Avm1(object) => Self::deliver_avm1(context, object, message),
+                AvmObject::Avm2(object) => Self::deliver_avm2(context, object, message),
+            }
+        }
+
+        let statuses = std::mem::take(&mut context.local_connections.pending_statuses);
+        for (sender, delivered) in statuses {
+            let level = if delivered { "status" } else { "error" };
+            match sender {
+                AvmObject::Avm1(sender) => Self::report_avm1_status(context, sender, level),
+                AvmObject::Avm2(sender) => Self::report_avm2_status(context, sender, level),
+            }
+        }
+    }
+
+    fn deliver_avm1(
+        context: &mut UpdateContext<'_, 'gc>,
+        object: Avm1Object<'gc>,
+        message: LocalConnectionMessage,
+    ) {
+        let root = context.stage.root_clip();
+        let mut activation = Avm1Activation::from_nothing(
+            context.reborrow(),
+            ActivationIdentifier::root("[LocalConnection]"),
+            root,
+        );
+        let args =
+            crate::avm1::decode_local_connection_arguments(&mut activation, &message.arguments);
+        let method_name = AvmString::new_utf8(activation.context.gc_context, message.method_name);
+        if let Err(e) = object.call_method(
+            method_name,
+            &args,
+            &mut activation,
+            ExecutionReason::Special,
+        ) {
+            tracing::error!("Unhandled AVM1 error in LocalConnection method: {}", e);
+        }
+    }
+
+    fn deliver_avm2(
+        context: &mut UpdateContext<'_, 'gc>,
+        object: Avm2Object<'gc>,
+        message: LocalConnectionMessage,
+    ) {
+        let mut activation = Avm2Activation::from_nothing(context.reborrow());
+        if let Err(e) = Self::call_avm2_client(&mut activation, object, message) {
+            tracing::error!("Unhandled AVM2 error in LocalConnection method: {}", e);
+        }
+    }
+
+    /// Calls the method named by `message` on the `client` of an AVM2 `LocalConnection`.
+    fn call_avm2_client(
+        activation: &mut Avm2Activation<'_, 'gc>,
+        object: Avm2Object<'gc>,
+        message: LocalConnectionMessage,
+    ) -> Result<(), Avm2Error<'gc>> {
+        let args = crate::avm2::globals::flash::net::local_connection::decode_arguments(
+            activation,
+            &message.arguments,
+        )?;
+        let client = object
+            .get_property(&Avm2Multiname::public("client"), activation)?
+            .coerce_to_object(activation)?;
+        let method_name = AvmString::new_utf8(activation.context.gc_context, message.method_name);
+        client.call_property(&Avm2Multiname::public(method_name), &args, activation)?;
+        Ok(())
+    }
+
+    fn report_avm1_status(
+        context: &mut UpdateContext<'_, 'gc>,
+        sender: Avm1Object<'gc>,
+        level: &'static str,
+    ) {
+        let root = context.stage.root_clip();
+        let mut activation = Avm1Activation::from_nothing(
+            context.reborrow(),
+            ActivationIdentifier::root("[LocalConnection onStatus]"),
+            root,
+        );
+        let info = Avm1ScriptObject::new(
+            activation.context.gc_context,
+            Some(activation.context.avm1.prototypes().object),
+        );
+        let result = info
+            .set("level", level.into(), &mut activation)
+            .and_then(|_| {
+                sender.call_method(
+                    "onStatus".into(),
+                    &[info.into()],
+                    &mut activation,
+                    ExecutionReason::Special,
+                )
+            });
+        if let Err(e) = result {
+            tracing::error!("Unhandled AVM1 error in LocalConnection.onStatus: {}", e);
+        }
+    }
+
+    fn report_avm2_status(
+        context: &mut UpdateContext<'_, 'gc>,
+        sender: Avm2Object<'gc>,
+        level: &'static str,
+    ) {
+        let mut activation = Avm2Activation::from_nothing(context.reborrow());
+        let status_event =
+            Avm2Multiname::new(Avm2Namespace::package("flash.events"), "StatusEvent");
+        let event = activation.resolve_class(&status_event).and_then(|class| {
+            class.construct(
+                &mut activation,
+                &[
+                    "status".into(),
+                    false.into(),
+                    false.into(),
+                    Avm2Value::Null,
+                    level.into(),
+                ],
+            )
+        });
+        let result =
+            event.and_then(|event| Avm2::dispatch_event(&mut activation.context, event, sender));
+        if let Err(e) = result {
+            tracing::error!(
+                "Unhandled AVM2 error in LocalConnection status event: {}",
+                e
+            );
+        }
+    }
+}
@@ -1,9 +1,9 @@
 use crate::avm1::Attribute;
 use crate::avm1::Avm1;
 use crate::avm1::Object;
-use crate::avm1::SystemProperties;
 use crate::avm1::VariableDumper;
 use crate::avm1::{Activation, ActivationIdentifier};
+use crate::avm1::{SandboxType, SystemProperties};
 use crate::avm1::{ScriptObject, TObject, Value};
 use crate::avm2::{
     object::LoaderInfoObject, object::TObject as _, Activation as Avm2Activation, Avm2, CallStack,
@@ -11,8 +11,10 @@ use crate::avm2::{
 };
 use crate::backend::{
     audio::{AudioBackend, AudioManager},
+    local_connection::LocalConnectionBackend,
+    locale::LocaleBackend,
     log::LogBackend,
-    navigator::{NavigatorBackend, Request},
+    navigator::{NavigatorBackend, OwnedFuture, Request, Response},
     storage::StorageBackend,
     ui::{InputManager, MouseCursor, UiBackend},
 };
@@ -33,7 +35,8 @@ use crate::font::Font;
 use crate::frame_lifecycle::{run_all_phases_avm2, FramePhase};
 use crate::library::Library;
 use crate::limits::ExecutionLimit;
-use crate::loader::{LoadBehavior, LoadManager};
+use crate::loader::{Error as LoaderError, LoadBehavior, LoadManager};
+use crate::local_connection::LocalConnections;
 use crate::locale::get_current_date_time;
 use crate::prelude::*;
 use crate::string::AvmString;
@@ -135,6 +138,9 @@ struct GcRootData<'gc> {
     /// Timed callbacks created with `setInterval`/`setTimeout`.
     timers: Timers<'gc>,
 
+    /// `LocalConnection` objects that are listening for or sending messages.
+    local_connections: LocalConnections<'gc>,
+
     current_context_menu: Option<ContextMenuState<'gc>>,
 
     /// External interface for (for example) JavaScript <-> ActionScript interaction
@@ -165,6 +171,7 @@ impl<'gc> GcRootData<'gc> {
         &mut HashMap<String, Avm2Object<'gc>>,
         &mut Vec<EditText<'gc>>,
         &mut Timers<'gc>,
+        &mut LocalConnections<'gc>,
         &mut Option<ContextMenuState<'gc>>,
         &mut ExternalInterface<'gc>,
         &mut AudioManager<'gc>,
@@ -181,6 +188,7 @@ impl<'gc> GcRootData<'gc> {
             &mut self.avm2_shared_objects,
             &mut self.unbound_text_fields,
             &mut self.timers,
+            &mut self.local_connections,
             &mut self.current_context_menu,
             &mut self.external_interface,
             &mut self.audio_manager,
@@ -194,6 +202,8 @@ type Audio = Box<dyn AudioBackend>;
 type Navigator = Box<dyn NavigatorBackend>;
 type Renderer = Box<dyn RenderBackend>;
 type Storage = Box<dyn StorageBackend>;
+type Locale = Box<dyn LocaleBackend>;
+type LocalConnection = Box<dyn LocalConnectionBackend>;
 type Log = Box<dyn LogBackend>;
 type Ui = Box<dyn UiBackend>;
 type Video = Box<dyn VideoBackend>;
@@ -223,6 +233,8 @@ pub struct Player {
     navigator: Navigator,
     storage: Storage,
     log: Log,
+    locale: Locale,
+    local_connection: LocalConnection,
     ui: Ui,
     video: Video,
 
@@ -247,6 +259,9 @@ pub struct Player {
     /// Faked time passage for fooling hand-written busy-loop FPS limiters.
     time_offset: u32,
 
+    /// The number of frames that have been run. Used to blink the text caret.
+    frame_count: u32,
+
     input: InputManager,
 
     mouse_pos: (Twips, Twips),
@@ -266,6 +281,15 @@ pub struct Player {
     /// The instant at which the SWF was launched.
     start_time: Instant,
 
+    /// The simulated time passed since the SWF was launched, in milliseconds.
+    /// Only used when the player runs on a simulated clock.
+    simulated_time: Option<f64>,
+
+    /// The time passed since the SWF was launched at the moment the player was paused,
+    /// in milliseconds. `getTimer` reports this frozen time while paused.
+    /// See `Player::set_paused`.
+    paused_time: Option<f64>,
+
     /// The maximum amount of time that can be called before a `Error::ExecutionTimeout`
     /// is raised. This defaults to 15 seconds but can be changed.
     max_execution_duration: Duration,
@@ -454,6 +478,11 @@ impl Player {
         }
     }
 
+    /// Advances the player by `dt` milliseconds, running as many frames as are due
+    /// at the movie's frame rate and firing any timers that elapsed.
+    ///
+    /// When the player runs on a simulated clock, this also advances the time reported
+    /// by `getTimer`.
     pub fn tick(&mut self, dt: f64) {
         // Don't run until preloading is complete.
         // TODO: Eventually we want to stream content similar to the Flash player.
@@ -462,6 +491,10 @@ impl Player {
         }
 
         if self.is_playing() {
+            if let Some(simulated_time) = &mut self.simulated_time {
+                *simulated_time += dt;
+            }
+
             self.frame_accumulator += dt;
             let frame_rate = self.frame_rate;
             let frame_time = 1000.0 / frame_rate;
@@ -584,16 +617,16 @@ impl Player {
                 };
                 crate::avm1::make_context_menu_state(menu_object, &mut activation)
             } else if let Avm2Value::Object(_obj) = root_dobj.object2() {
-                // TODO: send "menuSelect" event
-                tracing::warn!("AVM2 Context menu callbacks are not implemented");
-
-                let mut activation = Avm2Activation::from_nothing(context.reborrow());
-
                 let menu_object = root_dobj
                     .as_interactive()
                     .map(|iobj| iobj.context_menu())
                     .and_then(|v| v.as_object());
 
+                if let Some(menu_object) = menu_object {
+                    Self::dispatch_avm2_context_menu_event(context, "menuSelect", menu_object);
+                }
+
+                let mut activation = Avm2Activation::from_nothing(context.reborrow());
                 crate::avm2::make_context_menu_state(menu_object, &mut activation)
             } else {
                 // no AVM1 or AVM2 object - so just prepare the builtin items
@@ -628,8 +661,9 @@ impl Player {
                     ContextMenuCallback::Forward => Self::forward_root_movie(context),
                     ContextMenuCallback::Back => Self::back_root_movie(context),
                     ContextMenuCallback::Rewind => Self::rewind_root_movie(context),
-                    ContextMenuCallback::Avm2 { .. } => {
-                        // TODO: Send menuItemSelect event
+                    ContextMenuCallback::Avm2 { item } => {
+                        let item = *item;
+                        Self::dispatch_avm2_context_menu_event(context, "menuItemSelect", item)
                     }
                     _ => {}
                 }
@@ -662,6 +696,45 @@ impl Player {
         );
     }
 
+    /// Dispatches a `ContextMenuEvent` to an AVM2 `ContextMenu` or `ContextMenuItem`.
+    fn dispatch_avm2_context_menu_event<'gc>(
+        context: &mut UpdateContext<'_, 'gc>,
+        event_type: &'static str,
+        target: Avm2Object<'gc>,
+    ) {
+        // TODO: Remember to also change the mouse target and owner
+        // when we support contextmenu on non-root-movie
+        let root_clip = context.stage.root_clip().object2();
+        let mut activation = Avm2Activation::from_nothing(context.reborrow());
+        let event = activation.avm2().classes().contextmenuevent.construct(
+            &mut activation,
+            &[
+                event_type.into(),
+                false.into(),
+                false.into(),
+                root_clip,
+                root_clip,
+            ],
+        );
+
+        match event {
+            Err(e) => tracing::error!(
+                "Encountered AVM2 error when constructing `{}` event: {}",
+                event_type,
+                e
+            ),
+            Ok(event) => {
+                if let Err(e) = Avm2::dispatch_event(&mut activation.context, event, target) {
+                    tracing::error!(
+                        "Encountered AVM2 error when dispatching `{}` event: {}",
+                        event_type,
+                        e
+                    );
+                }
+            }
+        }
+    }
+
     pub fn set_fullscreen(&mut self, is_fullscreen: bool) {
         self.mutate_with_update_context(|context| {
             let display_state = if is_fullscreen {
@@ -698,8 +771,12 @@ impl Player {
         }
     }
 
+    /// Starts or stops playback.
+    ///
+    /// Starting playback also resumes a player paused through `set_paused`.
     pub fn set_is_playing(&mut self, v: bool) {
         if v {
+            self.unfreeze_clock();
             // Allow auto-play after user gesture for web backends.
             self.audio.play();
         } else {
@@ -708,6 +785,70 @@ impl Player {
         self.is_playing = v;
     }
 
+    /// Returns whether the player is paused through `set_paused`.
+    pub fn is_paused(&self) -> bool {
+        self.paused_time.is_some()
+    }
+
+    /// Pauses or resumes the player.
+    ///
+    /// Unlike `set_is_playing`, pausing also freezes the clock seen by the movie, so that
+    /// `getTimer` and timers don't advance until the player is resumed or stepped with
+    /// `step_one_frame`.
+    pub fn set_paused(&mut self, paused: bool) {
+        if paused && !self.is_paused() {
+            self.paused_time = Some(self.elapsed_time());
+        }
+        self.set_is_playing(!paused);
+    }
+
+    /// Continues the clock from where `set_paused` froze it.
+    fn unfreeze_clock(&mut self) {
+        if let Some(paused_time) = self.paused_time.take() {
+            if self.simulated_time.is_none() {
+                if let Some(start_time) =
+                    Instant::now().checked_sub(Duration::from_secs_f64(paused_time / 1000.0))
+                {
+                    self.start_time = start_time;
+                }
+            }
+        }
+    }
+
+    /// Runs exactly one frame of a paused player, advancing its clock and timers by one
+    /// frame interval.
+    ///
+    /// Does nothing if the player isn't paused.
+    pub fn step_one_frame(&mut self) {
+        let frame_time = 1000.0 / self.frame_rate;
+        if let Some(paused_time) = &mut self.paused_time {
+            *paused_time += frame_time;
+        } else {
+            return;
+        }
+        self.advance_simulated_clock(frame_time);
+
+        self.run_frame();
+        self.update_timers(frame_time);
+    }
+
+    /// Advances the simulated clock by `dt` milliseconds, without running any frames.
+    ///
+    /// Does nothing if the player doesn't run on a simulated clock.
+    pub fn advance_simulated_clock(&mut self, dt: f64) {
+        if let Some(simulated_time) = &mut self.simulated_time {
+            *simulated_time += dt;
+        }
+    }
+
+    /// Returns the time passed since the SWF was launched, in milliseconds.
+    fn elapsed_time(&self) -> f64 {
+        match self.simulated_time {
+            Some(time) => time,
+            None => Instant::now().duration_since(self.start_time).as_secs_f64() * 1000.0,
+        }
+    }
+
     pub fn needs_render(&self) -> bool {
         self.needs_render
     }
@@ -773,7 +914,7 @@ impl Player {
         self.mutate_with_update_context(|context| {
             let stage = context.stage;
             if let Ok(quality) = StageQuality::from_str(quality) {
-                stage.set_quality(context.gc_context, quality);
+                stage.set_quality(context.gc_context, context.renderer, quality);
             }
         })
     }
@@ -1017,6 +1158,17 @@ impl Player {
                 }
             }
 
+            // Input method compositions go straight to the focused text field.
+            if let Some(text_field) = context.focus_tracker.get().and_then(|o| o.as_edit_text()) {
+                match &event {
+                    PlayerEvent::ImePreedit { text, cursor } => {
+                        text_field.ime_preedit(text, *cursor, context)
+                    }
+                    PlayerEvent::ImeCommit { text } => text_field.ime_commit(text, context),
+                    _ => {}
+                }
+            }
+
             // Propagate clip events.
             let (clip_event, listener) = match event {
                 PlayerEvent::KeyDown { .. } => {
@@ -1044,7 +1196,7 @@ impl Player {
                     Some(("Mouse", "onMouseDown", vec![])),
                 ),
                 PlayerEvent::MouseWheel { delta } => {
-                    let delta = Value::from(delta.lines());
+                    let delta = Value::from(delta.whole_lines());
                     (None, Some(("Mouse", "onMouseWheel", vec![delta])))
                 }
                 _ => (None, None),
@@ -1460,14 +1612,28 @@ impl Player {
             } else {
                 Avm1::run_frame(context);
             }
+            LocalConnections::update_connections(context);
             context.update_sounds();
         });
 
+        self.frame_count = self.frame_count.wrapping_add(1);
         self.needs_render = true;
     }
 
+    /// Whether the text caret is visible in the current frame.
+    /// The caret blinks on and off every half second, or stays visible if the movie
+    /// has no usable frame rate to time the blinking with.
+    fn caret_visible(&self) -> bool {
+        if self.frame_rate <= 0.0 || !self.frame_rate.is_finite() {
+            return true;
+        }
+        let half_seconds = (f64::from(self.frame_count) * 2.0 / self.frame_rate) as u64;
+        half_seconds % 2 == 0
+    }
+
     #[instrument(level = "debug", skip_all)]
     pub fn render(&mut self) {
+        let caret_visible = self.caret_visible();
         let (renderer, ui, transform_stack) =
             (&mut self.renderer, &mut self.ui, &mut self.transform_stack);
         let mut background_color = Color::WHITE;
@@ -1487,6 +1653,7 @@ impl Player {
                 stage,
                 clip_depth_stack: vec![],
                 allow_mask: true,
+                caret_visible,
             };
 
             stage.render(&mut render_context);
@@ -1524,6 +1691,20 @@ impl Player {
         &self.navigator
     }
 
+    /// Fetches data through the navigator backend, if the security sandbox allows it.
+    pub fn fetch(&self, request: Request) -> OwnedFuture<Response, LoaderError> {
+        let movie_url = self.spoofed_url.as_deref().or_else(|| self.swf.url());
+        if !self.system.can_fetch(movie_url, request.url()) {
+            let message = format!(
+                "{} is not allowed in the {} sandbox",
+                request.url(),
+                self.system.sandbox_type
+            );
+            return Box::pin(async move { Err(LoaderError::FetchError(message)) });
+        }
+        self.navigator.fetch(request)
+    }
+
     // The frame rate of the current movie in FPS.
     pub fn frame_rate(&self) -> f64 {
         self.frame_rate
@@ -1545,6 +1726,10 @@ impl Player {
         &mut self.storage
     }
 
+    pub fn locale_mut(&mut self) -> &mut Locale {
+        &mut self.locale
+    }
+
     pub fn destroy(self) -> Renderer {
         self.renderer
     }
@@ -1691,6 +1876,7 @@ impl Player {
                 avm2_shared_objects,
                 unbound_text_fields,
                 timers,
+                local_connections,
                 current_context_menu,
                 external_interface,
                 audio_manager,
@@ -1719,17 +1905,21 @@ impl Player {
                 instance_counter: &mut self.instance_counter,
                 storage: self.storage.deref_mut(),
                 log: self.log.deref_mut(),
+                locale: self.locale.deref_mut(),
+                local_connection: self.local_connection.deref_mut(),
                 video: self.video.deref_mut(),
                 avm1_shared_objects,
                 avm2_shared_objects,
                 unbound_text_fields,
                 timers,
+                local_connections,
                 current_context_menu,
                 needs_render: &mut self.needs_render,
                 avm1,
                 avm2,
                 external_interface,
                 start_time: self.start_time,
+                simulated_time: self.paused_time.or(self.simulated_time),
                 update_start: Instant::now(),
                 max_execution_duration: self.max_execution_duration,
                 focus_tracker,
@@ -1785,6 +1975,20 @@ impl Player {
         )
     }
 
+    /// Registers a device font that text can select by its family name and style.
+    ///
+    /// `data` is the body of a `DefineFont3` tag.
+    pub fn register_device_font(&mut self, data: &[u8]) -> Result<(), swf::error::Error> {
+        let mut reader = swf::read::Reader::new(data, 8);
+        let font = reader.read_define_font_2(3)?;
+        let encoding = reader.encoding();
+        self.mutate_with_update_context(|context| {
+            let font = Font::from_swf_tag(context.gc_context, context.renderer, font, encoding);
+            context.library.register_device_font(font);
+        });
+        Ok(())
+    }
+
     /// Update the current state of the player.
     ///
     /// The given function will be called with the current stage root, current
@@ -1890,6 +2094,26 @@ impl Player {
         self.max_execution_duration = max_execution_duration
     }
 
+    /// Sets the number of instructions an AVM2 frame script may execute
+    /// per frame before it is suspended until the next frame.
+    ///
+    /// `None`, the default, lets frame scripts run to completion.
+    ///
+    /// This is experimental: suspending scripts changes the order in which
+    /// they run, which content may rely on.
+    #[cfg(feature = "avm2_instruction_budget")]
+    pub fn set_avm2_instruction_budget(&mut self, budget: Option<u32>) {
+        self.mutate_with_update_context(|context| context.avm2.set_instruction_budget(budget));
+    }
+
+    /// Reseeds the random number generator used by `Math.random` and `random`
+    /// in both AVM1 and AVM2, making their output reproducible.
+    ///
+    /// By default, the generator is seeded from the current time.
+    pub fn set_rng_seed(&mut self, seed: u64) {
+        self.rng = SmallRng::seed_from_u64(seed);
+    }
+
     pub fn callstack(&self) -> StaticCallstack {
         StaticCallstack {
             arena: Rc::downgrade(&self.gc_arena),
@@ -1897,12 +2121,33 @@ impl Player {
     }
 }
 
+impl Drop for Player {
+    fn drop(&mut self) {
+        // Release the names our connections are listening on, so that other players
+        // sharing the backend can connect to them.
+        let names: Vec<String> = self.gc_arena.borrow().mutate(|_gc_context, gc_root| {
+            gc_root
+                .data
+                .read()
+                .local_connections
+                .names()
+                .map(str::to_string)
+                .collect()
+        });
+        for name in names {
+            self.local_connection.close(&name);
+        }
+    }
+}
+
 /// Player factory, which can be used to configure the aspects of a Ruffle player.
 pub struct PlayerBuilder {
     movie: Option<SwfMovie>,
 
     // Backends
     audio: Option<Audio>,
+    locale: Option<Locale>,
+    local_connection: Option<LocalConnection>,
     log: Option<Log>,
     navigator: Option<Navigator>,
     renderer: Option<Renderer>,
@@ -1922,6 +2167,9 @@ pub struct PlayerBuilder {
     load_behavior: LoadBehavior,
     spoofed_url: Option<String>,
     player_version: Option<u8>,
+    simulated_clock: bool,
+    sandbox_type: SandboxType,
+    device_fonts: Vec<Vec<u8>>,
 }
 
 impl PlayerBuilder {
@@ -1935,6 +2183,8 @@ impl PlayerBuilder {
             movie: None,
 
             audio: None,
+            locale: None,
+            local_connection: None,
             log: None,
             navigator: None,
             renderer: None,
@@ -1958,6 +2208,9 @@ impl PlayerBuilder {
             load_behavior: LoadBehavior::Streaming,
             spoofed_url: None,
             player_version: None,
+            simulated_clock: false,
+            sandbox_type: SandboxType::LocalTrusted,
+            device_fonts: Vec::new(),
         }
     }
 
@@ -1975,6 +2228,23 @@ impl PlayerBuilder {
         self
     }
 
+    /// Sets the locale backend of the player.
+    #[inline]
+    pub fn with_locale(mut self, locale: impl 'static + LocaleBackend) -> Self {
+        self.locale = Some(Box::new(locale));
+        self
+    }
+
+    /// Sets the local connection backend of the player.
+    #[inline]
+    pub fn with_local_connection(
+        mut self,
+        local_connection: impl 'static + LocalConnectionBackend,
+    ) -> Self {
+        self.local_connection = Some(Box::new(local_connection));
+        self
+    }
+
     /// Sets the logging backend of the player.
     #[inline]
     pub fn with_log(mut self, log: impl 'static + LogBackend) -> Self {
@@ -2083,6 +2353,29 @@ impl PlayerBuilder {
         self
     }
 
+    /// Sets whether the player runs on a simulated clock.
+    /// Time then only passes through `Player::tick` or `Player::advance_simulated_clock`,
+    /// which is useful for headless capture at a fixed timestep.
+    pub fn with_simulated_clock(mut self, simulated_clock: bool) -> Self {
+        self.simulated_clock = simulated_clock;
+        self
+    }
+
+    /// Sets the security sandbox the root movie runs in.
+    pub fn with_sandbox_type(mut self, sandbox_type: SandboxType) -> Self {
+        self.sandbox_type = sandbox_type;
+        self
+    }
+
+    /// Adds a device font that text can select by its family name and style.
+    ///
+    /// `data` is the body of a `DefineFont3` tag, the same format as the built-in
+    /// device font. Text asking for a family that wasn't added uses the built-in font.
+    pub fn with_device_font(mut self, data: Vec<u8>) -> Self {
+        self.device_fonts.push(data);
+        self
+    }
+
     /// Builds the player, wiring up the backends and configuring the specified settings.
     pub fn build(self) -> Arc<Mutex<Player>> {
         use crate::backend::*;
@@ -2093,6 +2386,12 @@ impl PlayerBuilder {
         let log = self
             .log
             .unwrap_or_else(|| Box::new(log::NullLogBackend::new()));
+        let locale = self
+            .locale
+            .unwrap_or_else(|| Box::new(locale::DefaultLocaleBackend::new()));
+        let local_connection = self
+            .local_connection
+            .unwrap_or_else(|| Box::new(local_connection::InProcessLocalConnectionBackend::new()));
         let navigator = self
             .navigator
             .unwrap_or_else(|| Box::new(navigator::NullNavigatorBackend::new()));
@@ -2122,6 +2421,8 @@ impl PlayerBuilder {
             Mutex::new(Player {
                 // Backends
                 audio,
+                locale,
+                local_connection,
                 log,
                 navigator,
                 renderer,
@@ -2139,7 +2440,10 @@ impl PlayerBuilder {
                 frame_accumulator: 0.0,
                 recent_run_frame_timings: VecDeque::with_capacity(10),
                 start_time: Instant::now(),
+                simulated_time: self.simulated_clock.then_some(0.0),
+                paused_time: None,
                 time_offset: 0,
+                frame_count: 0,
                 time_til_next_timer: None,
                 max_execution_duration: self.max_execution_duration,
                 actions_since_timeout_check: 0,
@@ -2152,7 +2456,10 @@ impl PlayerBuilder {
 
                 // Misc. state
                 rng: SmallRng::seed_from_u64(get_current_date_time().timestamp_millis() as u64),
-                system: SystemProperties::default(),
+                system: SystemProperties {
+                    sandbox_type: self.sandbox_type,
+                    ..Default::default()
+                },
                 transform_stack: TransformStack::new(),
                 instance_counter: 0,
                 player_version,
@@ -2181,6 +2488,7 @@ impl PlayerBuilder {
                                 focus_tracker: FocusTracker::new(gc_context),
                                 library: Library::empty(),
                                 load_manager: LoadManager::new(),
+                                local_connections: LocalConnections::new(),
                                 mouse_hovered_object: None,
                                 mouse_pressed_object: None,
                                 avm1_shared_objects: HashMap::new(),
@@ -2215,6 +2523,11 @@ impl PlayerBuilder {
             let call_stack = root.data.read().avm2.call_stack();
             root.callstack.write(context).avm2 = Some(call_stack);
         });
+        for data in &self.device_fonts {
+            if let Err(e) = player_lock.register_device_font(data) {
+                tracing::error!("Unable to load device font: {}", e);
+            }
+        }
         player_lock.audio.set_frame_rate(frame_rate);
         player_lock.set_letterbox(self.letterbox);
         player_lock.set_viewport_dimensions(ViewportDimensions {
@@ -0,0 +1,324 @@
+//! NetStream implementation
+
+use crate::avm1::{
+    Activation as Avm1Activation, ActivationIdentifier, ExecutionReason, Object as Avm1Object,
+    ScriptObject, TObject as _,
+};
+use crate::backend::navigator::Request;
+use crate::context::UpdateContext;
+use core::fmt;
+use gc_arena::{Collect, GcCell, MutationContext};
+use ruffle_render::bitmap::BitmapInfo;
+use ruffle_video::frame::EncodedFrame;
+use ruffle_video::VideoStreamHandle;
+use std::ops::Range;
+use swf::{VideoCodec, VideoDeblocking};
+
+/// The FLV tag type of video tags.
+const FLV_TAG_VIDEO: u8 = 9;
+
+/// The FLV frame type of video info/command frames, which carry no picture.
+const FLV_FRAME_INFO: u8 = 5;
+
+/// A stream of FLV video data, played back into any `Video` it is attached
+/// to.
+///
+/// The stream is advanced by the `Video` displaying it, one video frame per
+/// tick; a stream that isn't attached to a `Video` does not play.
+#[derive(Clone, Collect, Copy)]
+#[collect(no_drop)]
+pub struct NetStream<'gc>(GcCell<'gc, NetStreamData<'gc>>);
+
+impl fmt::Debug for NetStream<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NetStream")
+            .field("ptr", &self.0.as_ptr())
+            .finish()
+    }
+}
+
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+pub struct NetStreamData<'gc> {
+    /// The FLV file being played.
+    buffer: Vec<u8>,
+
+    /// The offset of the next FLV tag to read from `buffer`.
+    offset: usize,
+
+    /// The decoder stream that video tags are fed through.
+    ///
+    /// This is registered when the first video tag is read, as FLV files
+    /// only declare their codec on each tag.
+    #[collect(require_static)]
+    video_stream: Option<VideoStreamHandle>,
+
+    /// The ID of the next video frame to be decoded.
+    frame_id: u32,
+
+    /// Whether the stream is currently playing.
+    playing: bool,
+
+    /// AVM1 representation of this stream, which receives `onStatus` events.
+    avm1_object: Option<Avm1Object<'gc>>,
+}
+
+impl<'gc> NetStream<'gc> {
+    pub fn new(gc_context: MutationContext<'gc, '_>, avm1_object: Option<Avm1Object<'gc>>) -> Self {
+        Self(GcCell::allocate(
+            gc_context,
+            NetStreamData {
+                buffer: Vec::new(),
+                offset: 0,
+                video_stream: None,
+                frame_id: 0,
+                playing: false,
+                avm1_object,
+            },
+        ))
+    }
+
+    /// Start fetching the FLV file at `url`, which is played once loaded.
+    pub fn play(self, context: &mut UpdateContext<'_, 'gc>, url: String) {
+        self.close(context);
+
+        let future =
+            context
+                .load_manager
+                .load_netstream(context.player.clone(), self, Request::get(url));
+        context.navigator.spawn_future(future);
+    }
+
+    /// Stop playback and discard any loaded data.
+    pub fn close(self, context: &mut UpdateContext<'_, 'gc>) {
+        let mut write = self.0.write(context.gc_context);
+        write.buffer = Vec::new();
+        write.offset = 0;
+        write.video_stream = None;
+        write.frame_id = 0;
+        write.playing = false;
+    }
+
+    /// Start playing a loaded FLV file.
+    pub fn load_buffer(self, context: &mut UpdateContext<'_, 'gc>, data: Vec<u8>) {
+        let offset = match flv_body_offset(&data) {
+            Some(offset) => offset,
+            None => {
+                tracing::error!("NetStream: Loaded data is not an FLV file");
+                self.send_status(context, "NetStream.Play.FileStructureInvalid", "error");
+                return;
+            }
+        };
+
+        let mut write = self.0.write(context.gc_context);
+        write.buffer = data;
+        write.offset = offset;
+        write.playing = true;
+        drop(write);
+
+        self.send_status(context, "NetStream.Play.Start", "status");
+    }
+
+    /// Report that the FLV file could not be loaded.
+    pub fn report_error(self, context: &mut UpdateContext<'_, 'gc>) {
+        self.0.write(context.gc_context).playing = false;
+        self.send_status(context, "NetStream.Play.StreamNotFound", "error");
+    }
+
+    /// Decode the next video frame of the stream.
+    ///
+    /// Returns the ID and bitmap of the decoded frame, or `None` if no frame
+    /// could be decoded this tick. Reaching the end of the stream stops
+    /// playback.
+    pub fn tick(self, context: &mut UpdateContext<'_, 'gc>) -> Option<(u32, BitmapInfo)> {
+        if !self.0.read().playing {
+            return None;
+        }
+
+        loop {
+            let read = self.0.read();
+            let (tag_type, body, next_offset) = match read_flv_tag(&read.buffer, read.offset) {
+                Some(tag) => tag,
+                None => {
+                    drop(read);
+                    self.0.write(context.gc_context).playing = false;
+                    self.send_status(context, "NetStream.Play.Stop", "status");
+                    return None;
+                }
+            };
+
+            let frame = if tag_type == FLV_TAG_VIDEO {
+                read_video_packet(&read.buffer[body.clone()]).map(|(codec, packet)| {
+                    (codec, body.start + packet.start..body.start + packet.end)
+                })
+            } else {
+                None
+            };
+            drop(read);
+            self.0.write(context.gc_context).offset = next_offset;
+
+            if let Some((codec, packet)) = frame {
+                return self.decode_frame(context, codec, packet);
+            }
+        }
+    }
+
+    /// Preload and decode one video packet out of the stream's buffer.
+    fn decode_frame(
+        self,
+        context: &mut UpdateContext<'_, 'gc>,
+        codec: VideoCodec,
+        packet: Range<usize>,
+    ) -> Option<(u32, BitmapInfo)> {
+        let mut write = self.0.write(context.gc_context);
+        let stream = match write.video_stream {
+            Some(stream) => stream,
+            None => {
+                let stream = context.video.register_video_stream(
+                    0,
+                    (0, 0),
+                    codec,
+                    VideoDeblocking::UseVideoPacketValue,
+                );
+                match stream {
+                    Ok(stream) => *write.video_stream.insert(stream),
+                    Err(e) => {
+                        tracing::error!("NetStream: Got error when registering video: {}", e);
+                        write.playing = false;
+                        return None;
+                    }
+                }
+            }
+        };
+
+        let frame_id = write.frame_id;
+        write.frame_id += 1;
+        let data = &write.buffer[packet];
+
+        let res = context
+            .video
+            .preload_video_stream_frame(
+                stream,
+                EncodedFrame {
+                    codec,
+                    data,
+                    frame_id,
+                },
+            )
+            .and_then(|_| {
+                context.video.decode_video_stream_frame(
+                    stream,
+                    EncodedFrame {
+                        codec,
+                        data,
+                        frame_id,
+                    },
+                    context.renderer,
+                )
+            });
+
+        match res {
+            Ok(bitmap) => Some((frame_id, bitmap)),
+            Err(e) => {
+                tracing::error!(
+                    "NetStream: Got error when decoding frame {}: {}",
+                    frame_id,
+                    e
+                );
+                None
+            }
+        }
+    }
+
+    /// Call `onStatus` on this stream's AVM1 object.
+    fn send_status(
+        self,
+        context: &mut UpdateContext<'_, 'gc>,
+        code: &'static str,
+        level: &'static str,
+    ) {
+        let object = match self.0.read().avm1_object {
+            Some(object) => object,
+            None => return,
+        };
+
+        let mut activation = Avm1Activation::from_stub(
+            context.reborrow(),
+            ActivationIdentifier::root("[NetStream]"),
+        );
+        let info = ScriptObject::new(
+            activation.context.gc_context,
+            Some(activation.context.avm1.prototypes().object),
+        );
+        let _ = info.set("code", code.into(), &mut activation);
+        let _ = info.set("level", level.into(), &mut activation);
+        let _ = object.call_method(
+            "onStatus".into(),
+            &[info.into()],
+            &mut activation,
+            ExecutionReason::Special,
+        );
+    }
+}
+
+/// Returns the offset of the first tag in an FLV file, or `None` if `data`
+/// does not start with an FLV header.
+fn flv_body_offset(data: &[u8]) -> Option<usize> {
+    if data.get(0..3)? != b"FLV" {
+        return None;
+    }
+
+    let header_size = u32::from_be_bytes(data.get(5..9)?.try_into().ok()?) as usize;
+
+    // The body opens with the size of the (nonexistent) previous tag.
+    Some(header_size + 4)
+}
+
+/// Read the FLV tag at `offset`.
+///
+/// Returns the tag type, the range of its body, and the offset of the tag
+/// following it, or `None` if there is no complete tag at `offset`.
+fn read_flv_tag(data: &[u8], offset: usize) -> Option<(u8, Range<usize>, usize)> {
+    let header = data.get(offset..offset + 11)?;
+    let tag_type = header[0] & 0x1f;
+    let size = u32::from_be_bytes([0, header[1], header[2], header[3]]) as usize;
+    let body = offset + 11..offset + 11 + size;
+    if body.end > data.len() {
+        return None;
+    }
+
+    let next_offset = body.end + 4;
+    Some((tag_type, body, next_offset))
+}
+
+/// Read the codec and the range of the codec bitstream out of an FLV video
+/// tag body.
+///
+/// Returns `None` for info frames and unsupported codecs.
+fn read_video_packet(body: &[u8]) -> Option<(VideoCodec, Range<usize>)> {
+    let flags = *body.first()?;
+    if flags >> 4 == FLV_FRAME_INFO {
+        return None;
+    }
+
+    let codec = match VideoCodec::from_u8(flags & 0xf) {
+        Some(codec) => codec,
+        None => {
+            tracing::warn!("NetStream: Unsupported video codec {}", flags & 0xf);
+            return None;
+        }
+    };
+
+    // VP6 packets in FLV files are prefixed with a byte of size adjustments,
+    // which SWF-embedded packets don't have.
+    let start = match codec {
+        VideoCodec::Vp6 | VideoCodec::Vp6WithAlpha => 2,
+        _ => 1,
+    };
+
+    if start > body.len() {
+        return None;
+    }
+
+    Some((codec, start..body.len()))
+}
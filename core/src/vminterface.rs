@@ -94,6 +94,15 @@ impl<'gc> AvmObject<'gc> {
             Self::Avm2(o) => Some(*o),
         }
     }
+
+    /// Check if two objects are the same object in the same VM.
+    pub fn ptr_eq(&self, other: AvmObject<'gc>) -> bool {
+        match (self, other) {
+            (Self::Avm1(a), Self::Avm1(b)) => Avm1Object::ptr_eq(*a, b),
+            (Self::Avm2(a), Self::Avm2(b)) => Avm2Object::ptr_eq(*a, b),
+            _ => false,
+        }
+    }
 }
 
 impl<'gc> From<Avm1Object<'gc>> for AvmObject<'gc> {
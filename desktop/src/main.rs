@@ -35,8 +35,8 @@ use std::time::Instant;
 use url::Url;
 use winit::dpi::{LogicalSize, PhysicalPosition, PhysicalSize, Size};
 use winit::event::{
-    ElementState, KeyboardInput, ModifiersState, MouseButton, MouseScrollDelta, VirtualKeyCode,
-    WindowEvent,
+    ElementState, Ime, KeyboardInput, ModifiersState, MouseButton, MouseScrollDelta,
+    VirtualKeyCode, WindowEvent,
 };
 use winit::event_loop::{ControlFlow, EventLoop, EventLoopBuilder};
 use winit::window::{Fullscreen, Icon, Window, WindowBuilder};
@@ -239,6 +239,7 @@ impl App {
             .with_window_icon(Some(icon))
             .with_max_inner_size(LogicalSize::new(i16::MAX, i16::MAX))
             .build(&event_loop)?;
+        window.set_ime_allowed(true);
 
         let mut builder = PlayerBuilder::new();
 
@@ -508,6 +509,22 @@ impl App {
                                 self.window.request_redraw();
                             }
                         }
+                        WindowEvent::Ime(ime) => {
+                            let event = match ime {
+                                Ime::Preedit(text, cursor) => {
+                                    Some(PlayerEvent::ImePreedit { text, cursor })
+                                }
+                                Ime::Commit(text) => Some(PlayerEvent::ImeCommit { text }),
+                                Ime::Enabled | Ime::Disabled => None,
+                            };
+                            if let Some(event) = event {
+                                let mut player_lock = self.player.lock().expect("Cannot reenter");
+                                player_lock.handle_event(event);
+                                if player_lock.needs_render() {
+                                    self.window.request_redraw();
+                                }
+                            }
+                        }
                         _ => (),
                     },
                     winit::event::Event::UserEvent(RuffleEvent::TaskPoll) => self
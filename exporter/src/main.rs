@@ -1,20 +1,26 @@
 use anyhow::{anyhow, Result};
 use clap::Parser;
-use image::RgbaImage;
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, Frame};
 use indicatif::{ProgressBar, ProgressStyle};
 use rayon::prelude::*;
+use ruffle_core::backend::log::LogBackend;
 use ruffle_core::limits::ExecutionLimit;
 use ruffle_core::tag_utils::SwfMovie;
 use ruffle_core::PlayerBuilder;
+use ruffle_render_software::SoftwareRenderBackend;
 use ruffle_render_wgpu::backend::WgpuRenderBackend;
 use ruffle_render_wgpu::clap::{GraphicsBackend, PowerPreference};
 use ruffle_render_wgpu::descriptors::Descriptors;
 use ruffle_render_wgpu::target::TextureTarget;
 use ruffle_render_wgpu::wgpu;
-use std::fs::create_dir_all;
+use sha2::{Digest, Sha256};
+use std::fs::{create_dir_all, File};
+use std::io::Write;
 use std::panic::catch_unwind;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use walkdir::{DirEntry, WalkDir};
 
 #[derive(Parser, Debug, Copy, Clone)]
@@ -44,6 +50,9 @@ struct Opt {
     /// - If given one swf and one frame, the name of the swf + ".png"
     /// - If given one swf and multiple frames, the name of the swf as a directory
     /// - If given multiple swfs, this field is required.
+    ///
+    /// If given one swf and a path ending in ".gif", the frames are saved as an
+    /// animated GIF, timed by the movie's frame rate.
     #[clap(name = "output")]
     output_path: Option<PathBuf>,
 
@@ -80,18 +89,51 @@ struct Opt {
     /// Skip unsupported movie types (currently AVM 2)
     #[clap(long, action)]
     skip_unsupported: bool,
+
+    /// Seed for the random number generator, to make output reproducible between runs
+    #[clap(long)]
+    seed: Option<u64>,
+
+    /// File to write the output of `trace()` calls to, each line prefixed with the path of the movie
+    #[clap(long)]
+    trace_output: Option<PathBuf>,
+
+    /// Print a checksum of each captured frame instead of saving images.
+    /// The output path is not used in this mode.
+    #[clap(long, action)]
+    hash: bool,
+}
+
+/// Log backend that writes traces to a file, one line per call.
+/// The file is shared between all movies being captured, which run in parallel,
+/// so each line is prefixed with the path of the movie that traced it.
+struct FileLogBackend {
+    file: Arc<Mutex<File>>,
+    swf_path: String,
 }
 
-/// Captures a screenshot. The resulting image uses straight alpha
+impl LogBackend for FileLogBackend {
+    fn avm_trace(&self, message: &str) {
+        if let Ok(mut file) = self.file.lock() {
+            let _ = writeln!(file, "{}: {message}", self.swf_path);
+        }
+    }
+}
+
+/// Captures a screenshot. The resulting image uses straight alpha.
+/// Each frame is delayed by the frame rate in effect when it was captured.
+#[allow(clippy::too_many_arguments)]
 fn take_screenshot(
-    descriptors: Arc<Descriptors>,
+    descriptors: Option<Arc<Descriptors>>,
     swf_path: &Path,
     frames: u32,
     skipframes: u32,
     progress: &Option<ProgressBar>,
     size: SizeOpt,
     skip_unsupported: bool,
-) -> Result<Vec<RgbaImage>> {
+    seed: Option<u64>,
+    trace_output: Option<Arc<Mutex<File>>>,
+) -> Result<Vec<Frame>> {
     let movie = SwfMovie::from_path(&swf_path, None).map_err(|e| anyhow!(e.to_string()))?;
 
     if movie.is_action_script_3() && skip_unsupported {
@@ -110,16 +152,33 @@ fn take_screenshot(
         .unwrap_or_else(|| movie.height().to_pixels());
     let height = (height * size.scale).round() as u32;
 
-    let target = TextureTarget::new(&descriptors.device, (width, height))
-        .map_err(|e| anyhow!(e.to_string()))?;
-    let player = PlayerBuilder::new()
-        .with_renderer(
+    let builder = if let Some(descriptors) = descriptors {
+        let target = TextureTarget::new(&descriptors.device, (width, height))
+            .map_err(|e| anyhow!(e.to_string()))?;
+        PlayerBuilder::new().with_renderer(
             WgpuRenderBackend::new(descriptors, target, 4).map_err(|e| anyhow!(e.to_string()))?,
         )
+    } else {
+        PlayerBuilder::new().with_renderer(SoftwareRenderBackend::new(width, height))
+    };
+    let builder = match trace_output {
+        Some(file) => builder.with_log(FileLogBackend {
+            file,
+            swf_path: swf_path.to_string_lossy().into_owned(),
+        }),
+        None => builder,
+    };
+    let player = builder
         .with_movie(movie)
         .with_viewport_dimensions(width, height, size.scale)
+        .with_autoplay(true)
+        .with_simulated_clock(true)
         .build();
 
+    if let Some(seed) = seed {
+        player.lock().unwrap().set_rng_seed(seed);
+    }
+
     let mut result = Vec::new();
     let totalframes = frames + skipframes;
 
@@ -134,19 +193,33 @@ fn take_screenshot(
 
         player.lock().unwrap().preload(&mut ExecutionLimit::none());
 
-        player.lock().unwrap().run_frame();
+        let delay = {
+            // Run exactly one frame per capture, and advance the clock and timers by
+            // one frame's worth of time, so that time-based content sees the same
+            // clock it would see when playing in real time.
+            let mut player = player.lock().unwrap();
+            let frame_time = 1000.0 / player.frame_rate();
+            player.advance_simulated_clock(frame_time);
+            player.run_frame();
+            player.update_timers(frame_time);
+            // Content may have changed the frame rate while running the frame.
+            Delay::from_saturating_duration(Duration::from_secs_f64(1.0 / player.frame_rate()))
+        };
         if i >= skipframes {
             match catch_unwind(|| {
                 player.lock().unwrap().render();
                 let mut player = player.lock().unwrap();
-                let renderer = player
-                    .renderer_mut()
+                let renderer = player.renderer_mut();
+                if let Some(renderer) = renderer.downcast_ref::<SoftwareRenderBackend>() {
+                    return Some(renderer.capture_frame());
+                }
+                let renderer = renderer
                     .downcast_mut::<WgpuRenderBackend<TextureTarget>>()
                     .unwrap();
                 // Use straight alpha
                 renderer.capture_frame(false)
             }) {
-                Ok(Some(image)) => result.push(image),
+                Ok(Some(image)) => result.push(Frame::from_parts(image, 0, 0, delay)),
                 Ok(None) => return Err(anyhow!("Unable to capture frame {} of {:?}", i, swf_path)),
                 Err(e) => {
                     return Err(anyhow!(
@@ -166,6 +239,43 @@ fn take_screenshot(
     Ok(result)
 }
 
+/// Returns a SHA-256 checksum of the frame's dimensions and RGBA pixels, as hex.
+fn frame_hash(frame: &Frame) -> String {
+    let image = frame.buffer();
+    let mut hash = Sha256::new();
+    hash.update(image.width().to_le_bytes());
+    hash.update(image.height().to_le_bytes());
+    hash.update(image.as_raw());
+    hash.finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Formats the checksums of the frames of `swf_path`, one line per frame.
+fn frame_hashes(swf_path: &Path, frames: &[Frame]) -> String {
+    frames
+        .iter()
+        .enumerate()
+        .map(|(frame, image)| {
+            format!(
+                "{} frame {}: {}\n",
+                swf_path.to_string_lossy(),
+                frame,
+                frame_hash(image)
+            )
+        })
+        .collect()
+}
+
+/// Saves the frames as an animated GIF that loops forever.
+fn save_gif(path: &Path, frames: Vec<Frame>) -> Result<()> {
+    let mut encoder = GifEncoder::new(File::create(path)?);
+    encoder.set_repeat(Repeat::Infinite)?;
+    encoder.encode_frames(frames)?;
+    Ok(())
+}
+
 fn find_files(root: &Path, with_progress: bool) -> Vec<DirEntry> {
     let progress = if with_progress {
         Some(ProgressBar::new_spinner())
@@ -196,7 +306,34 @@ fn find_files(root: &Path, with_progress: bool) -> Vec<DirEntry> {
     results
 }
 
-fn capture_single_swf(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
+/// Returns the graphics device to render with, reporting when there is none
+/// and frames are rendered in software instead.
+fn descriptors_or_fallback(
+    descriptors: Result<Arc<Descriptors>>,
+    progress: &Option<ProgressBar>,
+    opt: &Opt,
+) -> Option<Arc<Descriptors>> {
+    match descriptors {
+        Ok(descriptors) => Some(descriptors),
+        Err(e) => {
+            let message = format!(
+                "Falling back to software rendering, as no graphics device is available: {e}"
+            );
+            if let Some(progress) = progress {
+                progress.println(message);
+            } else if !opt.hash {
+                println!("{message}");
+            }
+            None
+        }
+    }
+}
+
+fn capture_single_swf(
+    descriptors: Result<Arc<Descriptors>>,
+    opt: &Opt,
+    trace_output: Option<Arc<Mutex<File>>>,
+) -> Result<()> {
     let output = opt.output_path.clone().unwrap_or_else(|| {
         let mut result = PathBuf::new();
         result.set_file_name(opt.swf.file_stem().unwrap());
@@ -206,7 +343,11 @@ fn capture_single_swf(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
         result
     });
 
-    if opt.frames > 1 {
+    let is_gif = output
+        .extension()
+        .map_or(false, |extension| extension.eq_ignore_ascii_case("gif"));
+
+    if opt.frames > 1 && !is_gif && !opt.hash {
         let _ = create_dir_all(&output);
     }
 
@@ -223,6 +364,7 @@ fn capture_single_swf(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
     } else {
         None
     };
+    let descriptors = descriptors_or_fallback(descriptors, &progress, opt);
 
     let frames = take_screenshot(
         descriptors,
@@ -232,23 +374,36 @@ fn capture_single_swf(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
         &progress,
         opt.size,
         opt.skip_unsupported,
+        opt.seed,
+        trace_output,
     )?;
 
     if let Some(progress) = &progress {
         progress.set_message(opt.swf.file_stem().unwrap().to_string_lossy().into_owned());
     }
 
-    if frames.len() == 1 {
-        frames.get(0).unwrap().save(&output)?;
+    let frame_count = frames.len();
+    if opt.hash {
+        print!("{}", frame_hashes(&opt.swf, &frames));
+    } else if is_gif {
+        save_gif(&output, frames)?;
+    } else if frame_count == 1 {
+        frames.get(0).unwrap().buffer().save(&output)?;
     } else {
         for (frame, image) in frames.iter().enumerate() {
             let mut path: PathBuf = (&output).into();
             path.push(format!("{frame}.png"));
-            image.save(&path)?;
+            image.buffer().save(&path)?;
         }
     }
 
-    let message = if frames.len() == 1 {
+    let message = if opt.hash {
+        format!(
+            "Hashed first {} frames of {}",
+            frame_count,
+            opt.swf.to_string_lossy()
+        )
+    } else if frame_count == 1 {
         format!(
             "Saved first frame of {} to {}",
             opt.swf.to_string_lossy(),
@@ -257,7 +412,7 @@ fn capture_single_swf(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
     } else {
         format!(
             "Saved first {} frames of {} to {}",
-            frames.len(),
+            frame_count,
             opt.swf.to_string_lossy(),
             output.to_string_lossy()
         )
@@ -265,7 +420,8 @@ fn capture_single_swf(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
 
     if let Some(progress) = progress {
         progress.finish_with_message(message);
-    } else {
+    } else if !opt.hash {
+        // Keep the output of hash mode limited to the hashes.
         println!("{message}");
     }
 
@@ -273,8 +429,12 @@ fn capture_single_swf(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
 }
 
 #[allow(clippy::branches_sharing_code)]
-fn capture_multiple_swfs(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
-    let output = opt.output_path.clone().unwrap();
+fn capture_multiple_swfs(
+    descriptors: Result<Arc<Descriptors>>,
+    opt: &Opt,
+    trace_output: Option<Arc<Mutex<File>>>,
+) -> Result<()> {
+    let output = opt.output_path.clone().unwrap_or_default();
     let files = find_files(&opt.swf, !opt.silent);
 
     let progress = if !opt.silent {
@@ -290,57 +450,80 @@ fn capture_multiple_swfs(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()>
     } else {
         None
     };
+    let descriptors = descriptors_or_fallback(descriptors, &progress, opt);
 
-    files.par_iter().try_for_each(|file| -> Result<()> {
-        if let Some(progress) = &progress {
-            progress.set_message(
-                file.path()
-                    .file_stem()
-                    .unwrap()
-                    .to_string_lossy()
-                    .into_owned(),
-            );
-        }
-        if let Ok(frames) = take_screenshot(
-            descriptors.clone(),
-            file.path(),
-            opt.frames,
-            opt.skipframes,
-            &progress,
-            opt.size,
-            opt.skip_unsupported,
-        ) {
-            let mut relative_path = file
-                .path()
-                .strip_prefix(&opt.swf)
-                .unwrap_or_else(|_| file.path())
-                .to_path_buf();
-
-            if frames.len() == 1 {
-                let mut destination: PathBuf = (&output).into();
-                relative_path.set_extension("png");
-                destination.push(relative_path);
-                if let Some(parent) = destination.parent() {
-                    let _ = create_dir_all(parent);
-                }
-                frames.get(0).unwrap().save(&destination)?;
-            } else {
-                let mut parent: PathBuf = (&output).into();
-                relative_path.set_extension("");
-                parent.push(&relative_path);
-                let _ = create_dir_all(&parent);
-                for (frame, image) in frames.iter().enumerate() {
-                    let mut destination = parent.clone();
-                    destination.push(format!("{frame}.png"));
-                    image.save(&destination)?;
+    let hashes = files
+        .par_iter()
+        .map(|file| -> Result<Option<(PathBuf, String)>> {
+            if let Some(progress) = &progress {
+                progress.set_message(
+                    file.path()
+                        .file_stem()
+                        .unwrap()
+                        .to_string_lossy()
+                        .into_owned(),
+                );
+            }
+            if let Ok(frames) = take_screenshot(
+                descriptors.clone(),
+                file.path(),
+                opt.frames,
+                opt.skipframes,
+                &progress,
+                opt.size,
+                opt.skip_unsupported,
+                opt.seed,
+                trace_output.clone(),
+            ) {
+                let mut relative_path = file
+                    .path()
+                    .strip_prefix(&opt.swf)
+                    .unwrap_or_else(|_| file.path())
+                    .to_path_buf();
+
+                if opt.hash {
+                    let hashes = frame_hashes(&relative_path, &frames);
+                    return Ok(Some((relative_path, hashes)));
+                } else if frames.len() == 1 {
+                    let mut destination: PathBuf = (&output).into();
+                    relative_path.set_extension("png");
+                    destination.push(relative_path);
+                    if let Some(parent) = destination.parent() {
+                        let _ = create_dir_all(parent);
+                    }
+                    frames.get(0).unwrap().buffer().save(&destination)?;
+                } else {
+                    let mut parent: PathBuf = (&output).into();
+                    relative_path.set_extension("");
+                    parent.push(&relative_path);
+                    let _ = create_dir_all(&parent);
+                    for (frame, image) in frames.iter().enumerate() {
+                        let mut destination = parent.clone();
+                        destination.push(format!("{frame}.png"));
+                        image.buffer().save(&destination)?;
+                    }
                 }
             }
-        }
 
-        Ok(())
-    })?;
+            Ok(None)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // Print the checksums once every file is done, sorted so the output is stable
+    // regardless of the order the files were processed in.
+    let mut hashes: Vec<_> = hashes.into_iter().flatten().collect();
+    hashes.sort_by(|(a, _), (b, _)| a.cmp(b));
+    for (_, hashes) in hashes {
+        print!("{hashes}");
+    }
 
-    let message = if opt.frames == 1 {
+    let message = if opt.hash {
+        format!(
+            "Hashed first {} frames of {} files",
+            opt.frames,
+            files.len()
+        )
+    } else if opt.frames == 1 {
         format!(
             "Saved first frame of {} files to {}",
             files.len(),
@@ -357,7 +540,7 @@ fn capture_multiple_swfs(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()>
 
     if let Some(progress) = progress {
         progress.finish_with_message(message);
-    } else {
+    } else if !opt.hash {
         println!("{message}");
     }
 
@@ -390,14 +573,18 @@ fn main() -> Result<()> {
             opt.power.into(),
             trace_path(&opt),
         ))
-        .map_err(|e| anyhow!(e.to_string()))?;
+        .map(Arc::new)
+        .map_err(|e| anyhow!(e.to_string()));
 
-    let descriptors = Arc::new(descriptors);
+    let trace_output = match &opt.trace_output {
+        Some(path) => Some(Arc::new(Mutex::new(File::create(path)?))),
+        None => None,
+    };
 
     if opt.swf.is_file() {
-        capture_single_swf(descriptors, &opt)?;
-    } else if opt.output_path.is_some() {
-        capture_multiple_swfs(descriptors, &opt)?;
+        capture_single_swf(descriptors, &opt, trace_output)?;
+    } else if opt.output_path.is_some() || opt.hash {
+        capture_multiple_swfs(descriptors, &opt, trace_output)?;
     } else {
         return Err(anyhow!(
             "Output directory is required when exporting multiple files."
@@ -406,3 +593,36 @@ fn main() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash_test_swf(seed: Option<u64>) -> String {
+        let swf_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../tests/tests/swfs/visual/blend_modes/add/test.swf");
+        let size = SizeOpt {
+            scale: 1.0,
+            width: None,
+            height: None,
+        };
+        let frames = take_screenshot(None, &swf_path, 3, 1, &None, size, false, seed, None)
+            .expect("Frames should be captured");
+        frame_hashes(Path::new("test.swf"), &frames)
+    }
+
+    #[test]
+    fn frame_hashes_are_stable() {
+        let hashes = hash_test_swf(Some(1));
+        assert_eq!(hashes, hash_test_swf(Some(1)));
+
+        let lines: Vec<_> = hashes.lines().collect();
+        assert_eq!(lines.len(), 3);
+        for (frame, line) in lines.iter().enumerate() {
+            let (name, hash) = line.split_once(": ").unwrap();
+            assert_eq!(name, format!("test.swf frame {frame}"));
+            assert_eq!(hash.len(), 64);
+            assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
+        }
+    }
+}
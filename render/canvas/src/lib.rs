@@ -9,7 +9,7 @@ use ruffle_render::bitmap::{
     Bitmap, BitmapFormat, BitmapHandle, BitmapHandleImpl, BitmapSource, SyncHandle,
 };
 use ruffle_render::color_transform::ColorTransform;
-use ruffle_render::commands::{CommandHandler, CommandList};
+use ruffle_render::commands::{CommandHandler, CommandList, TriangleMesh};
 use ruffle_render::error::Error;
 use ruffle_render::matrix::Matrix;
 use ruffle_render::shape_utils::{DistilledShape, DrawCommand, LineScaleMode, LineScales};
@@ -17,7 +17,7 @@ use ruffle_render::transform::Transform;
 use ruffle_web_common::{JsError, JsResult};
 use std::borrow::Cow;
 use std::sync::Arc;
-use swf::{BlendMode, Color};
+use swf::{BlendMode, Color, Filter};
 use wasm_bindgen::{Clamped, JsCast, JsValue};
 use web_sys::{
     CanvasGradient, CanvasPattern, CanvasRenderingContext2d, CanvasWindingRule, DomMatrix, Element,
@@ -476,6 +476,13 @@ impl RenderBackend for WebCanvasRenderBackend {
         Err(Error::Unimplemented)
     }
 
+    fn get_offscreen_pixels(
+        &self,
+        _handle: &BitmapHandle,
+    ) -> Result<Bitmap, ruffle_render::error::Error> {
+        Err(Error::Unimplemented)
+    }
+
     fn submit_frame(&mut self, clear: Color, commands: CommandList) {
         self.begin_frame(clear);
         commands.execute(self);
@@ -790,6 +797,19 @@ impl CommandHandler for WebCanvasRenderBackend {
         commands.execute(self);
         self.pop_blend_mode();
     }
+
+    fn apply_filters(&mut self, commands: CommandList, _filters: Vec<Filter>) {
+        // TODO: Filters are not yet supported by this backend, so draw the content unfiltered.
+        commands.execute(self);
+    }
+
+    fn draw_triangles(&mut self, _mesh: TriangleMesh, _transform: Transform) {
+        // TODO: Triangle meshes are not yet supported by this backend.
+        static WARN_ONCE: std::sync::Once = std::sync::Once::new();
+        WARN_ONCE.call_once(|| {
+            log::warn!("Triangle meshes are not yet supported by the canvas backend")
+        });
+    }
 }
 
 /// Convert a series of `DrawCommands` to a `Path2d` shape.
@@ -0,0 +1,1007 @@
+//! A render backend that rasterizes on the CPU.
+//!
+//! This is much slower than the GPU backends, but it needs no graphics device at all,
+//! making it useful for capturing frames on headless machines such as CI servers.
+
+#![deny(clippy::unwrap_used)]
+
+mod raster;
+
+use crate::raster::{rasterize_triangle, Canvas, Pixel};
+use gc_arena::MutationContext;
+use image::RgbaImage;
+use ruffle_render::backend::null::NullBitmapSource;
+use ruffle_render::backend::{
+    Context3D, Context3DCommand, RenderBackend, ShapeHandle, ViewportDimensions,
+};
+use ruffle_render::bitmap::{
+    Bitmap, BitmapFormat, BitmapHandle, BitmapHandleImpl, BitmapSource, SyncHandle,
+};
+use ruffle_render::color_transform::ColorTransform;
+use ruffle_render::commands::{CommandHandler, CommandList, MeshFill, TriangleMesh};
+use ruffle_render::error::Error;
+use ruffle_render::matrix::Matrix;
+use ruffle_render::quality::RenderQuality;
+use ruffle_render::shape_utils::DistilledShape;
+use ruffle_render::tessellator::{
+    DrawType as TessDrawType, Gradient, GradientType, ShapeTessellator, Vertex,
+};
+use ruffle_render::transform::Transform;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::sync::Arc;
+use swf::{BlendMode, Color, Filter};
+
+pub struct SoftwareRenderBackend {
+    dimensions: ViewportDimensions,
+    target: Canvas,
+    meshes: Vec<Mesh>,
+    shape_tessellator: ShapeTessellator,
+}
+
+impl SoftwareRenderBackend {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            dimensions: ViewportDimensions {
+                width,
+                height,
+                scale_factor: 1.0,
+            },
+            target: Canvas::new(width, height),
+            meshes: Vec::new(),
+            shape_tessellator: ShapeTessellator::new(),
+        }
+    }
+
+    /// Returns the last submitted frame, with straight alpha.
+    pub fn capture_frame(&self) -> RgbaImage {
+        self.target.to_image()
+    }
+
+    fn register_shape_internal(
+        &mut self,
+        shape: DistilledShape,
+        bitmap_source: &dyn BitmapSource,
+    ) -> Mesh {
+        let lyon_mesh = self
+            .shape_tessellator
+            .tessellate_shape(shape, bitmap_source);
+
+        let mut draws = Vec::with_capacity(lyon_mesh.len());
+        for draw in lyon_mesh {
+            let draw_type = match draw.draw_type {
+                TessDrawType::Color => DrawType::Color,
+                TessDrawType::Gradient(gradient) => DrawType::Gradient(Box::new(gradient)),
+                TessDrawType::Bitmap(bitmap) => DrawType::Bitmap {
+                    matrix: bitmap.matrix,
+                    handle: bitmap_source.bitmap_handle(bitmap.bitmap_id, self),
+                    is_smoothed: bitmap.is_smoothed,
+                    is_repeating: bitmap.is_repeating,
+                },
+            };
+            draws.push(Draw {
+                draw_type,
+                vertices: draw.vertices,
+                indices: draw.indices,
+                mask_index_count: draw.mask_index_count as usize,
+            });
+        }
+
+        Mesh { draws }
+    }
+}
+
+impl RenderBackend for SoftwareRenderBackend {
+    fn viewport_dimensions(&self) -> ViewportDimensions {
+        self.dimensions
+    }
+
+    fn set_viewport_dimensions(&mut self, dimensions: ViewportDimensions) {
+        self.dimensions = dimensions;
+        self.target = Canvas::new(dimensions.width, dimensions.height);
+    }
+
+    fn register_shape(
+        &mut self,
+        shape: DistilledShape,
+        bitmap_source: &dyn BitmapSource,
+    ) -> ShapeHandle {
+        let handle = ShapeHandle(self.meshes.len());
+        let mesh = self.register_shape_internal(shape, bitmap_source);
+        self.meshes.push(mesh);
+        handle
+    }
+
+    fn replace_shape(
+        &mut self,
+        shape: DistilledShape,
+        bitmap_source: &dyn BitmapSource,
+        handle: ShapeHandle,
+    ) {
+        let mesh = self.register_shape_internal(shape, bitmap_source);
+        self.meshes[handle.0] = mesh;
+    }
+
+    fn register_glyph_shape(&mut self, glyph: &swf::Glyph) -> ShapeHandle {
+        let shape = ruffle_render::shape_utils::swf_glyph_to_shape(glyph);
+        self.register_shape((&shape).into(), &NullBitmapSource)
+    }
+
+    fn render_offscreen(
+        &mut self,
+        handle: BitmapHandle,
+        width: u32,
+        height: u32,
+        commands: CommandList,
+    ) -> Result<Box<dyn SyncHandle>, Error> {
+        // Render into a copy, as the commands may draw the bitmap itself.
+        let mut canvas = as_bitmap(&handle)?.0.borrow().resized(width, height);
+        commands.execute(&mut Frame::new(&mut canvas, &self.meshes));
+        *as_bitmap(&handle)?.0.borrow_mut() = canvas;
+        Ok(Box::new(SoftwareSyncHandle(handle)))
+    }
+
+    fn retrieve_offscreen_texture(&self, sync: Box<dyn SyncHandle>) -> Result<Bitmap, Error> {
+        let sync = sync
+            .downcast::<SoftwareSyncHandle>()
+            .map_err(|_| Error::Unimplemented)?;
+        self.get_offscreen_pixels(&sync.0)
+    }
+
+    fn get_offscreen_pixels(&self, handle: &BitmapHandle) -> Result<Bitmap, Error> {
+        Ok(as_bitmap(handle)?.0.borrow().to_bitmap())
+    }
+
+    fn submit_frame(&mut self, clear: Color, commands: CommandList) {
+        self.target.clear(premultiply(color_to_rgba(&clear)));
+        commands.execute(&mut Frame::new(&mut self.target, &self.meshes));
+    }
+
+    fn register_bitmap(&mut self, bitmap: Bitmap) -> Result<BitmapHandle, Error> {
+        Ok(BitmapHandle(Arc::new(SoftwareBitmap(RefCell::new(
+            Canvas::from_bitmap(bitmap),
+        )))))
+    }
+
+    fn update_texture(
+        &mut self,
+        handle: &BitmapHandle,
+        width: u32,
+        height: u32,
+        rgba: Vec<u8>,
+    ) -> Result<(), Error> {
+        let bitmap = Bitmap::new(width, height, BitmapFormat::Rgba, rgba);
+        *as_bitmap(handle)?.0.borrow_mut() = Canvas::from_bitmap(bitmap);
+        Ok(())
+    }
+
+    fn create_context3d(&mut self) -> Result<Box<dyn Context3D>, Error> {
+        Err(Error::Unimplemented)
+    }
+
+    fn context3d_present<'gc>(
+        &mut self,
+        _context: &mut dyn Context3D,
+        _commands: Vec<Context3DCommand<'gc>>,
+        _mc: MutationContext<'gc, '_>,
+    ) -> Result<(), Error> {
+        Err(Error::Unimplemented)
+    }
+
+    fn debug_info(&self) -> Cow<'static, str> {
+        Cow::Borrowed("Renderer: Software")
+    }
+
+    fn set_quality(&mut self, quality: RenderQuality) {
+        self.shape_tessellator.set_quality(quality);
+    }
+}
+
+#[derive(Debug)]
+struct SoftwareBitmap(RefCell<Canvas>);
+
+impl BitmapHandleImpl for SoftwareBitmap {}
+
+fn as_bitmap(handle: &BitmapHandle) -> Result<&SoftwareBitmap, Error> {
+    handle
+        .0
+        .downcast_ref::<SoftwareBitmap>()
+        .ok_or_else(|| Error::UnknownHandle(handle.clone()))
+}
+
+#[derive(Debug)]
+struct SoftwareSyncHandle(BitmapHandle);
+
+impl SyncHandle for SoftwareSyncHandle {}
+
+struct Mesh {
+    draws: Vec<Draw>,
+}
+
+struct Draw {
+    draw_type: DrawType,
+    vertices: Vec<Vertex>,
+    indices: Vec<u32>,
+    mask_index_count: usize,
+}
+
+enum DrawType {
+    Color,
+    Gradient(Box<Gradient>),
+    Bitmap {
+        matrix: [[f32; 3]; 3],
+        handle: Option<BitmapHandle>,
+        is_smoothed: bool,
+        is_repeating: bool,
+    },
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum MaskState {
+    /// Content is drawn to the canvas, clipped by any active masks.
+    DrawContent,
+
+    /// Content is drawn to the topmost mask.
+    DrawMask,
+
+    /// The topmost mask is being cleared, so nothing is drawn.
+    ClearMask,
+}
+
+/// The state of a single render pass over a canvas.
+struct Frame<'a> {
+    canvas: &'a mut Canvas,
+    meshes: &'a [Mesh],
+    masks: Vec<Vec<bool>>,
+    mask_state: MaskState,
+}
+
+impl<'a> Frame<'a> {
+    fn new(canvas: &'a mut Canvas, meshes: &'a [Mesh]) -> Self {
+        Self {
+            canvas,
+            meshes,
+            masks: Vec::new(),
+            mask_state: MaskState::DrawContent,
+        }
+    }
+
+    /// The number of masks that currently clip drawing.
+    fn num_active_masks(&self) -> usize {
+        match self.mask_state {
+            MaskState::DrawContent => self.masks.len(),
+            MaskState::DrawMask | MaskState::ClearMask => self.masks.len().saturating_sub(1),
+        }
+    }
+
+    /// Rasterizes the given triangles, calling `shade` to find the color of each pixel
+    /// from its position in object space.
+    fn draw_triangles(
+        &mut self,
+        matrix: &Matrix,
+        vertices: &[(f32, f32)],
+        indices: &[u32],
+        mut shade: impl FnMut((f32, f32)) -> Option<Pixel>,
+    ) {
+        if self.mask_state == MaskState::ClearMask {
+            return;
+        }
+
+        let (width, height) = (self.canvas.width(), self.canvas.height());
+        let num_active_masks = self.num_active_masks();
+        let mask_state = self.mask_state;
+        let to_device = |(x, y): (f32, f32)| {
+            (
+                matrix.a * x + matrix.c * y + matrix.tx.to_pixels() as f32,
+                matrix.b * x + matrix.d * y + matrix.ty.to_pixels() as f32,
+            )
+        };
+
+        for triangle in indices.chunks_exact(3) {
+            let points = match triangle
+                .iter()
+                .map(|&i| vertices.get(i as usize).copied())
+                .collect::<Option<Vec<_>>>()
+            {
+                Some(points) => points,
+                None => continue,
+            };
+            let device = [
+                to_device(points[0]),
+                to_device(points[1]),
+                to_device(points[2]),
+            ];
+            rasterize_triangle(width, height, device, |x, y, weights| {
+                let index = y as usize * width as usize + x as usize;
+                if !self.masks[..num_active_masks]
+                    .iter()
+                    .all(|mask| mask[index])
+                {
+                    return;
+                }
+                if mask_state == MaskState::DrawMask {
+                    if let Some(mask) = self.masks.last_mut() {
+                        mask[index] = true;
+                    }
+                    return;
+                }
+                let position = (
+                    weights[0] * points[0].0 + weights[1] * points[1].0 + weights[2] * points[2].0,
+                    weights[0] * points[0].1 + weights[1] * points[1].1 + weights[2] * points[2].1,
+                );
+                if let Some(color) = shade(position) {
+                    self.canvas.blend(x, y, color);
+                }
+            });
+        }
+    }
+
+    /// Draws the unit square, scaled by the given matrix.
+    fn draw_quad(&mut self, matrix: &Matrix, shade: impl FnMut((f32, f32)) -> Option<Pixel>) {
+        const QUAD: [(f32, f32); 4] = [(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        self.draw_triangles(matrix, &QUAD, &[0, 1, 2, 0, 2, 3], shade);
+    }
+}
+
+impl CommandHandler for Frame<'_> {
+    fn render_bitmap(&mut self, bitmap: BitmapHandle, transform: Transform, smoothing: bool) {
+        let texture = match as_bitmap(&bitmap) {
+            Ok(bitmap) => bitmap.0.borrow(),
+            Err(_) => return,
+        };
+        let matrix = transform.matrix
+            * Matrix {
+                a: texture.width() as f32,
+                d: texture.height() as f32,
+                ..Default::default()
+            };
+        let color_transform = transform.color_transform;
+        self.draw_quad(&matrix, |uv| {
+            let color = sample(&texture, uv, smoothing, false);
+            Some(transform_premultiplied(color, &color_transform))
+        });
+    }
+
+    fn render_shape(&mut self, shape: ShapeHandle, transform: Transform) {
+        let meshes = self.meshes;
+        let mesh = match meshes.get(shape.0) {
+            Some(mesh) => mesh,
+            None => return,
+        };
+        let color_transform = transform.color_transform;
+        for draw in &mesh.draws {
+            let num_indices = if self.mask_state == MaskState::DrawMask {
+                draw.mask_index_count
+            } else {
+                draw.indices.len()
+            };
+            let indices = &draw.indices[..num_indices.min(draw.indices.len())];
+            let positions: Vec<_> = draw.vertices.iter().map(|v| (v.x, v.y)).collect();
+
+            match &draw.draw_type {
+                DrawType::Color => {
+                    for triangle in indices.chunks_exact(3) {
+                        // Solid fills have the same color at every vertex of a triangle.
+                        let vertex = match draw.vertices.get(triangle[0] as usize) {
+                            Some(vertex) => vertex,
+                            None => continue,
+                        };
+                        let color =
+                            apply_color_transform(color_to_rgba(&vertex.color), &color_transform);
+                        self.draw_triangles(&transform.matrix, &positions, triangle, |_| {
+                            Some(color)
+                        });
+                    }
+                }
+                DrawType::Gradient(gradient) => {
+                    self.draw_triangles(&transform.matrix, &positions, indices, |position| {
+                        let uv = apply_uv_matrix(&gradient.matrix, position);
+                        let color = sample_gradient(gradient, uv);
+                        Some(apply_color_transform(color, &color_transform))
+                    });
+                }
+                DrawType::Bitmap {
+                    matrix,
+                    handle,
+                    is_smoothed,
+                    is_repeating,
+                } => {
+                    let texture = match handle.as_ref().and_then(|handle| as_bitmap(handle).ok()) {
+                        Some(bitmap) => bitmap.0.borrow(),
+                        None => continue,
+                    };
+                    self.draw_triangles(&transform.matrix, &positions, indices, |position| {
+                        let uv = apply_uv_matrix(matrix, position);
+                        let color = sample(&texture, uv, *is_smoothed, *is_repeating);
+                        Some(transform_premultiplied(color, &color_transform))
+                    });
+                }
+            }
+        }
+    }
+
+    fn draw_rect(&mut self, color: Color, matrix: Matrix) {
+        let color = premultiply(color_to_rgba(&color));
+        self.draw_quad(&matrix, |_| Some(color));
+    }
+
+    fn draw_triangles(&mut self, mesh: TriangleMesh, transform: Transform) {
+        let color_transform = transform.color_transform;
+        match mesh.fill {
+            MeshFill::Color(color) => {
+                let color = apply_color_transform(color_to_rgba(&color), &color_transform);
+                self.draw_triangles(&transform.matrix, &mesh.vertices, &mesh.indices, |_| {
+                    Some(color)
+                });
+            }
+            MeshFill::Bitmap {
+                bitmap,
+                smoothing,
+                repeating,
+            } => {
+                let (texture, uvs) = match (as_bitmap(&bitmap), &mesh.uvs) {
+                    (Ok(bitmap), Some(uvs)) => (bitmap.0.borrow(), uvs),
+                    _ => return,
+                };
+                for triangle in mesh.indices.chunks_exact(3) {
+                    let corner = |i: u32| {
+                        let position = mesh.vertices.get(i as usize)?;
+                        let uv = uvs.get(i as usize)?;
+                        Some((*position, *uv))
+                    };
+                    let corners = match (
+                        corner(triangle[0]),
+                        corner(triangle[1]),
+                        corner(triangle[2]),
+                    ) {
+                        (Some(a), Some(b), Some(c)) => [a, b, c],
+                        _ => continue,
+                    };
+                    // Texture coordinates are interpolated linearly across each triangle.
+                    let positions = corners.map(|(position, _)| position);
+                    self.draw_triangles(&transform.matrix, &positions, &[0, 1, 2], |position| {
+                        let weights = barycentric(&positions, position)?;
+                        let mut uv = (0.0, 0.0);
+                        for (weight, (_, corner_uv)) in weights.iter().zip(&corners) {
+                            uv.0 += weight * corner_uv.0;
+                            uv.1 += weight * corner_uv.1;
+                        }
+                        let color = sample(&texture, uv, smoothing, repeating);
+                        Some(transform_premultiplied(color, &color_transform))
+                    });
+                }
+            }
+        }
+    }
+
+    fn push_mask(&mut self) {
+        let len = self.canvas.width() as usize * self.canvas.height() as usize;
+        self.masks.push(vec![false; len]);
+        self.mask_state = MaskState::DrawMask;
+    }
+
+    fn activate_mask(&mut self) {
+        self.mask_state = MaskState::DrawContent;
+    }
+
+    fn deactivate_mask(&mut self) {
+        self.mask_state = MaskState::ClearMask;
+    }
+
+    fn pop_mask(&mut self) {
+        self.masks.pop();
+        self.mask_state = MaskState::DrawContent;
+    }
+
+    fn blend(&mut self, commands: CommandList, blend_mode: BlendMode) {
+        let mut layer = Canvas::new(self.canvas.width(), self.canvas.height());
+        let mut frame = Frame::new(&mut layer, self.meshes);
+        frame.masks = self.masks[..self.num_active_masks()].to_vec();
+        commands.execute(&mut frame);
+        self.canvas.composite(&layer, blend_mode);
+    }
+
+    fn apply_filters(&mut self, commands: CommandList, filters: Vec<Filter>) {
+        let mut layer = Canvas::new(self.canvas.width(), self.canvas.height());
+        let mut frame = Frame::new(&mut layer, self.meshes);
+        frame.masks = self.masks[..self.num_active_masks()].to_vec();
+        commands.execute(&mut frame);
+        for filter in &filters {
+            match filter {
+                Filter::BlurFilter(blur) => {
+                    // Each pass is a box blur whose window is `blur_x` by `blur_y` pixels.
+                    let radius_x = (blur.blur_x.to_f64() / 2.0).max(0.0) as u32;
+                    let radius_y = (blur.blur_y.to_f64() / 2.0).max(0.0) as u32;
+                    for _ in 0..blur.num_passes {
+                        layer.box_blur(radius_x, radius_y);
+                    }
+                }
+                // TODO: Implement the remaining filters.
+                _ => {}
+            }
+        }
+        self.canvas.composite(&layer, BlendMode::Normal);
+    }
+}
+
+fn color_to_rgba(color: &Color) -> [f32; 4] {
+    [
+        f32::from(color.r) / 255.0,
+        f32::from(color.g) / 255.0,
+        f32::from(color.b) / 255.0,
+        f32::from(color.a) / 255.0,
+    ]
+}
+
+fn premultiply([r, g, b, a]: [f32; 4]) -> Pixel {
+    [r * a, g * a, b * a, a]
+}
+
+/// Applies a color transform to a straight color, returning a premultiplied color.
+fn apply_color_transform(color: [f32; 4], color_transform: &ColorTransform) -> Pixel {
+    let mult = color_transform.mult_rgba_normalized();
+    let add = color_transform.add_rgba_normalized();
+    let mut out = [0.0; 4];
+    for (((out, color), mult), add) in out.iter_mut().zip(color).zip(mult).zip(add) {
+        *out = (color * mult + add).clamp(0.0, 1.0);
+    }
+    premultiply(out)
+}
+
+/// Applies a color transform to a premultiplied color.
+fn transform_premultiplied(color: Pixel, color_transform: &ColorTransform) -> Pixel {
+    let [r, g, b, a] = color;
+    if a <= 0.0 {
+        return color;
+    }
+    apply_color_transform([r / a, g / a, b / a, a], color_transform)
+}
+
+fn apply_uv_matrix(matrix: &[[f32; 3]; 3], (x, y): (f32, f32)) -> (f32, f32) {
+    (
+        matrix[0][0] * x + matrix[1][0] * y + matrix[2][0],
+        matrix[0][1] * x + matrix[1][1] * y + matrix[2][1],
+    )
+}
+
+/// Returns the barycentric weights of `point` within `triangle`.
+fn barycentric(triangle: &[(f32, f32); 3], (x, y): (f32, f32)) -> Option<[f32; 3]> {
+    let [(x0, y0), (x1, y1), (x2, y2)] = *triangle;
+    let area = (x1 - x0) * (y2 - y0) - (x2 - x0) * (y1 - y0);
+    if area == 0.0 {
+        return None;
+    }
+    let w1 = ((x - x0) * (y2 - y0) - (x2 - x0) * (y - y0)) / area;
+    let w2 = ((x1 - x0) * (y - y0) - (x - x0) * (y1 - y0)) / area;
+    Some([1.0 - w1 - w2, w1, w2])
+}
+
+/// Samples a texture at the given normalized coordinates.
+fn sample(texture: &Canvas, (u, v): (f32, f32), smoothing: bool, repeating: bool) -> Pixel {
+    let (width, height) = (texture.width(), texture.height());
+    if width == 0 || height == 0 {
+        return [0.0; 4];
+    }
+    let wrap = |n: i64, size: u32| -> u32 {
+        if repeating {
+            n.rem_euclid(size.into()) as u32
+        } else {
+            n.clamp(0, i64::from(size) - 1) as u32
+        }
+    };
+    let x = u * width as f32;
+    let y = v * height as f32;
+    if !smoothing {
+        return texture.get(
+            wrap(x.floor() as i64, width),
+            wrap(y.floor() as i64, height),
+        );
+    }
+
+    let (x, y) = (x - 0.5, y - 0.5);
+    let (x0, y0) = (x.floor(), y.floor());
+    let (fx, fy) = (x - x0, y - y0);
+    let (x0, y0) = (x0 as i64, y0 as i64);
+    let corners = [
+        (
+            texture.get(wrap(x0, width), wrap(y0, height)),
+            (1.0 - fx) * (1.0 - fy),
+        ),
+        (
+            texture.get(wrap(x0 + 1, width), wrap(y0, height)),
+            fx * (1.0 - fy),
+        ),
+        (
+            texture.get(wrap(x0, width), wrap(y0 + 1, height)),
+            (1.0 - fx) * fy,
+        ),
+        (
+            texture.get(wrap(x0 + 1, width), wrap(y0 + 1, height)),
+            fx * fy,
+        ),
+    ];
+    let mut out = [0.0; 4];
+    for (color, weight) in corners {
+        for (out, c) in out.iter_mut().zip(color) {
+            *out += c * weight;
+        }
+    }
+    out
+}
+
+/// Finds the straight color of a gradient at the given coordinates,
+/// mirroring the gradient shaders of the GPU backends.
+fn sample_gradient(gradient: &Gradient, (u, v): (f32, f32)) -> [f32; 4] {
+    let num_colors = gradient.num_colors.min(gradient.colors.len());
+    if num_colors == 0 {
+        return [0.0; 4];
+    }
+
+    let mut t = match gradient.gradient_type {
+        GradientType::Linear => u,
+        GradientType::Radial => {
+            let (x, y) = (u * 2.0 - 1.0, v * 2.0 - 1.0);
+            (x * x + y * y).sqrt()
+        }
+        GradientType::Focal => {
+            let focal_point = gradient.focal_point.to_f32();
+            let (x, y) = (u * 2.0 - 1.0, v * 2.0 - 1.0);
+            let (dx, dy) = (focal_point - x, -y);
+            let l = (dx * dx + dy * dy).sqrt();
+            if l > 0.0 {
+                let (dx, dy) = (dx / l, dy / l);
+                l / ((1.0 - focal_point * focal_point * dy * dy).sqrt() + focal_point * dx)
+            } else {
+                0.0
+            }
+        }
+    };
+    t = match gradient.repeat_mode {
+        swf::GradientSpread::Reflect => {
+            let t = t.abs();
+            if (t as i32) & 1 == 0 {
+                t.fract()
+            } else {
+                1.0 - t.fract()
+            }
+        }
+        swf::GradientSpread::Repeat => t.rem_euclid(1.0),
+        swf::GradientSpread::Pad => t.clamp(0.0, 1.0),
+    };
+
+    let ratios = &gradient.ratios[..num_colors];
+    let colors = &gradient.colors[..num_colors];
+    t = t.clamp(ratios[0], ratios[num_colors - 1]);
+    let j = ratios
+        .iter()
+        .position(|&ratio| t <= ratio)
+        .unwrap_or(num_colors - 1)
+        .max(1)
+        .min(num_colors - 1);
+    let i = j.saturating_sub(1);
+    let span = ratios[j] - ratios[i];
+    let a = if span > 0.0 {
+        (t - ratios[i]) / span
+    } else {
+        0.0
+    };
+    let mut color = [0.0; 4];
+    for ((out, from), to) in color.iter_mut().zip(colors[i]).zip(colors[j]) {
+        *out = from + (to - from) * a;
+    }
+    if gradient.interpolation == swf::GradientInterpolation::LinearRgb {
+        for n in &mut color[..3] {
+            *n = if *n <= 0.0031308 {
+                *n * 12.92
+            } else {
+                1.055 * n.powf(1.0 / 2.4) - 0.055
+            };
+        }
+    }
+    color
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ruffle_render::shape_utils::{DrawCommand, DrawPath};
+    use swf::Twips;
+
+    const RED: Color = Color::from_rgb(0xFF0000, 255);
+    const WHITE_PIXEL: image::Rgba<u8> = image::Rgba([255, 255, 255, 255]);
+    const RED_PIXEL: image::Rgba<u8> = image::Rgba([255, 0, 0, 255]);
+
+    fn point(x: f32, y: f32) -> (Twips, Twips) {
+        (Twips::from_pixels(x.into()), Twips::from_pixels(y.into()))
+    }
+
+    fn rectangle(x0: f32, y0: f32, x1: f32, y1: f32) -> Vec<DrawCommand> {
+        let mut commands = vec![];
+        for (i, (x, y)) in [(x0, y0), (x1, y0), (x1, y1), (x0, y1), (x0, y0)]
+            .into_iter()
+            .enumerate()
+        {
+            let (x, y) = point(x, y);
+            commands.push(if i == 0 {
+                DrawCommand::MoveTo { x, y }
+            } else {
+                DrawCommand::LineTo { x, y }
+            });
+        }
+        commands
+    }
+
+    /// Builds a circle out of eight quadratic curves.
+    fn circle(cx: f32, cy: f32, radius: f32) -> Vec<DrawCommand> {
+        use std::f32::consts::FRAC_PI_4;
+        let control_radius = radius / (FRAC_PI_4 / 2.0).cos();
+        let (x, y) = point(cx + radius, cy);
+        let mut commands = vec![DrawCommand::MoveTo { x, y }];
+        for i in 0..8 {
+            let control_angle = (i as f32 + 0.5) * FRAC_PI_4;
+            let angle = (i + 1) as f32 * FRAC_PI_4;
+            let (x1, y1) = point(
+                cx + control_radius * control_angle.cos(),
+                cy + control_radius * control_angle.sin(),
+            );
+            let (x2, y2) = point(cx + radius * angle.cos(), cy + radius * angle.sin());
+            commands.push(DrawCommand::CurveTo { x1, y1, x2, y2 });
+        }
+        commands
+    }
+
+    fn register_fill(
+        renderer: &mut SoftwareRenderBackend,
+        color: Color,
+        commands: Vec<DrawCommand>,
+    ) -> ShapeHandle {
+        let fill = swf::FillStyle::Color(color);
+        let shape = DistilledShape {
+            paths: vec![DrawPath::Fill {
+                style: &fill,
+                commands,
+            }],
+            shape_bounds: Default::default(),
+            edge_bounds: Default::default(),
+            id: 1,
+        };
+        renderer.register_shape(shape, &NullBitmapSource)
+    }
+
+    #[test]
+    fn render_solid_shape() {
+        let mut renderer = SoftwareRenderBackend::new(8, 6);
+        let handle = register_fill(&mut renderer, RED, rectangle(2.0, 1.0, 6.0, 4.0));
+
+        // Offset the shape by one pixel to the right and draw it at half opacity.
+        let mut commands = CommandList::new();
+        commands.render_shape(
+            handle,
+            Transform {
+                matrix: Matrix::translate(Twips::from_pixels(1.0), Twips::ZERO),
+                color_transform: ColorTransform {
+                    a_mult: swf::Fixed8::from_f32(0.5),
+                    ..Default::default()
+                },
+            },
+        );
+        renderer.submit_frame(Color::WHITE, commands);
+
+        let mut reference = RgbaImage::from_pixel(8, 6, WHITE_PIXEL);
+        for y in 1..4 {
+            for x in 3..7 {
+                reference.put_pixel(x, y, image::Rgba([255, 128, 128, 255]));
+            }
+        }
+        assert_eq!(renderer.capture_frame(), reference);
+    }
+
+    #[test]
+    fn render_masked_shape() {
+        let mut renderer = SoftwareRenderBackend::new(32, 32);
+        let rect = register_fill(&mut renderer, RED, rectangle(0.0, 0.0, 32.0, 32.0));
+        let mask = register_fill(&mut renderer, Color::BLACK, circle(16.0, 16.0, 6.0));
+        let left_half = register_fill(&mut renderer, Color::BLACK, rectangle(0.0, 0.0, 16.0, 32.0));
+
+        // A red rectangle covering the whole stage, masked by a small circle.
+        let mut commands = CommandList::new();
+        commands.push_mask();
+        commands.render_shape(mask, Transform::default());
+        commands.activate_mask();
+        commands.render_shape(rect, Transform::default());
+        commands.deactivate_mask();
+        commands.render_shape(mask, Transform::default());
+        commands.pop_mask();
+        renderer.submit_frame(Color::WHITE, commands);
+
+        let image = renderer.capture_frame();
+        for (x, y, pixel) in image.enumerate_pixels() {
+            let distance =
+                ((x as f32 + 0.5 - 16.0).powi(2) + (y as f32 + 0.5 - 16.0).powi(2)).sqrt();
+            if distance < 5.5 {
+                assert_eq!(*pixel, RED_PIXEL, "inside the mask at ({x}, {y})");
+            } else if distance > 6.5 {
+                assert_eq!(*pixel, WHITE_PIXEL, "outside the mask at ({x}, {y})");
+            }
+        }
+
+        // Nested masks only show content inside both of them.
+        let mut commands = CommandList::new();
+        commands.push_mask();
+        commands.render_shape(mask, Transform::default());
+        commands.activate_mask();
+        commands.push_mask();
+        commands.render_shape(left_half, Transform::default());
+        commands.activate_mask();
+        commands.render_shape(rect, Transform::default());
+        commands.deactivate_mask();
+        commands.render_shape(left_half, Transform::default());
+        commands.pop_mask();
+        commands.deactivate_mask();
+        commands.render_shape(mask, Transform::default());
+        commands.pop_mask();
+        renderer.submit_frame(Color::WHITE, commands);
+
+        let image = renderer.capture_frame();
+        assert_eq!(*image.get_pixel(13, 16), RED_PIXEL);
+        assert_eq!(*image.get_pixel(18, 16), WHITE_PIXEL);
+        assert_eq!(*image.get_pixel(2, 16), WHITE_PIXEL);
+    }
+
+    #[test]
+    fn render_blurred_shape() {
+        let mut renderer = SoftwareRenderBackend::new(9, 3);
+        let line = register_fill(&mut renderer, RED, rectangle(4.0, 0.0, 5.0, 3.0));
+
+        // A three pixel wide blur spreads the one pixel wide line across its neighbors.
+        let mut filtered = CommandList::new();
+        filtered.render_shape(line, Transform::default());
+        let mut commands = CommandList::new();
+        commands.apply_filters(
+            filtered,
+            vec![Filter::BlurFilter(Box::new(swf::BlurFilter {
+                blur_x: swf::Fixed16::from_f64(3.0),
+                blur_y: swf::Fixed16::ZERO,
+                num_passes: 1,
+            }))],
+        );
+        renderer.submit_frame(Color::WHITE, commands);
+
+        let image = renderer.capture_frame();
+        let blurred = image::Rgba([255, 170, 170, 255]);
+        for y in 0..3 {
+            assert_eq!(*image.get_pixel(2, y), WHITE_PIXEL);
+            assert_eq!(*image.get_pixel(3, y), blurred);
+            assert_eq!(*image.get_pixel(4, y), blurred);
+            assert_eq!(*image.get_pixel(5, y), blurred);
+            assert_eq!(*image.get_pixel(6, y), WHITE_PIXEL);
+        }
+    }
+
+    #[test]
+    fn read_back_offscreen_pixels() {
+        let mut renderer = SoftwareRenderBackend::new(1, 1);
+        let white = [255; 4];
+        let bitmap = renderer
+            .register_bitmap(Bitmap::new(4, 2, BitmapFormat::Rgba, white.repeat(8)))
+            .expect("Failed to register bitmap");
+        let pixels = renderer
+            .get_offscreen_pixels(&bitmap)
+            .expect("Failed to read bitmap");
+        assert_eq!((pixels.width(), pixels.height()), (4, 2));
+        assert_eq!(pixels.data(), &white.repeat(8)[..]);
+
+        // Pixels drawn offscreen are read back without going through the sync handle.
+        let handle = register_fill(&mut renderer, RED, rectangle(0.0, 0.0, 2.0, 2.0));
+        let mut commands = CommandList::new();
+        commands.render_shape(handle, Transform::default());
+        renderer
+            .render_offscreen(bitmap.clone(), 4, 2, commands)
+            .expect("Failed to render offscreen");
+        let pixels = renderer
+            .get_offscreen_pixels(&bitmap)
+            .expect("Failed to read bitmap");
+        let red = [255, 0, 0, 255];
+        assert_eq!(
+            pixels.data(),
+            &[red, red, white, white, red, red, white, white].concat()[..]
+        );
+    }
+
+    #[test]
+    fn render_offscreen_to_requested_size() {
+        let mut renderer = SoftwareRenderBackend::new(1, 1);
+        let white = [255; 4];
+        let bitmap = renderer
+            .register_bitmap(Bitmap::new(2, 1, BitmapFormat::Rgba, white.repeat(2)))
+            .expect("Failed to register bitmap");
+
+        let handle = register_fill(&mut renderer, RED, rectangle(1.0, 0.0, 3.0, 2.0));
+        let mut commands = CommandList::new();
+        commands.render_shape(handle, Transform::default());
+        renderer
+            .render_offscreen(bitmap.clone(), 3, 2, commands)
+            .expect("Failed to render offscreen");
+        let pixels = renderer
+            .get_offscreen_pixels(&bitmap)
+            .expect("Failed to read bitmap");
+        assert_eq!((pixels.width(), pixels.height()), (3, 2));
+        let (red, clear) = ([255, 0, 0, 255], [0; 4]);
+        assert_eq!(
+            pixels.data(),
+            &[white, red, red, clear, red, red].concat()[..]
+        );
+    }
+
+    #[test]
+    fn render_textured_triangles() {
+        let mut renderer = SoftwareRenderBackend::new(4, 2);
+        let blue = [0, 0, 255, 255];
+        let texture = Bitmap::new(2, 1, BitmapFormat::Rgba, [[255, 0, 0, 255], blue].concat());
+        let bitmap = renderer
+            .register_bitmap(texture)
+            .expect("Failed to register bitmap");
+
+        // A quad made of two triangles, with the texture stretched over it.
+        let mut commands = CommandList::new();
+        commands.draw_triangles(
+            TriangleMesh {
+                vertices: vec![(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (0.0, 2.0)],
+                indices: vec![0, 1, 2, 0, 2, 3],
+                uvs: Some(vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]),
+                fill: MeshFill::Bitmap {
+                    bitmap,
+                    smoothing: false,
+                    repeating: false,
+                },
+            },
+            Transform::default(),
+        );
+        renderer.submit_frame(Color::WHITE, commands);
+
+        let mut reference = RgbaImage::from_pixel(4, 2, RED_PIXEL);
+        for y in 0..2 {
+            for x in 2..4 {
+                reference.put_pixel(x, y, image::Rgba(blue));
+            }
+        }
+        assert_eq!(renderer.capture_frame(), reference);
+    }
+
+    #[test]
+    fn render_tinted_translucent_bitmap() {
+        let mut renderer = SoftwareRenderBackend::new(4, 1);
+        // A white gradient fading out from left to right, with premultiplied alpha.
+        let data = [255, 170, 85, 0]
+            .iter()
+            .flat_map(|&a| [a, a, a, a])
+            .collect();
+        let bitmap = renderer
+            .register_bitmap(Bitmap::new(4, 1, BitmapFormat::Rgba, data))
+            .expect("Failed to register bitmap");
+
+        // Tint the bitmap red: the partially transparent pixels must blend
+        // towards the background rather than darkening into a halo.
+        let mut commands = CommandList::new();
+        commands.render_bitmap(
+            bitmap,
+            Transform {
+                matrix: Matrix::default(),
+                color_transform: ColorTransform {
+                    g_mult: swf::Fixed8::ZERO,
+                    b_mult: swf::Fixed8::ZERO,
+                    r_add: 255,
+                    ..Default::default()
+                },
+            },
+            false,
+        );
+        renderer.submit_frame(Color::WHITE, commands);
+
+        let mut reference = RgbaImage::new(4, 1);
+        for (x, gb) in [0, 85, 170, 255].into_iter().enumerate() {
+            reference.put_pixel(x as u32, 0, image::Rgba([255, gb, gb, 255]));
+        }
+        assert_eq!(renderer.capture_frame(), reference);
+    }
+}
@@ -0,0 +1,288 @@
+use image::RgbaImage;
+use ruffle_render::bitmap::{Bitmap, BitmapFormat};
+use swf::BlendMode;
+
+/// A premultiplied RGBA color, with each component in the range [0.0, 1.0].
+pub type Pixel = [f32; 4];
+
+/// A buffer of premultiplied pixels that can be drawn to.
+#[derive(Clone, Debug)]
+pub struct Canvas {
+    width: u32,
+    height: u32,
+    pixels: Vec<Pixel>,
+}
+
+impl Canvas {
+    /// Creates a fully transparent canvas.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![[0.0; 4]; width as usize * height as usize],
+        }
+    }
+
+    pub fn from_bitmap(bitmap: Bitmap) -> Self {
+        let bitmap = bitmap.to_rgba();
+        Self {
+            width: bitmap.width(),
+            height: bitmap.height(),
+            pixels: bitmap
+                .data()
+                .chunks_exact(4)
+                .map(|rgba| [rgba[0], rgba[1], rgba[2], rgba[3]].map(|n| f32::from(n) / 255.0))
+                .collect(),
+        }
+    }
+
+    /// Converts the canvas to a bitmap with premultiplied alpha.
+    pub fn to_bitmap(&self) -> Bitmap {
+        let data = self
+            .pixels
+            .iter()
+            .flat_map(|pixel| pixel.map(to_u8))
+            .collect();
+        Bitmap::new(self.width, self.height, BitmapFormat::Rgba, data)
+    }
+
+    /// Converts the canvas to an image with straight alpha.
+    pub fn to_image(&self) -> RgbaImage {
+        let data = self
+            .pixels
+            .iter()
+            .flat_map(|&[r, g, b, a]| {
+                if a > 0.0 {
+                    [to_u8(r / a), to_u8(g / a), to_u8(b / a), to_u8(a)]
+                } else {
+                    [0, 0, 0, 0]
+                }
+            })
+            .collect();
+        RgbaImage::from_raw(self.width, self.height, data)
+            .unwrap_or_else(|| RgbaImage::new(self.width, self.height))
+    }
+
+    /// Copies this canvas into a new canvas of the given size.
+    ///
+    /// Pixels outside of this canvas are transparent, and pixels outside of the new size are dropped.
+    pub fn resized(&self, width: u32, height: u32) -> Self {
+        let mut canvas = Self::new(width, height);
+        let copy_width = width.min(self.width) as usize;
+        for y in 0..height.min(self.height) as usize {
+            let src = y * self.width as usize;
+            let dst = y * width as usize;
+            canvas.pixels[dst..dst + copy_width]
+                .copy_from_slice(&self.pixels[src..src + copy_width]);
+        }
+        canvas
+    }
+
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn clear(&mut self, color: Pixel) {
+        self.pixels.fill(color);
+    }
+
+    #[inline]
+    pub fn get(&self, x: u32, y: u32) -> Pixel {
+        self.pixels[self.index(x, y)]
+    }
+
+    /// Composites `color` over the pixel at the given position.
+    #[inline]
+    pub fn blend(&mut self, x: u32, y: u32, color: Pixel) {
+        let i = self.index(x, y);
+        let dst = &mut self.pixels[i];
+        let inv_alpha = 1.0 - color[3];
+        for (dst, src) in dst.iter_mut().zip(color) {
+            *dst = src + *dst * inv_alpha;
+        }
+    }
+
+    /// Composites all of `layer` over this canvas using the given blend mode.
+    pub fn composite(&mut self, layer: &Canvas, blend_mode: BlendMode) {
+        for (dst, &src) in self.pixels.iter_mut().zip(&layer.pixels) {
+            if src[3] <= 0.0 {
+                continue;
+            }
+            let blended = match blend_mode {
+                BlendMode::Multiply => separable(*dst, src, |s, d| s * d),
+                BlendMode::Screen => separable(*dst, src, |s, d| s + d - s * d),
+                BlendMode::Lighten => separable(*dst, src, f32::max),
+                BlendMode::Darken => separable(*dst, src, f32::min),
+                BlendMode::Difference => separable(*dst, src, |s, d| (s - d).abs()),
+                BlendMode::Add => {
+                    let mut out = *dst;
+                    for (out, src) in out.iter_mut().zip(src) {
+                        *out = (*out + src).min(1.0);
+                    }
+                    *dst = out;
+                    continue;
+                }
+                BlendMode::Subtract => {
+                    let mut out = *dst;
+                    for (out, src) in out[..3].iter_mut().zip(src) {
+                        *out = (*out - src).max(0.0);
+                    }
+                    *dst = out;
+                    continue;
+                }
+                _ => src,
+            };
+            let inv_alpha = 1.0 - src[3];
+            for (dst, blended) in dst.iter_mut().zip(blended) {
+                *dst = blended + *dst * inv_alpha;
+            }
+        }
+    }
+
+    /// Applies a box blur that averages each pixel with `radius_x` pixels to either side,
+    /// then `radius_y` pixels above and below. Pixels outside the canvas count as transparent.
+    pub fn box_blur(&mut self, radius_x: u32, radius_y: u32) {
+        let (width, height) = (self.width as usize, self.height as usize);
+        if self.pixels.is_empty() {
+            return;
+        }
+        if radius_x > 0 {
+            for row in self.pixels.chunks_exact_mut(width) {
+                box_blur_line(row, 1, width, radius_x as usize);
+            }
+        }
+        if radius_y > 0 {
+            for x in 0..width {
+                box_blur_line(&mut self.pixels[x..], width, height, radius_y as usize);
+            }
+        }
+    }
+
+    #[inline]
+    fn index(&self, x: u32, y: u32) -> usize {
+        y as usize * self.width as usize + x as usize
+    }
+}
+
+/// Applies a separable blend function to premultiplied colors.
+/// The result is the source color to composite over the destination.
+fn separable(dst: Pixel, src: Pixel, f: impl Fn(f32, f32) -> f32) -> Pixel {
+    let straight = |c: Pixel| {
+        if c[3] > 0.0 {
+            [c[0] / c[3], c[1] / c[3], c[2] / c[3]]
+        } else {
+            [0.0; 3]
+        }
+    };
+    let mut out = [0.0, 0.0, 0.0, src[3]];
+    for ((out, s), d) in out.iter_mut().zip(straight(src)).zip(straight(dst)) {
+        // Where the destination is transparent, the source shows through unchanged.
+        let mixed = (1.0 - dst[3]) * s + dst[3] * f(s, d).clamp(0.0, 1.0);
+        *out = mixed * src[3];
+    }
+    out
+}
+
+/// Box blurs `len` pixels spaced `stride` apart, using a running sum over the window.
+fn box_blur_line(pixels: &mut [Pixel], stride: usize, len: usize, radius: usize) {
+    let line: Vec<Pixel> = (0..len).map(|i| pixels[i * stride]).collect();
+    let window = (radius * 2 + 1) as f32;
+    let mut sum = [0.0; 4];
+    for pixel in line.iter().take(radius) {
+        for (sum, n) in sum.iter_mut().zip(pixel) {
+            *sum += n;
+        }
+    }
+    for i in 0..len {
+        if let Some(entering) = line.get(i + radius) {
+            for (sum, n) in sum.iter_mut().zip(entering) {
+                *sum += n;
+            }
+        }
+        pixels[i * stride] = sum.map(|n| n / window);
+        if let Some(leaving) = i.checked_sub(radius).and_then(|i| line.get(i)) {
+            for (sum, n) in sum.iter_mut().zip(leaving) {
+                *sum -= n;
+            }
+        }
+    }
+}
+
+fn to_u8(n: f32) -> u8 {
+    (n.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Calls `f` for every pixel whose center lies inside the given triangle, along with
+/// the barycentric weights of the pixel center.
+///
+/// Pixels on an edge shared between two triangles are only visited once, so that
+/// translucent meshes don't blend twice along their seams.
+pub fn rasterize_triangle(
+    width: u32,
+    height: u32,
+    mut vertices: [(f32, f32); 3],
+    mut f: impl FnMut(u32, u32, [f32; 3]),
+) {
+    let mut area = edge(vertices[0], vertices[1], vertices[2]);
+    if area == 0.0 || !area.is_finite() {
+        return;
+    }
+    let mut order = [0, 1, 2];
+    if area < 0.0 {
+        vertices.swap(1, 2);
+        order.swap(1, 2);
+        area = -area;
+    }
+
+    let min_x = vertices.iter().map(|v| v.0).fold(f32::INFINITY, f32::min);
+    let max_x = vertices
+        .iter()
+        .map(|v| v.0)
+        .fold(f32::NEG_INFINITY, f32::max);
+    let min_y = vertices.iter().map(|v| v.1).fold(f32::INFINITY, f32::min);
+    let max_y = vertices
+        .iter()
+        .map(|v| v.1)
+        .fold(f32::NEG_INFINITY, f32::max);
+    let x_range = (min_x - 0.5).ceil().max(0.0) as u32
+        ..((max_x - 0.5).floor() + 1.0).clamp(0.0, width as f32) as u32;
+    let y_range = (min_y - 0.5).ceil().max(0.0) as u32
+        ..((max_y - 0.5).floor() + 1.0).clamp(0.0, height as f32) as u32;
+
+    let [v0, v1, v2] = vertices;
+    for y in y_range {
+        for x in x_range.clone() {
+            let p = (x as f32 + 0.5, y as f32 + 0.5);
+            let w = [edge(v1, v2, p), edge(v2, v0, p), edge(v0, v1, p)];
+            let inside = [(v1, v2), (v2, v0), (v0, v1)]
+                .iter()
+                .zip(w)
+                .all(|(&(a, b), w)| w > 0.0 || (w == 0.0 && owns_edge(a, b)));
+            if inside {
+                let mut weights = [0.0; 3];
+                for (i, w) in order.into_iter().zip(w) {
+                    weights[i] = w / area;
+                }
+                f(x, y, weights);
+            }
+        }
+    }
+}
+
+fn edge(a: (f32, f32), b: (f32, f32), p: (f32, f32)) -> f32 {
+    (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0)
+}
+
+/// Decides which of two triangles owns a pixel lying exactly on their shared edge.
+/// Neighbouring triangles traverse the edge in opposite directions, so exactly one
+/// of them will own it.
+fn owns_edge(a: (f32, f32), b: (f32, f32)) -> bool {
+    let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+    dy > 0.0 || (dy == 0.0 && dx < 0.0)
+}
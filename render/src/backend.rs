@@ -3,6 +3,7 @@ pub mod null;
 use crate::bitmap::{Bitmap, BitmapHandle, BitmapSource, SyncHandle};
 use crate::commands::CommandList;
 use crate::error::Error;
+use crate::quality::RenderQuality;
 use crate::shape_utils::DistilledShape;
 use downcast_rs::{impl_downcast, Downcast};
 use gc_arena::{Collect, GcCell, MutationContext};
@@ -48,6 +49,13 @@ pub trait RenderBackend: Downcast {
     /// Retrieves the rendered pixels from a previous `render_offscreen` call
     fn retrieve_offscreen_texture(&self, sync: Box<dyn SyncHandle>) -> Result<Bitmap, Error>;
 
+    /// Reads back the current pixels of the texture specified by `BitmapHandle`,
+    /// with premultiplied alpha.
+    ///
+    /// Unlike `retrieve_offscreen_texture`, this does not need a prior
+    /// `render_offscreen` call, and can read any registered bitmap.
+    fn get_offscreen_pixels(&self, handle: &BitmapHandle) -> Result<Bitmap, Error>;
+
     fn submit_frame(&mut self, clear: swf::Color, commands: CommandList);
 
     fn register_bitmap(&mut self, bitmap: Bitmap) -> Result<BitmapHandle, Error>;
@@ -68,6 +76,12 @@ pub trait RenderBackend: Downcast {
     ) -> Result<(), Error>;
 
     fn debug_info(&self) -> Cow<'static, str>;
+
+    /// Sets the quality at which content should be rendered.
+    ///
+    /// Shapes registered after this call will be tessellated using the new quality.
+    /// Backends that have no control over their rendering quality can ignore this.
+    fn set_quality(&mut self, _quality: RenderQuality) {}
 }
 impl_downcast!(RenderBackend);
 
@@ -74,6 +74,10 @@ impl RenderBackend for NullRenderer {
         Err(Error::Unimplemented)
     }
 
+    fn get_offscreen_pixels(&self, _handle: &BitmapHandle) -> Result<Bitmap, Error> {
+        Err(Error::Unimplemented)
+    }
+
     fn submit_frame(&mut self, _clear: Color, _commands: CommandList) {}
     fn register_bitmap(&mut self, _bitmap: Bitmap) -> Result<BitmapHandle, Error> {
         Ok(BitmapHandle(Arc::new(NullBitmapHandle)))
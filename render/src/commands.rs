@@ -2,18 +2,23 @@ use crate::backend::ShapeHandle;
 use crate::bitmap::BitmapHandle;
 use crate::matrix::Matrix;
 use crate::transform::Transform;
-use swf::{BlendMode, Color};
+use std::fmt;
+use swf::{BlendMode, Color, Filter};
 
 pub trait CommandHandler {
     fn render_bitmap(&mut self, bitmap: BitmapHandle, transform: Transform, smoothing: bool);
     fn render_shape(&mut self, shape: ShapeHandle, transform: Transform);
     fn draw_rect(&mut self, color: Color, matrix: Matrix);
+    fn draw_triangles(&mut self, mesh: TriangleMesh, transform: Transform);
     fn push_mask(&mut self);
     fn activate_mask(&mut self);
     fn deactivate_mask(&mut self);
     fn pop_mask(&mut self);
 
     fn blend(&mut self, commands: CommandList, blend_mode: BlendMode);
+
+    /// Draws `commands` with each of `filters` applied, in order, to the result.
+    fn apply_filters(&mut self, commands: CommandList, filters: Vec<Filter>);
 }
 
 #[derive(Debug, Default, Clone)]
@@ -36,11 +41,158 @@ impl CommandList {
                 } => handler.render_bitmap(bitmap, transform, smoothing),
                 Command::RenderShape { shape, transform } => handler.render_shape(shape, transform),
                 Command::DrawRect { color, matrix } => handler.draw_rect(color, matrix),
+                Command::DrawTriangles { mesh, transform } => {
+                    handler.draw_triangles(mesh, transform)
+                }
                 Command::PushMask => handler.push_mask(),
                 Command::ActivateMask => handler.activate_mask(),
                 Command::DeactivateMask => handler.deactivate_mask(),
                 Command::PopMask => handler.pop_mask(),
                 Command::Blend(commands, blend_mode) => handler.blend(commands, blend_mode),
+                Command::Filters(commands, filters) => handler.apply_filters(commands, filters),
+            }
+        }
+    }
+
+    /// Counts the commands in this list by kind, including those nested inside blends and filters.
+    pub fn debug_summary(&self) -> CommandSummary {
+        let mut summary = CommandSummary::default();
+        summary.add(self);
+        summary
+    }
+
+    fn fmt_indented(&self, f: &mut fmt::Formatter<'_>, depth: usize) -> fmt::Result {
+        for command in &self.commands {
+            write!(f, "{:indent$}", "", indent = depth * 2)?;
+            match command {
+                Command::RenderBitmap {
+                    transform,
+                    smoothing,
+                    ..
+                } => {
+                    writeln!(
+                        f,
+                        "render_bitmap smoothing={smoothing} matrix={}",
+                        MatrixText(&transform.matrix)
+                    )?;
+                }
+                Command::RenderShape { shape, transform } => {
+                    writeln!(
+                        f,
+                        "render_shape {} matrix={}",
+                        shape.0,
+                        MatrixText(&transform.matrix)
+                    )?;
+                }
+                Command::DrawRect { color, matrix } => {
+                    writeln!(
+                        f,
+                        "draw_rect color=#{:02x}{:02x}{:02x}{:02x} matrix={}",
+                        color.r,
+                        color.g,
+                        color.b,
+                        color.a,
+                        MatrixText(matrix)
+                    )?;
+                }
+                Command::DrawTriangles { mesh, transform } => {
+                    let fill = match mesh.fill {
+                        MeshFill::Color(_) => "color",
+                        MeshFill::Bitmap { .. } => "bitmap",
+                    };
+                    writeln!(
+                        f,
+                        "draw_triangles fill={fill} vertices={} triangles={} uvs={} matrix={}",
+                        mesh.vertices.len(),
+                        mesh.indices.len() / 3,
+                        mesh.uvs.is_some(),
+                        MatrixText(&transform.matrix)
+                    )?;
+                }
+                Command::PushMask => writeln!(f, "push_mask")?,
+                Command::ActivateMask => writeln!(f, "activate_mask")?,
+                Command::DeactivateMask => writeln!(f, "deactivate_mask")?,
+                Command::PopMask => writeln!(f, "pop_mask")?,
+                Command::Blend(commands, blend_mode) => {
+                    writeln!(f, "blend {blend_mode:?}")?;
+                    commands.fmt_indented(f, depth + 1)?;
+                }
+                Command::Filters(commands, filters) => {
+                    let names: Vec<_> = filters.iter().map(filter_name).collect();
+                    writeln!(f, "filters [{}]", names.join(", "))?;
+                    commands.fmt_indented(f, depth + 1)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Writes a text form of the command list, one command per line.
+/// Commands inside of a blend or filter are indented below it.
+impl fmt::Display for CommandList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+fn filter_name(filter: &Filter) -> &'static str {
+    match filter {
+        Filter::DropShadowFilter(_) => "drop_shadow",
+        Filter::BlurFilter(_) => "blur",
+        Filter::GlowFilter(_) => "glow",
+        Filter::BevelFilter(_) => "bevel",
+        Filter::GradientGlowFilter(_) => "gradient_glow",
+        Filter::ConvolutionFilter(_) => "convolution",
+        Filter::ColorMatrixFilter(_) => "color_matrix",
+        Filter::GradientBevelFilter(_) => "gradient_bevel",
+    }
+}
+
+struct MatrixText<'a>(&'a Matrix);
+
+impl fmt::Display for MatrixText<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let m = self.0;
+        write!(f, "[{} {} {} {} {} {}]", m.a, m.b, m.c, m.d, m.tx, m.ty)
+    }
+}
+
+/// The number of each kind of command in a `CommandList`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CommandSummary {
+    pub bitmaps: usize,
+    pub shapes: usize,
+    pub rects: usize,
+    pub triangle_meshes: usize,
+    pub push_masks: usize,
+    pub activate_masks: usize,
+    pub deactivate_masks: usize,
+    pub pop_masks: usize,
+    pub blends: usize,
+    pub filters: usize,
+}
+
+impl CommandSummary {
+    fn add(&mut self, commands: &CommandList) {
+        for command in &commands.commands {
+            match command {
+                Command::RenderBitmap { .. } => self.bitmaps += 1,
+                Command::RenderShape { .. } => self.shapes += 1,
+                Command::DrawRect { .. } => self.rects += 1,
+                Command::DrawTriangles { .. } => self.triangle_meshes += 1,
+                Command::PushMask => self.push_masks += 1,
+                Command::ActivateMask => self.activate_masks += 1,
+                Command::DeactivateMask => self.deactivate_masks += 1,
+                Command::PopMask => self.pop_masks += 1,
+                Command::Blend(commands, _) => {
+                    self.blends += 1;
+                    self.add(commands);
+                }
+                Command::Filters(commands, _) => {
+                    self.filters += 1;
+                    self.add(commands);
+                }
             }
         }
     }
@@ -64,6 +216,11 @@ impl CommandHandler for CommandList {
         self.commands.push(Command::DrawRect { color, matrix });
     }
 
+    fn draw_triangles(&mut self, mesh: TriangleMesh, transform: Transform) {
+        self.commands
+            .push(Command::DrawTriangles { mesh, transform });
+    }
+
     fn push_mask(&mut self) {
         self.commands.push(Command::PushMask);
     }
@@ -83,6 +240,10 @@ impl CommandHandler for CommandList {
     fn blend(&mut self, commands: CommandList, blend_mode: BlendMode) {
         self.commands.push(Command::Blend(commands, blend_mode));
     }
+
+    fn apply_filters(&mut self, commands: CommandList, filters: Vec<Filter>) {
+        self.commands.push(Command::Filters(commands, filters));
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -100,9 +261,126 @@ pub enum Command {
         color: Color,
         matrix: Matrix,
     },
+    DrawTriangles {
+        mesh: TriangleMesh,
+        transform: Transform,
+    },
     PushMask,
     ActivateMask,
     DeactivateMask,
     PopMask,
     Blend(CommandList, BlendMode),
+    Filters(CommandList, Vec<Filter>),
+}
+
+/// A mesh of triangles drawn with `Graphics.drawTriangles`.
+#[derive(Debug, Clone)]
+pub struct TriangleMesh {
+    /// The position of each vertex, in pixels.
+    pub vertices: Vec<(f32, f32)>,
+
+    /// Indices into `vertices`, three for each triangle.
+    pub indices: Vec<u32>,
+
+    /// The texture coordinates of each vertex, normalized to the size of the bitmap.
+    /// Only bitmap fills use these, and they aren't drawn without them.
+    pub uvs: Option<Vec<(f32, f32)>>,
+
+    pub fill: MeshFill,
+}
+
+/// How a `TriangleMesh` is filled.
+#[derive(Debug, Clone)]
+pub enum MeshFill {
+    Color(Color),
+    Bitmap {
+        bitmap: BitmapHandle,
+        smoothing: bool,
+        repeating: bool,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swf::Twips;
+
+    #[test]
+    fn masked_scene_summary() {
+        let mut blended = CommandList::new();
+        blended.render_shape(ShapeHandle(2), Transform::default());
+
+        let mut commands = CommandList::new();
+        commands.draw_rect(Color::WHITE, Matrix::scale(550.0, 400.0));
+        commands.push_mask();
+        commands.render_shape(ShapeHandle(0), Transform::default());
+        commands.activate_mask();
+        commands.render_shape(
+            ShapeHandle(1),
+            Transform {
+                matrix: Matrix::translate(Twips::from_pixels(10.0), Twips::ZERO),
+                ..Default::default()
+            },
+        );
+        commands.deactivate_mask();
+        commands.render_shape(ShapeHandle(0), Transform::default());
+        commands.pop_mask();
+        commands.blend(blended, BlendMode::Multiply);
+
+        assert_eq!(
+            commands.debug_summary(),
+            CommandSummary {
+                bitmaps: 0,
+                shapes: 4,
+                rects: 1,
+                triangle_meshes: 0,
+                push_masks: 1,
+                activate_masks: 1,
+                deactivate_masks: 1,
+                pop_masks: 1,
+                blends: 1,
+                filters: 0,
+            }
+        );
+
+        assert_eq!(
+            commands.to_string(),
+            "draw_rect color=#ffffffff matrix=[550 0 0 400 0 0]
+push_mask
+render_shape 0 matrix=[1 0 0 1 0 0]
+activate_mask
+render_shape 1 matrix=[1 0 0 1 10 0]
+deactivate_mask
+render_shape 0 matrix=[1 0 0 1 0 0]
+pop_mask
+blend Multiply
+  render_shape 2 matrix=[1 0 0 1 0 0]
+"
+        );
+    }
+
+    #[test]
+    fn filtered_scene_summary() {
+        let mut filtered = CommandList::new();
+        filtered.render_shape(ShapeHandle(0), Transform::default());
+
+        let mut commands = CommandList::new();
+        commands.apply_filters(
+            filtered,
+            vec![Filter::BlurFilter(Box::new(swf::BlurFilter {
+                blur_x: swf::Fixed16::from_f64(4.0),
+                blur_y: swf::Fixed16::from_f64(4.0),
+                num_passes: 1,
+            }))],
+        );
+
+        assert_eq!(commands.debug_summary().filters, 1);
+        assert_eq!(commands.debug_summary().shapes, 1);
+        assert_eq!(
+            commands.to_string(),
+            "filters [blur]
+  render_shape 0 matrix=[1 0 0 1 0 0]
+"
+        );
+    }
 }
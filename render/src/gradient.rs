@@ -0,0 +1,260 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use swf::{GradientInterpolation, GradientSpread};
+
+/// The number of texels in a baked gradient ramp.
+pub const GRADIENT_RAMP_SIZE: usize = 256;
+
+/// A gradient baked into a strip of RGBA texels, ready to be uploaded as a texture
+/// or sampled directly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GradientRamp {
+    /// `GRADIENT_RAMP_SIZE` straight (non-premultiplied) RGBA texels.
+    pub texels: Vec<[u8; 4]>,
+    pub spread: GradientSpread,
+}
+
+impl GradientRamp {
+    /// Bakes the records of `gradient` into a ramp.
+    ///
+    /// Colors between stops are interpolated in the color space requested by the
+    /// gradient; the texels themselves are always stored in sRGB.
+    pub fn new(gradient: &swf::Gradient) -> Self {
+        let stops: Vec<(f32, [f32; 4])> = gradient
+            .records
+            .iter()
+            .map(|record| {
+                let mut color = [
+                    f32::from(record.color.r) / 255.0,
+                    f32::from(record.color.g) / 255.0,
+                    f32::from(record.color.b) / 255.0,
+                    f32::from(record.color.a) / 255.0,
+                ];
+                if gradient.interpolation == GradientInterpolation::LinearRgb {
+                    srgb_to_linear(&mut color);
+                }
+                (f32::from(record.ratio), color)
+            })
+            .collect();
+
+        let texels = (0..GRADIENT_RAMP_SIZE)
+            .map(|i| {
+                let mut color = interpolate_stops(&stops, i as f32);
+                if gradient.interpolation == GradientInterpolation::LinearRgb {
+                    linear_to_srgb(&mut color);
+                }
+                color.map(|n| (n.clamp(0.0, 1.0) * 255.0).round() as u8)
+            })
+            .collect();
+
+        Self {
+            texels,
+            spread: gradient.spread,
+        }
+    }
+
+    /// Returns the texel at gradient position `t`, where `0.0..=1.0` spans the ramp.
+    /// Positions outside of that range are resolved according to the spread mode.
+    pub fn sample(&self, t: f32) -> [u8; 4] {
+        let t = match self.spread {
+            GradientSpread::Pad => t.clamp(0.0, 1.0),
+            GradientSpread::Repeat => t.rem_euclid(1.0),
+            GradientSpread::Reflect => {
+                let t = t.rem_euclid(2.0);
+                if t > 1.0 {
+                    2.0 - t
+                } else {
+                    t
+                }
+            }
+        };
+        let index = (t * (GRADIENT_RAMP_SIZE - 1) as f32).round() as usize;
+        self.texels[index.min(GRADIENT_RAMP_SIZE - 1)]
+    }
+}
+
+/// Identifies the parts of a gradient that affect its baked ramp.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct GradientRampKey {
+    records: Vec<(u8, [u8; 4])>,
+    spread: u8,
+    interpolation: u8,
+}
+
+impl GradientRampKey {
+    fn new(gradient: &swf::Gradient) -> Self {
+        Self {
+            records: gradient
+                .records
+                .iter()
+                .map(|record| {
+                    let color = &record.color;
+                    (record.ratio, [color.r, color.g, color.b, color.a])
+                })
+                .collect(),
+            spread: gradient.spread as u8,
+            interpolation: gradient.interpolation as u8,
+        }
+    }
+}
+
+/// Caches baked gradient ramps by gradient contents, so that gradients shared
+/// between shapes (or re-registered every frame) are only baked once.
+#[derive(Debug, Default)]
+pub struct GradientRampCache {
+    ramps: HashMap<GradientRampKey, Arc<GradientRamp>>,
+}
+
+impl GradientRampCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the ramp for `gradient`, baking it if it hasn't been seen before.
+    pub fn get_or_bake(&mut self, gradient: &swf::Gradient) -> Arc<GradientRamp> {
+        self.ramps
+            .entry(GradientRampKey::new(gradient))
+            .or_insert_with(|| Arc::new(GradientRamp::new(gradient)))
+            .clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.ramps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ramps.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.ramps.clear();
+    }
+}
+
+/// Finds the color at `ratio` (in `0.0..=255.0`) between sorted gradient stops.
+fn interpolate_stops(stops: &[(f32, [f32; 4])], ratio: f32) -> [f32; 4] {
+    let (first, last) = match (stops.first(), stops.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return [0.0; 4],
+    };
+    if ratio <= first.0 {
+        return first.1;
+    }
+    if ratio >= last.0 {
+        return last.1;
+    }
+    for window in stops.windows(2) {
+        let ((from_ratio, from), (to_ratio, to)) = (window[0], window[1]);
+        if ratio <= to_ratio {
+            let span = to_ratio - from_ratio;
+            let a = if span > 0.0 {
+                (ratio - from_ratio) / span
+            } else {
+                1.0
+            };
+            let mut color = [0.0; 4];
+            for ((out, from), to) in color.iter_mut().zip(from).zip(to) {
+                *out = from + (to - from) * a;
+            }
+            return color;
+        }
+    }
+    last.1
+}
+
+/// Converts an RGBA color from sRGB space to linear color space.
+fn srgb_to_linear(color: &mut [f32; 4]) {
+    for n in &mut color[..3] {
+        *n = if *n <= 0.04045 {
+            *n / 12.92
+        } else {
+            f32::powf((*n + 0.055) / 1.055, 2.4)
+        };
+    }
+}
+
+/// Converts an RGBA color from linear color space to sRGB space.
+fn linear_to_srgb(color: &mut [f32; 4]) {
+    for n in &mut color[..3] {
+        *n = if *n <= 0.0031308 {
+            *n * 12.92
+        } else {
+            1.055 * n.powf(1.0 / 2.4) - 0.055
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swf::{Color, GradientRecord, Matrix};
+
+    fn black_to_white(interpolation: GradientInterpolation) -> swf::Gradient {
+        swf::Gradient {
+            matrix: Matrix::IDENTITY,
+            spread: GradientSpread::Pad,
+            interpolation,
+            records: vec![
+                GradientRecord {
+                    ratio: 0,
+                    color: Color::from_rgb(0x000000, 255),
+                },
+                GradientRecord {
+                    ratio: 255,
+                    color: Color::from_rgb(0xFFFFFF, 255),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn two_stop_endpoints() {
+        let ramp = GradientRamp::new(&black_to_white(GradientInterpolation::Rgb));
+        assert_eq!(ramp.texels.len(), GRADIENT_RAMP_SIZE);
+        assert_eq!(ramp.texels[0], [0, 0, 0, 255]);
+        assert_eq!(ramp.texels[GRADIENT_RAMP_SIZE - 1], [255, 255, 255, 255]);
+        assert_eq!(ramp.texels[128], [128, 128, 128, 255]);
+    }
+
+    #[test]
+    fn linear_rgb_midpoint() {
+        let srgb = GradientRamp::new(&black_to_white(GradientInterpolation::Rgb));
+        let linear = GradientRamp::new(&black_to_white(GradientInterpolation::LinearRgb));
+        assert_eq!(linear.texels[0], srgb.texels[0]);
+        assert_eq!(linear.texels[255], srgb.texels[255]);
+        // Interpolating in linear space brightens the midpoint once converted back.
+        assert!(linear.texels[128][0] > srgb.texels[128][0] + 40);
+    }
+
+    #[test]
+    fn spread_modes() {
+        let mut gradient = black_to_white(GradientInterpolation::Rgb);
+        let pad = GradientRamp::new(&gradient);
+        assert_eq!(pad.sample(1.5), [255, 255, 255, 255]);
+        assert_eq!(pad.sample(-0.5), [0, 0, 0, 255]);
+
+        gradient.spread = GradientSpread::Repeat;
+        let repeat = GradientRamp::new(&gradient);
+        assert_eq!(repeat.sample(1.25), repeat.sample(0.25));
+
+        gradient.spread = GradientSpread::Reflect;
+        let reflect = GradientRamp::new(&gradient);
+        assert_eq!(reflect.sample(1.25), reflect.sample(0.75));
+        assert_eq!(reflect.sample(-0.25), reflect.sample(0.25));
+    }
+
+    #[test]
+    fn cache_reuses_ramps() {
+        let mut cache = GradientRampCache::new();
+        let gradient = black_to_white(GradientInterpolation::Rgb);
+        let first = cache.get_or_bake(&gradient);
+        let mut moved = gradient.clone();
+        moved.matrix.tx = swf::Twips::new(100);
+        let second = cache.get_or_bake(&moved);
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(cache.len(), 1);
+
+        cache.get_or_bake(&black_to_white(GradientInterpolation::LinearRgb));
+        assert_eq!(cache.len(), 2);
+    }
+}
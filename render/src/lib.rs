@@ -5,7 +5,9 @@ pub mod bitmap;
 pub mod bounding_box;
 pub mod color_transform;
 pub mod error;
+pub mod gradient;
 pub mod matrix;
+pub mod quality;
 pub mod shape_utils;
 pub mod transform;
 pub mod utils;
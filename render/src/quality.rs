@@ -0,0 +1,51 @@
+/// The quality at which the renderer should draw content.
+///
+/// This is derived from the stage quality setting (`_quality` in AVM1, `Stage.quality` in AVM2),
+/// and controls how finely curves are flattened and how much anti-aliasing is applied.
+#[derive(Default, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RenderQuality {
+    /// No anti-aliasing, and curves are flattened coarsely.
+    Low,
+
+    /// 2x anti-aliasing.
+    Medium,
+
+    /// 4x anti-aliasing.
+    #[default]
+    High,
+
+    /// 4x anti-aliasing, with curves flattened more finely.
+    Best,
+
+    /// 8x anti-aliasing.
+    High8x8,
+
+    /// 16x anti-aliasing.
+    High16x16,
+}
+
+impl RenderQuality {
+    /// The number of MSAA samples that should be used when rendering at this quality.
+    ///
+    /// Backends may clamp this to the sample counts supported by the device.
+    pub fn sample_count(self) -> u32 {
+        match self {
+            RenderQuality::Low => 1,
+            RenderQuality::Medium => 2,
+            RenderQuality::High | RenderQuality::Best => 4,
+            RenderQuality::High8x8 => 8,
+            RenderQuality::High16x16 => 16,
+        }
+    }
+
+    /// The maximum distance (in pixels) between a curve and its flattened approximation
+    /// when tessellating shapes at this quality.
+    pub fn tessellation_tolerance(self) -> f32 {
+        match self {
+            RenderQuality::Low => 0.5,
+            RenderQuality::Medium => 0.25,
+            RenderQuality::High => 0.1,
+            RenderQuality::Best | RenderQuality::High8x8 | RenderQuality::High16x16 => 0.05,
+        }
+    }
+}
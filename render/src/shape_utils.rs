@@ -688,6 +688,42 @@ mod tests {
         }];
         assert_eq!(commands, expected);
     }
+
+    /// A point inside the bounding box of a triangle, but outside of its edges,
+    /// should only hit when testing bounds.
+    #[test]
+    fn triangle_hit_test() {
+        let shape = build_shape(vec![
+            ShapeRecord::StyleChange(Box::new(swf::StyleChangeData {
+                move_to: Some((Twips::from_pixels(0.0), Twips::from_pixels(0.0))),
+                fill_style_0: None,
+                fill_style_1: Some(1),
+                line_style: None,
+                new_styles: None,
+            })),
+            ShapeRecord::StraightEdge {
+                delta_x: Twips::from_pixels(100.0),
+                delta_y: Twips::from_pixels(100.0),
+            },
+            ShapeRecord::StraightEdge {
+                delta_x: Twips::from_pixels(-100.0),
+                delta_y: Twips::from_pixels(0.0),
+            },
+            ShapeRecord::StraightEdge {
+                delta_x: Twips::from_pixels(0.0),
+                delta_y: Twips::from_pixels(-100.0),
+            },
+        ]);
+        let bounds = BoundingBox::from(&shape.shape_bounds);
+
+        let outside = (Twips::from_pixels(75.0), Twips::from_pixels(25.0));
+        assert!(bounds.contains(outside));
+        assert!(!shape_hit_test(&shape, outside, &Matrix::IDENTITY));
+
+        let inside = (Twips::from_pixels(25.0), Twips::from_pixels(75.0));
+        assert!(bounds.contains(inside));
+        assert!(shape_hit_test(&shape, inside, &Matrix::IDENTITY));
+    }
 }
 
 /* SHAPEFLAG HITTEST (point-in-contour)
@@ -1,4 +1,5 @@
 use crate::bitmap::BitmapSource;
+use crate::quality::RenderQuality;
 use crate::shape_utils::{DistilledShape, DrawCommand, DrawPath};
 use enum_map::Enum;
 use lyon::path::Path;
@@ -16,6 +17,7 @@ pub struct ShapeTessellator {
     lyon_mesh: VertexBuffers<Vertex, u32>,
     mask_index_count: Option<u32>,
     is_stroke: bool,
+    tolerance: f32,
 }
 
 impl ShapeTessellator {
@@ -27,9 +29,17 @@ impl ShapeTessellator {
             lyon_mesh: VertexBuffers::new(),
             mask_index_count: None,
             is_stroke: false,
+            tolerance: RenderQuality::default().tessellation_tolerance(),
         }
     }
 
+    /// Sets the quality used for subsequent tessellations.
+    ///
+    /// Lower qualities flatten curves into fewer line segments.
+    pub fn set_quality(&mut self, quality: RenderQuality) {
+        self.tolerance = quality.tessellation_tolerance();
+    }
+
     pub fn tessellate_shape(
         &mut self,
         shape: DistilledShape,
@@ -132,13 +142,14 @@ impl ShapeTessellator {
             let result = match path {
                 DrawPath::Fill { .. } => self.fill_tess.tessellate_path(
                     &lyon_path,
-                    &FillOptions::even_odd(),
+                    &FillOptions::even_odd().with_tolerance(self.tolerance),
                     &mut buffers_builder,
                 ),
                 DrawPath::Stroke { style, .. } => {
                     // TODO(Herschel): 0 width indicates "hairline".
                     let width = (style.width().to_pixels() as f32).max(1.0);
                     let mut stroke_options = StrokeOptions::default()
+                        .with_tolerance(self.tolerance)
                         .with_line_width(width)
                         .with_start_cap(match style.start_cap() {
                             swf::LineCapStyle::None => tessellation::LineCap::Butt,
@@ -447,3 +458,74 @@ pub enum GradientType {
     Radial,
     Focal,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::null::NullBitmapSource;
+    use swf::Twips;
+
+    const FILL_STYLE: swf::FillStyle = swf::FillStyle::Color(swf::Color::WHITE);
+
+    /// Builds a circle out of eight quadratic curves.
+    fn circle(radius: f64) -> Vec<DrawCommand> {
+        let point = |angle: f64, r: f64| {
+            (
+                Twips::from_pixels(r * angle.cos()),
+                Twips::from_pixels(r * angle.sin()),
+            )
+        };
+
+        let segments = 8;
+        let step = std::f64::consts::TAU / f64::from(segments);
+        // The control point radius that makes each curve tangent to the circle.
+        let control_radius = radius / (step / 2.0).cos();
+
+        let (x, y) = point(0.0, radius);
+        let mut commands = vec![DrawCommand::MoveTo { x, y }];
+        for i in 0..segments {
+            let angle = f64::from(i) * step;
+            let (x1, y1) = point(angle + step / 2.0, control_radius);
+            let (x2, y2) = point(angle + step, radius);
+            commands.push(DrawCommand::CurveTo { x1, y1, x2, y2 });
+        }
+        commands
+    }
+
+    fn tessellate_circle(quality: RenderQuality) -> usize {
+        let shape = DistilledShape {
+            paths: vec![DrawPath::Fill {
+                style: &FILL_STYLE,
+                commands: circle(100.0),
+            }],
+            shape_bounds: Default::default(),
+            edge_bounds: Default::default(),
+            id: 1,
+        };
+
+        let mut tessellator = ShapeTessellator::new();
+        tessellator.set_quality(quality);
+        let mesh = tessellator.tessellate_shape(shape, &NullBitmapSource);
+        mesh.iter().map(|draw| draw.vertices.len()).sum()
+    }
+
+    #[test]
+    fn quality_affects_curve_flattening() {
+        let low = tessellate_circle(RenderQuality::Low);
+        let high = tessellate_circle(RenderQuality::High);
+        let best = tessellate_circle(RenderQuality::Best);
+
+        assert!(
+            low > 8,
+            "circle should be flattened into more than 8 segments"
+        );
+        assert!(
+            low < high,
+            "low quality should use fewer segments ({low} >= {high})"
+        );
+        assert!(
+            high < best,
+            "best quality should use more segments ({high} >= {best})"
+        );
+    }
+}
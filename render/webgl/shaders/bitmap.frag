@@ -22,9 +22,8 @@ void main() {
     // Unmultiply alpha before apply color transform.
     if( color.a > 0.0 ) {
         color.rgb /= color.a;
-        color = mult_color * color + add_color;
-        float alpha = clamp(color.a, 0.0, 1.0);
-        color = vec4(color.rgb * alpha, alpha);
+        color = clamp(mult_color * color + add_color, 0.0, 1.0);
+        color = vec4(color.rgb * color.a, color.a);
     }
 
     gl_FragColor = color;
@@ -16,8 +16,7 @@ attribute vec4 color;
 varying vec4 frag_color;
 
 void main() {
-    frag_color = color * mult_color + add_color;
-    float alpha = clamp(frag_color.a, 0.0, 1.0);
-    frag_color = vec4(frag_color.rgb * alpha, alpha);
+    frag_color = clamp(color * mult_color + add_color, 0.0, 1.0);
+    frag_color = vec4(frag_color.rgb * frag_color.a, frag_color.a);
     gl_Position = view_matrix * world_matrix * vec4(position, 0.0, 1.0);
 }
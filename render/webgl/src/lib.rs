@@ -12,8 +12,9 @@ use ruffle_render::backend::{
 use ruffle_render::bitmap::{
     Bitmap, BitmapFormat, BitmapHandle, BitmapHandleImpl, BitmapSource, SyncHandle,
 };
-use ruffle_render::commands::{CommandHandler, CommandList};
+use ruffle_render::commands::{CommandHandler, CommandList, TriangleMesh};
 use ruffle_render::error::Error as BitmapError;
+use ruffle_render::quality::RenderQuality;
 use ruffle_render::shape_utils::DistilledShape;
 use ruffle_render::tessellator::{
     Gradient as TessGradient, GradientType, ShapeTessellator, Vertex as TessVertex,
@@ -21,7 +22,7 @@ use ruffle_render::tessellator::{
 use ruffle_render::transform::Transform;
 use ruffle_web_common::{JsError, JsResult};
 use std::sync::Arc;
-use swf::{BlendMode, Color};
+use swf::{BlendMode, Color, Filter};
 use thiserror::Error;
 use wasm_bindgen::{JsCast, JsValue};
 use web_sys::{
@@ -122,6 +123,9 @@ pub struct WebGlRenderBackend {
     msaa_buffers: Option<MsaaBuffers>,
     msaa_sample_count: u32,
 
+    // The maximum MSAA sample count supported by this device.
+    max_msaa_sample_count: u32,
+
     color_program: ShaderProgram,
     bitmap_program: ShaderProgram,
     gradient_program: ShaderProgram,
@@ -300,6 +304,7 @@ impl WebGlRenderBackend {
 
             msaa_buffers: None,
             msaa_sample_count,
+            max_msaa_sample_count: msaa_sample_count,
 
             color_program,
             gradient_program,
@@ -453,16 +458,9 @@ impl WebGlRenderBackend {
     }
 
     fn build_msaa_buffers(&mut self) -> Result<(), Error> {
-        if self.gl2.is_none() || self.msaa_sample_count <= 1 {
-            self.gl.bind_framebuffer(Gl::FRAMEBUFFER, None);
-            self.gl.bind_renderbuffer(Gl::RENDERBUFFER, None);
-            return Ok(());
-        }
-
-        let gl = self.gl2.as_ref().expect("gl2 must exist at this point");
-
         // Delete previous buffers, if they exist.
         if let Some(msaa_buffers) = self.msaa_buffers.take() {
+            let gl = &self.gl;
             gl.delete_renderbuffer(Some(&msaa_buffers.color_renderbuffer));
             gl.delete_renderbuffer(Some(&msaa_buffers.stencil_renderbuffer));
             gl.delete_framebuffer(Some(&msaa_buffers.render_framebuffer));
@@ -470,6 +468,14 @@ impl WebGlRenderBackend {
             gl.delete_texture(Some(&msaa_buffers.framebuffer_texture));
         }
 
+        if self.gl2.is_none() || self.msaa_sample_count <= 1 {
+            self.gl.bind_framebuffer(Gl::FRAMEBUFFER, None);
+            self.gl.bind_renderbuffer(Gl::RENDERBUFFER, None);
+            return Ok(());
+        }
+
+        let gl = self.gl2.as_ref().expect("gl2 must exist at this point");
+
         // Create frame and render buffers.
         let render_framebuffer = gl
             .create_framebuffer()
@@ -954,6 +960,13 @@ impl RenderBackend for WebGlRenderBackend {
         Err(ruffle_render::error::Error::Unimplemented)
     }
 
+    fn get_offscreen_pixels(
+        &self,
+        _handle: &BitmapHandle,
+    ) -> Result<Bitmap, ruffle_render::error::Error> {
+        Err(ruffle_render::error::Error::Unimplemented)
+    }
+
     fn viewport_dimensions(&self) -> ViewportDimensions {
         ViewportDimensions {
             width: self.renderbuffer_width as u32,
@@ -1145,6 +1158,16 @@ impl RenderBackend for WebGlRenderBackend {
 
         return Cow::Owned(result.join("\n"));
     }
+
+    fn set_quality(&mut self, quality: RenderQuality) {
+        self.shape_tessellator.set_quality(quality);
+
+        let msaa_sample_count = quality.sample_count().min(self.max_msaa_sample_count);
+        if msaa_sample_count != self.msaa_sample_count {
+            self.msaa_sample_count = msaa_sample_count;
+            let _ = self.build_msaa_buffers();
+        }
+    }
 }
 
 impl CommandHandler for WebGlRenderBackend {
@@ -1485,6 +1508,18 @@ impl CommandHandler for WebGlRenderBackend {
         commands.execute(self);
         self.pop_blend_mode();
     }
+
+    fn apply_filters(&mut self, commands: CommandList, _filters: Vec<Filter>) {
+        // TODO: Filters are not yet supported by this backend, so draw the content unfiltered.
+        commands.execute(self);
+    }
+
+    fn draw_triangles(&mut self, _mesh: TriangleMesh, _transform: Transform) {
+        // TODO: Triangle meshes are not yet supported by this backend.
+        static WARN_ONCE: std::sync::Once = std::sync::Once::new();
+        WARN_ONCE
+            .call_once(|| log::warn!("Triangle meshes are not yet supported by the WebGL backend"));
+    }
 }
 
 #[derive(Clone, Debug)]
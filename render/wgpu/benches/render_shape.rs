@@ -0,0 +1,86 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use ruffle_render::backend::null::NullBitmapSource;
+use ruffle_render::backend::RenderBackend;
+use ruffle_render::commands::{CommandHandler, CommandList};
+use ruffle_render::shape_utils::{DistilledShape, DrawCommand, DrawPath};
+use ruffle_render::transform::Transform;
+use ruffle_render_wgpu::backend::WgpuRenderBackend;
+use ruffle_render_wgpu::wgpu;
+use swf::{Color, FillStyle, Twips};
+
+/// A circle made of 64 quadratic curves, so that tessellating it takes some work.
+fn circle(fill: &FillStyle) -> DistilledShape<'_> {
+    use std::f64::consts::PI;
+    const SEGMENTS: usize = 64;
+    let (cx, cy, radius) = (128.0, 128.0, 100.0);
+    let step = 2.0 * PI / SEGMENTS as f64;
+    let control_radius = radius / (step / 2.0).cos();
+    let point = |radius: f64, angle: f64| {
+        (
+            Twips::from_pixels(cx + radius * angle.cos()),
+            Twips::from_pixels(cy + radius * angle.sin()),
+        )
+    };
+
+    let (x, y) = point(radius, 0.0);
+    let mut commands = vec![DrawCommand::MoveTo { x, y }];
+    for i in 0..SEGMENTS {
+        let (x1, y1) = point(control_radius, (i as f64 + 0.5) * step);
+        let (x2, y2) = point(radius, (i + 1) as f64 * step);
+        commands.push(DrawCommand::CurveTo { x1, y1, x2, y2 });
+    }
+    DistilledShape {
+        paths: vec![DrawPath::Fill {
+            style: fill,
+            commands,
+        }],
+        shape_bounds: Default::default(),
+        edge_bounds: Default::default(),
+        id: 1,
+    }
+}
+
+fn render(c: &mut Criterion) {
+    let mut renderer = match WgpuRenderBackend::for_offscreen(
+        (256, 256),
+        wgpu::Backends::PRIMARY,
+        Default::default(),
+        None,
+    ) {
+        Ok(renderer) => renderer,
+        Err(e) => {
+            eprintln!("Skipping benchmark, no graphics device available: {}", e);
+            return;
+        }
+    };
+    let fill = FillStyle::Color(Color::from_rgb(0xFF0000, 255));
+    let handle = renderer.register_shape(circle(&fill), &NullBitmapSource);
+
+    let render_frame = |renderer: &mut WgpuRenderBackend<_>| {
+        let mut commands = CommandList::new();
+        commands.render_shape(handle, Transform::default());
+        renderer.submit_frame(Color::WHITE, commands);
+    };
+
+    // Every frame draws the mesh built when the shape was registered.
+    c.bench_function("render registered shape x500", |b| {
+        b.iter(|| {
+            for _ in 0..500 {
+                render_frame(&mut renderer);
+            }
+        })
+    });
+
+    // For comparison, tessellate the shape again before every frame.
+    c.bench_function("replace and render shape x500", |b| {
+        b.iter(|| {
+            for _ in 0..500 {
+                renderer.replace_shape(circle(&fill), &NullBitmapSource, handle);
+                render_frame(&mut renderer);
+            }
+        })
+    });
+}
+
+criterion_group!(benches, render);
+criterion_main!(benches);
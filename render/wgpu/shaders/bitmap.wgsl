@@ -41,9 +41,8 @@ fn main_fragment(in: VertexOutput) -> @location(0) vec4<f32> {
     // Unmultiply alpha, apply color transform, remultiply alpha.
     if( color.a > 0.0 ) {
         color = vec4<f32>(color.rgb / color.a, color.a);
-        color = color * colorTransforms.mult_color + colorTransforms.add_color;
-        let alpha = clamp(color.a, 0.0, 1.0);
-        color = vec4<f32>(color.rgb * alpha, alpha);
+        color = clamp(color * colorTransforms.mult_color + colorTransforms.add_color, vec4<f32>(0.0), vec4<f32>(1.0));
+        color = vec4<f32>(color.rgb * color.a, color.a);
     }
     return color;
 }
@@ -115,7 +115,7 @@ fn main_fragment(in: VertexOutput) -> @location(0) vec4<f32> {
     if( gradient.interpolation != 0 ) {
         color = common::linear_to_srgb(color);
     }
-    let out = color * colorTransforms.mult_color + colorTransforms.add_color;
-    let alpha = clamp(out.a, 0.0, 1.0);
-    return vec4<f32>(out.rgb * alpha, alpha);
+    // Clamp before premultiplying, so that no channel can exceed alpha.
+    let out = clamp(color * colorTransforms.mult_color + colorTransforms.add_color, vec4<f32>(0.0), vec4<f32>(1.0));
+    return vec4<f32>(out.rgb * out.a, out.a);
 }
\ No newline at end of file
@@ -15,6 +15,7 @@ use ruffle_render::backend::{RenderBackend, ShapeHandle, ViewportDimensions};
 use ruffle_render::bitmap::{Bitmap, BitmapHandle, BitmapSource, SyncHandle};
 use ruffle_render::commands::CommandList;
 use ruffle_render::error::Error as BitmapError;
+use ruffle_render::quality::RenderQuality;
 use ruffle_render::shape_utils::DistilledShape;
 use ruffle_render::tessellator::ShapeTessellator;
 use std::borrow::Cow;
@@ -33,12 +34,18 @@ pub struct WgpuRenderBackend<T: RenderTarget> {
     color_buffers_storage: BufferStorage<ColorAdjustments>,
     target: T,
     surface: Surface,
+    /// GPU meshes indexed by `ShapeHandle`. Shapes are tessellated and uploaded
+    /// once on registration, and only rebuilt when the shape is replaced.
     meshes: Vec<Mesh>,
     shape_tessellator: ShapeTessellator,
+    /// The number of shapes tessellated so far, including replaced shapes.
+    tessellated_shapes: usize,
     // This is currently unused - we just store it to report in
     // `get_viewport_dimensions`
     viewport_scale_factor: f64,
     preferred_sample_count: u32,
+    // The sample count requested when this backend was created; quality changes never exceed it.
+    max_sample_count: u32,
     texture_pool: TexturePool,
     offscreen_texture_pool: TexturePool,
 }
@@ -177,8 +184,10 @@ impl<T: RenderTarget> WgpuRenderBackend<T> {
             surface,
             meshes: Vec::new(),
             shape_tessellator: ShapeTessellator::new(),
+            tessellated_shapes: 0,
             viewport_scale_factor: 1.0,
             preferred_sample_count,
+            max_sample_count: preferred_sample_count,
             texture_pool: TexturePool::new(),
             offscreen_texture_pool: TexturePool::new(),
         })
@@ -221,6 +230,7 @@ impl<T: RenderTarget> WgpuRenderBackend<T> {
         let lyon_mesh = self
             .shape_tessellator
             .tessellate_shape(shape, bitmap_source);
+        self.tessellated_shapes += 1;
 
         let mut draws = Vec::with_capacity(lyon_mesh.len());
         for draw in lyon_mesh {
@@ -354,6 +364,24 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
         Cow::Owned(result.join("\n"))
     }
 
+    fn set_quality(&mut self, quality: RenderQuality) {
+        self.shape_tessellator.set_quality(quality);
+
+        // WebGPU only guarantees support for 1x and 4x multisampling.
+        let sample_count = if quality.sample_count() > 1 { 4 } else { 1 };
+        let sample_count = sample_count.min(self.max_sample_count);
+        if sample_count != self.preferred_sample_count {
+            self.preferred_sample_count = sample_count;
+            self.surface = Surface::new(
+                &self.descriptors,
+                self.preferred_sample_count,
+                self.target.width(),
+                self.target.height(),
+                self.target.format(),
+            );
+        }
+    }
+
     fn viewport_dimensions(&self) -> ViewportDimensions {
         ViewportDimensions {
             width: self.target.width(),
@@ -620,6 +648,22 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
             image.into_raw(),
         ))
     }
+
+    fn get_offscreen_pixels(
+        &self,
+        handle: &BitmapHandle,
+    ) -> Result<Bitmap, ruffle_render::error::Error> {
+        let texture = as_texture(handle);
+        let sync = QueueSyncHandle::NotCopied {
+            handle: handle.clone(),
+            size: wgpu::Extent3d {
+                width: texture.width,
+                height: texture.height,
+                depth_or_array_layers: 1,
+            },
+        };
+        self.retrieve_offscreen_texture(Box::new(sync))
+    }
 }
 
 // We try to request the highest limits we can get away with
@@ -658,3 +702,76 @@ async fn request_device(
         )
         .await
 }
+
+#[cfg(all(test, not(target_family = "wasm")))]
+mod tests {
+    use super::*;
+    use ruffle_render::backend::null::NullBitmapSource;
+    use ruffle_render::commands::CommandHandler;
+    use ruffle_render::shape_utils::{DrawCommand, DrawPath};
+    use ruffle_render::transform::Transform;
+    use swf::Twips;
+
+    /// A shape with a single fill covering the given square, in pixels.
+    fn square(fill: &swf::FillStyle, x0: f64, x1: f64) -> DistilledShape<'_> {
+        let commands = [(x0, x0), (x1, x0), (x1, x1), (x0, x1), (x0, x0)]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (x, y))| {
+                let (x, y) = (Twips::from_pixels(x), Twips::from_pixels(y));
+                if i == 0 {
+                    DrawCommand::MoveTo { x, y }
+                } else {
+                    DrawCommand::LineTo { x, y }
+                }
+            })
+            .collect();
+        DistilledShape {
+            paths: vec![DrawPath::Fill {
+                style: fill,
+                commands,
+            }],
+            shape_bounds: Default::default(),
+            edge_bounds: Default::default(),
+            id: 1,
+        }
+    }
+
+    #[test]
+    fn rendering_reuses_registered_mesh() {
+        let mut renderer = match WgpuRenderBackend::for_offscreen(
+            (8, 8),
+            wgpu::Backends::PRIMARY,
+            Default::default(),
+            None,
+        ) {
+            Ok(renderer) => renderer,
+            Err(e) => {
+                eprintln!("Skipping test, no graphics device available: {}", e);
+                return;
+            }
+        };
+
+        let red = swf::FillStyle::Color(Color::from_rgb(0xFF0000, 255));
+        let handle = renderer.register_shape(square(&red, 2.0, 6.0), &NullBitmapSource);
+        assert_eq!(renderer.tessellated_shapes, 1);
+
+        for _ in 0..2 {
+            let mut commands = CommandList::new();
+            commands.render_shape(handle, Transform::default());
+            renderer.submit_frame(Color::WHITE, commands);
+
+            // Rendering draws the mesh that was built when the shape was registered.
+            assert_eq!(renderer.tessellated_shapes, 1);
+            assert_eq!(renderer.meshes.len(), 1);
+            let image = renderer.capture_frame(false).unwrap();
+            assert_eq!(image.get_pixel(4, 4), &image::Rgba([255, 0, 0, 255]));
+            assert_eq!(image.get_pixel(0, 0), &image::Rgba([255, 255, 255, 255]));
+        }
+
+        // Only replacing the shape builds its mesh again.
+        renderer.replace_shape(square(&red, 0.0, 2.0), &NullBitmapSource, handle);
+        assert_eq!(renderer.tessellated_shapes, 2);
+        assert_eq!(renderer.meshes.len(), 1);
+    }
+}
@@ -11,6 +11,7 @@ use ruffle_render::tessellator::{
 };
 use swf::{CharacterId, GradientSpread};
 
+/// The tessellated, GPU-resident form of a registered shape.
 #[derive(Debug)]
 pub struct Mesh {
     pub draws: Vec<Draw>,
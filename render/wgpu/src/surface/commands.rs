@@ -438,6 +438,19 @@ pub enum DrawCommand {
     PopMask,
 }
 
+/// Replaces every filter with the commands it wraps.
+// TODO: Filters are not yet supported by this backend, so filtered content is drawn unfiltered.
+fn without_filters(commands: Vec<Command>) -> Vec<Command> {
+    let mut result = Vec::with_capacity(commands.len());
+    for command in commands {
+        match command {
+            Command::Filters(commands, _) => result.extend(without_filters(commands.commands)),
+            command => result.push(command),
+        }
+    }
+    result
+}
+
 /// Replaces every blend with a RenderBitmap, with the subcommands rendered out to a temporary texture
 /// Every complex blend will be its own item, but every other draw will be chunked together
 #[allow(clippy::too_many_arguments)]
@@ -460,7 +473,7 @@ pub fn chunk_blends<'a>(
     let mut needs_depth = false;
     let mut num_masks = 0;
 
-    for command in commands {
+    for command in without_filters(commands) {
         match command {
             Command::Blend(commands, blend_mode) => {
                 let mut surface = Surface::new(
@@ -580,6 +593,14 @@ pub fn chunk_blends<'a>(
                 num_masks -= 1;
                 current.push(DrawCommand::PopMask);
             }
+            Command::DrawTriangles { .. } => {
+                // TODO: Triangle meshes are not yet supported by this backend.
+                static WARN_ONCE: std::sync::Once = std::sync::Once::new();
+                WARN_ONCE.call_once(|| {
+                    tracing::warn!("Triangle meshes are not yet supported by the wgpu backend")
+                });
+            }
+            Command::Filters(..) => unreachable!("Filters are removed before chunking"),
         }
     }
 
@@ -44,4 +44,29 @@ pub enum AutomatedEvent {
         pos: MousePosition,
         btn: MouseButton,
     },
+
+    /// Scroll the mouse wheel, by either a number of lines or a number of pixels.
+    MouseWheel {
+        lines: Option<f64>,
+        pixels: Option<f64>,
+    },
+
+    /// Press a key, given by its Flash key code.
+    KeyDown { key_code: u8 },
+
+    /// Release a key, given by its Flash key code.
+    KeyUp { key_code: u8 },
+
+    /// Input a character of text.
+    TextInput { codepoint: char },
+
+    /// Update the input method's in-progress composition, with the cursor
+    /// given as a byte range of `text`. An empty `text` cancels it.
+    ImePreedit {
+        text: String,
+        cursor: Option<(usize, usize)>,
+    },
+
+    /// Commit the input method's composition.
+    ImeCommit { text: String },
 }
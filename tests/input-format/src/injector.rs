@@ -80,7 +80,13 @@ impl InputInjector {
 
                 match event {
                     AutomatedEvent::Wait => break,
-                    AutomatedEvent::MouseMove { .. } => {}
+                    AutomatedEvent::MouseMove { .. }
+                    | AutomatedEvent::MouseWheel { .. }
+                    | AutomatedEvent::KeyDown { .. }
+                    | AutomatedEvent::KeyUp { .. }
+                    | AutomatedEvent::TextInput { .. }
+                    | AutomatedEvent::ImePreedit { .. }
+                    | AutomatedEvent::ImeCommit { .. } => {}
                     AutomatedEvent::MouseDown { btn, .. } => {
                         self.buttons |= (*btn).into();
                     }
@@ -5,17 +5,18 @@
 use approx::assert_relative_eq;
 use regex::Regex;
 use ruffle_core::backend::{
+    locale::LocaleBackend,
     log::LogBackend,
     navigator::{NullExecutor, NullNavigatorBackend},
     storage::{MemoryStorageBackend, StorageBackend},
 };
 use ruffle_core::context::UpdateContext;
-use ruffle_core::events::MouseButton as RuffleMouseButton;
+use ruffle_core::events::{KeyCode, MouseButton as RuffleMouseButton, MouseWheelDelta};
 use ruffle_core::external::Value as ExternalValue;
 use ruffle_core::external::{ExternalInterfaceMethod, ExternalInterfaceProvider};
 use ruffle_core::limits::ExecutionLimit;
 use ruffle_core::tag_utils::SwfMovie;
-use ruffle_core::{Player, PlayerBuilder, PlayerEvent, ViewportDimensions};
+use ruffle_core::{Player, PlayerBuilder, PlayerEvent, SandboxType, ViewportDimensions};
 use ruffle_input_format::{AutomatedEvent, InputInjector, MouseButton as InputMouseButton};
 
 #[cfg(feature = "imgtests")]
@@ -149,6 +150,7 @@ swf_tests! {
     (as2_super_and_this_v6, "avm1/as2_super_and_this_v6", 1),
     (as2_super_and_this_v8, "avm1/as2_super_and_this_v8", 1),
     (as2_super_via_manual_prototype, "avm1/as2_super_via_manual_prototype", 1),
+    (as3_activation_slots_by_name, "avm2/activation_slots_by_name", 1),
     (as3_add, "avm2/add", 1),
     (as3_agal_compiler, "avm2/agal_compiler", 1),
     (as3_application_domain, "avm2/application_domain", 1),
@@ -163,6 +165,7 @@ swf_tests! {
     (as3_array_foreach, "avm2/array_foreach", 1),
     (as3_array_hasownproperty, "avm2/array_hasownproperty", 1),
     (as3_array_holes, "avm2/array_holes", 1),
+    (as3_array_index_properties, "avm2/array_index_properties", 1),
     (as3_array_indexof, "avm2/array_indexof", 1),
     (as3_array_join, "avm2/array_join", 1),
     (as3_array_lastindexof, "avm2/array_lastindexof", 1),
@@ -201,7 +204,9 @@ swf_tests! {
     #[cfg_attr(not(feature = "imgtests"), ignore)] (as3_bitmapdata_opaque, "avm2/bitmapdata_opaque", 1, img = true),
     (as3_bitmapdata_zero_size, "avm2/bitmapdata_zero_size", 1),
     #[cfg_attr(not(feature = "imgtests"), ignore)] (as3_bitmapdata_embedded, "avm2/bitmapdata_embedded", 1, img = true),
+    (as3_bitmapdata_fill_and_copy_pixels, "avm2/bitmapdata_fill_and_copy_pixels", 1),
     (as3_bitmapdata_fillrect, "avm2/bitmapdata_fillrect", 1),
+    (as3_bitmapdata_flood_fill_transform_threshold, "avm2/bitmapdata_flood_fill_transform_threshold", 1),
     (as3_bitnot, "avm2/bitnot", 1),
     (as3_bitor, "avm2/bitor", 1),
     (as3_bitxor, "avm2/bitxor", 1),
@@ -212,6 +217,7 @@ swf_tests! {
     (as3_bytearray_readobject_amf0, "avm2/bytearray_readobject_amf0", 1),
     (as3_bytearray_readobject_amf3, "avm2/bytearray_readobject_amf3", 1),
     (as3_bytearray_writeobject, "avm2/bytearray_writeobject", 1),
+    (as3_bytearray_writeobject_references, "avm2/bytearray_writeobject_references", 1),
     (as3_bytearray, "avm2/bytearray", 1),
     (as3_checkfilter, "avm2/checkfilter", 1),
     (as3_class_call, "avm2/class_call", 1),
@@ -221,13 +227,16 @@ swf_tests! {
     (as3_class_methods, "avm2/class_methods", 1),
     (as3_class_object_properties, "avm2/class_object_properties", 1),
     (as3_class_singleton, "avm2/class_singleton", 1),
+    (as3_class_static_const_init, "avm2/class_static_const_init", 1),
     (as3_class_supercalls_mismatched, "avm2/class_supercalls_mismatched", 1),
     (as3_class_to_locale_string, "avm2/class_to_locale_string", 1),
     (as3_class_to_string, "avm2/class_to_string", 1),
     (as3_class_value_of, "avm2/class_value_of", 1),
     (as3_closures, "avm2/closures", 1),
+    (as3_coerce_error_catch, "avm2/coerce_error_catch", 1),
     (as3_coerce_property, "avm2/coerce_property", 1),
     (as3_coerce_string, "avm2/coerce_string", 1),
+    (as3_construct_subclass, "avm2/construct_subclass", 1),
     (as3_constructor_call, "avm2/constructor_call", 1),
     (as3_control_flow_bool, "avm2/control_flow_bool", 1),
     (as3_control_flow_stricteq, "avm2/control_flow_stricteq", 1),
@@ -237,11 +246,15 @@ swf_tests! {
     (as3_convert_uinteger, "avm2/convert_uinteger", 1),
     (as3_date_parse, "avm2/date_parse", 1),
     (as3_date, "avm2/date", 1),
+    (as3_date_locale_strings, "avm2/date_locale_strings", 1),
+    (as3_date_numeric_coercion, "avm2/date_numeric_coercion", 1),
     (as3_declocal_i, "avm2/declocal_i", 1),
     (as3_declocal, "avm2/declocal", 1),
     (as3_decrement_i, "avm2/decrement_i", 1),
     (as3_decrement, "avm2/decrement", 1),
     (as3_default_values, "avm2/default_values", 1),
+    (as3_delete_runtime_name, "avm2/delete_runtime_name", 1),
+    (as3_describe_type_packaged_class, "avm2/describe_type_packaged_class", 1),
     (as3_dictionary_access, "avm2/dictionary_access", 1),
     (as3_dictionary_delete, "avm2/dictionary_delete", 1),
     (as3_dictionary_foreach, "avm2/dictionary_foreach", 1),
@@ -250,10 +263,14 @@ swf_tests! {
     (as3_dictionary_namespaces, "avm2/dictionary_namespaces", 1),
     (as3_displayobject_alpha, "avm2/displayobject_alpha", 1),
     (as3_displayobject_filters, "avm2/displayobject_filters", 1),
+    (as3_displayobject_filters_round_trip, "avm2/displayobject_filters_round_trip", 1),
+    (as3_displayobject_hit_tests, "avm2/displayobject_hit_tests", 1),
     (as3_displayobject_blendmode, "avm2/displayobject_blendmode", 1, img = true),
+    (as3_displayobject_bounds_in_parent, "avm2/displayobject_bounds_in_parent", 1),
     (as3_displayobject_hittestobject, "avm2/displayobject_hittestobject", 1),
     (as3_displayobject_hittestpoint, "avm2/displayobject_hittestpoint", 2),
     (as3_displayobject_mask, "avm2/displayobject_mask", 1, img = true),
+    #[cfg_attr(not(feature = "imgtests"), ignore)] (as3_displayobject_mask_rendering, "avm2/displayobject_mask_rendering", 1, img = true),
     (as3_displayobject_name, "avm2/displayobject_name", 4),
     (as3_displayobject_parent, "avm2/displayobject_parent", 4),
     (as3_displayobject_root, "avm2/displayobject_root", 4),
@@ -267,6 +284,7 @@ swf_tests! {
     (as3_displayobjectcontainer_addchildat_timelinelock0, "avm2/displayobjectcontainer_addchildat_timelinelock0", 7),
     (as3_displayobjectcontainer_addchildat_timelinelock1, "avm2/displayobjectcontainer_addchildat_timelinelock1", 7),
     (as3_displayobjectcontainer_addchildat_timelinelock2, "avm2/displayobjectcontainer_addchildat_timelinelock2", 7),
+    (as3_displayobjectcontainer_child_order, "avm2/displayobjectcontainer_child_order", 1),
     (as3_displayobjectcontainer_addchildat, "avm2/displayobjectcontainer_addchildat", 1),
     (as3_displayobjectcontainer_contains, "avm2/displayobjectcontainer_contains", 5),
     (as3_displayobjectcontainer_getchildat, "avm2/displayobjectcontainer_getchildat", 1),
@@ -292,6 +310,7 @@ swf_tests! {
     (as3_edittext_newline_stripping, "avm2/edittext_newline_stripping", 1),
     (as3_edittext_width_height, "avm2/edittext_width_height", 1),
     (as3_equals, "avm2/equals", 1),
+    (as3_error_fields_and_sealed_writes, "avm2/error_fields_and_sealed_writes", 1),
     (as3_error_stack_trace, "avm2/error_stack_trace", 1),
     (as3_error_tostring, "avm2/error_tostring", 1),
     (as3_error_tostring_more, "avm2/error_tostring_more", 1),
@@ -325,11 +344,13 @@ swf_tests! {
     (as3_function_call_arguments, "avm2/function_call_arguments", 1),
     (as3_function_call_coercion, "avm2/function_call_coercion", 1),
     (as3_function_call_default, "avm2/function_call_default", 1),
+    (as3_function_call_default_coerce, "avm2/function_call_default_coerce", 1),
     (as3_function_call_rest, "avm2/function_call_rest", 1),
     (as3_function_call_types, "avm2/function_call_types", 1),
     (as3_function_call_via_apply, "avm2/function_call_via_apply", 1),
     (as3_function_call_via_call, "avm2/function_call_via_call", 1),
     (as3_function_call, "avm2/function_call", 1),
+    (as3_function_call_apply, "avm2/function_call_apply", 1),
     #[ignore] (as3_function_proto, "avm2/function_proto", 1),
     (as3_function_length, "avm2/function_length", 1),
     (as3_function_object, "avm2/function_object", 1),
@@ -339,12 +360,15 @@ swf_tests! {
     (as3_function_value_of, "avm2/function_value_of", 1),
     (as3_generate_random_bytes, "avm2/generate_random_bytes", 1),
     (as3_get_definition_by_name, "avm2/get_definition_by_name", 1),
+    (as3_get_definition_by_name_user_class, "avm2/get_definition_by_name_user_class", 1),
     (as3_get_qualified_class_name, "avm2/get_qualified_class_name", 1),
     (as3_get_qualified_super_class_name, "avm2/get_qualified_super_class_name", 1),
     (as3_get_timer, "avm2/get_timer", 1),
     (as3_getouterscope, "avm2/getouterscope", 1),
+    (as3_global_functions, "avm2/global_functions", 1),
     (as3_goto_methods, "avm2/goto_methods", 1),
     (as3_goto_methods_swfver10, "avm2/goto_methods_swfver10", 1),
+    #[cfg_attr(not(feature = "imgtests"), ignore)] (as3_graphics_draw_triangles, "avm2/graphics_draw_triangles", 1, img = true),
     (as3_greaterequals, "avm2/greaterequals", 1),
     (as3_greaterthan, "avm2/greaterthan", 1),
     (as3_has_own_property, "avm2/has_own_property", 1),
@@ -373,6 +397,7 @@ swf_tests! {
     #[ignore] (as3_int_toprecision, "avm2/int_toprecision", 1), //Ignored because Flash Player has a print routine that adds extraneous zeros to things
     (as3_int_tostring, "avm2/int_tostring", 1),
     (as3_interactiveobject_enabled, "avm2/interactiveobject_enabled", 1),
+    (as3_interface_method_dispatch, "avm2/interface_method_dispatch", 1),
     (as3_interface_namespaces, "avm2/interface_namespaces", 1),
     (as3_invalid_utf8, "avm2/invalid_utf8", 1),
     (as3_is_finite, "avm2/is_finite", 1),
@@ -393,6 +418,7 @@ swf_tests! {
     (as3_lessthan, "avm2/lessthan", 1),
     (as3_loader_applicationdomain, "avm2/loader_applicationDomain", 2),
     (as3_loader_events, "avm2/loader_events", 3, img = true),
+    (as3_loader_load_url_and_bytes, "avm2/loader_load_url_and_bytes", 5),
     (as3_loader_loadbytes_events, "avm2/loader_loadbytes_events", 3, img = true),
     (as3_loaderinfo_events, "avm2/loaderinfo_events", 2),
     (as3_loaderinfo_properties, "avm2/loaderinfo_properties", 2),
@@ -400,6 +426,7 @@ swf_tests! {
     (as3_loaderinfo_quine, "avm2/loaderinfo_quine", 2),
     (as3_lshift, "avm2/lshift", 1),
     (as3_modulo, "avm2/modulo", 1),
+    (as3_mouse_wheel_delta, "avm2/mouse_wheel_delta", 2),
     (as3_mouseevent_constr, "avm2/mouseevent_constr", 1),
     (as3_mouseevent_stagexy, "avm2/mouseevent_stagexy", 1),
     (as3_mouseevent_valueof_tostring, "avm2/mouseevent_valueof_tostring", 1),
@@ -432,6 +459,7 @@ swf_tests! {
     (as3_movieclip_displayevents, "avm2/movieclip_displayevents", 9),
     (as3_movieclip_drawrect, "avm2/movieclip_drawrect", 1),
     (as3_movieclip_goto_during_frame_script, "avm2/movieclip_goto_during_frame_script", 1),
+    (as3_movieclip_goto_labels_scenes, "avm2/movieclip_goto_labels_scenes", 1),
     (as3_movieclip_gotoandplay, "avm2/movieclip_gotoandplay", 5),
     (as3_movieclip_gotoandstop, "avm2/movieclip_gotoandstop", 5),
     (as3_movieclip_gotoandstop_children, "avm2/movieclip_gotoandstop_children", 1),
@@ -456,9 +484,11 @@ swf_tests! {
     (as3_negate, "avm2/negate", 1),
     (as3_nonconflicting_declarations, "avm2/nonconflicting_declarations", 1),
     (as3_number_constr, "avm2/number_constr", 1),
+    (as3_number_format_and_coercion, "avm2/number_format_and_coercion", 1),
     #[ignore] (as3_number_tostring, "avm2/number_tostring", 1), //Ignored because Flash Player adds extra x, W, and/or ° symbols randomly
     (as3_object_enumeration, "avm2/object_enumeration", 1),
     (as3_object_prototype, "avm2/object_prototype", 1),
+    (as3_object_prototype_methods, "avm2/object_prototype_methods", 1),
     (as3_object_to_locale_string, "avm2/object_to_locale_string", 1),
     (as3_object_to_string, "avm2/object_to_string", 1),
     (as3_object_value_of, "avm2/object_value_of", 1),
@@ -476,9 +506,12 @@ swf_tests! {
     (as3_property_is_enumerable, "avm2/property_is_enumerable", 1),
     (as3_propertyisenumerable_namespaces, "avm2/propertyisenumerable_namespaces", 1),
     (as3_proxy_callproperty, "avm2/proxy_callproperty", 1),
+    (as3_proxy_callproperty_arguments, "avm2/proxy_callproperty_arguments", 1),
     (as3_proxy_deleteproperty, "avm2/proxy_deleteproperty", 1),
     (as3_proxy_enumeration, "avm2/proxy_enumeration", 1),
+    (as3_proxy_enumeration_callbacks, "avm2/proxy_enumeration_callbacks", 1),
     (as3_proxy_getproperty, "avm2/proxy_getproperty", 1),
+    (as3_proxy_getproperty_any_name, "avm2/proxy_getproperty_any_name", 1),
     (as3_proxy_hasproperty, "avm2/proxy_hasproperty", 1),
     (as3_proxy_setproperty, "avm2/proxy_setproperty", 1),
     (as3_qname_constr_namespace, "avm2/qname_constr_namespace", 1),
@@ -493,6 +526,7 @@ swf_tests! {
     (as3_regexp_test, "avm2/regexp_test", 1),
     (as3_rshift, "avm2/rshift", 1),
     (as3_scene_constr, "avm2/scene_constr", 5),
+    (as3_script_global_scope, "avm2/script_global_scope", 1),
     (as3_set_property_is_enumerable, "avm2/set_property_is_enumerable", 1),
     (as3_shape_drawrect, "avm2/shape_drawrect", 1),
     (as3_simplebutton_childevents_nested, "avm2/simplebutton_childevents_nested", 2),
@@ -550,6 +584,7 @@ swf_tests! {
     (as3_throw, "avm2/throw", 1),
     (as3_timeline_scripts, "avm2/timeline_scripts", 3),
     (as3_trace, "avm2/trace", 1),
+    (as3_transform_concatenated, "avm2/transform_concatenated", 1),
     (as3_truthiness, "avm2/truthiness", 1),
     (as3_typeof, "avm2/typeof", 1),
     (as3_uint_constr, "avm2/uint_constr", 1),
@@ -584,10 +619,13 @@ swf_tests! {
     (as3_vector_sort, "avm2/vector_sort", 1),
     (as3_vector_splice, "avm2/vector_splice", 1),
     (as3_vector_tostring, "avm2/vector_tostring", 1),
+    (as3_vector_typed_coercion, "avm2/vector_typed_coercion", 1),
     (as3_virtual_properties, "avm2/virtual_properties", 1),
     (as3_with, "avm2/with", 1),
+    (as3_with_prototype_lookup, "avm2/with_prototype_lookup", 1),
     (as3_escape, "avm2/escape", 1),
     (as3_escape_multi_byte, "avm2/escape_multi_byte", 1),
+    #[cfg_attr(not(feature = "imgtests"), ignore)] (attach_bitmap_render, "avm1/attach_bitmap_render", 1, img = true),
     (attach_movie, "avm1/attach_movie", 1),
     (bad_placeobject_clipaction, "avm1/bad_placeobject_clipaction", 2),
     (bad_swf_tag_past_eof, "avm1/bad_swf_tag_past_eof", 1),
@@ -600,6 +638,7 @@ swf_tests! {
     (bitmap_data_noise, "avm1/bitmap_data_noise", 1),
     (bitmap_data_threshold, "avm1/bitmap_data_threshold", 1),
     (bitmap_filter, "avm1/bitmap_filter", 1),
+    #[cfg_attr(not(feature = "imgtests"), ignore)] (bitmapdata_draw_clip_rect, "avm1/bitmapdata_draw_clip_rect", 1, img = true),
     (biturshift, "avm1/biturshift", 1),
     (biturshift_swf8, "avm1/biturshift_swf8", 1),
     (blur_filter, "avm1/blur_filter", 1),
@@ -607,8 +646,11 @@ swf_tests! {
     (button_order, "avm1/button_order", 2),
     (call_method_empty_name, "avm1/call_method_empty_name", 1),
     (call, "avm1/call", 2),
+    (call_frame_label, "avm1/call_frame_label", 1),
+    (clip_event_order, "avm1/clip_event_order", 3),
     (clip_events, "avm1/clip_events", 4),
     (closure_scope, "avm1/closure_scope", 1),
+    (collection_methods, "avm1/collection_methods", 1),
     (color_matrix_filter, "avm1/color_matrix_filter", 1),
     (color_transform, "avm1/color_transform", 1),
     (color, "avm1/color", 1, img = true),
@@ -631,6 +673,7 @@ swf_tests! {
     (displacement_map_filter, "avm1/displacement_map_filter", 1),
     (divide_swf4, "avm1/divide_swf4", 1),
     (do_init_action, "avm1/do_init_action", 3),
+    (do_init_action_once, "avm1/do_init_action_once", 1),
     (drag_drop, "avm1/drag_drop", 14),
     (drop_shadow_filter, "avm1/drop_shadow_filter", 1),
     (duplicate_movie_clip_drawing, "avm1/duplicate_movie_clip_drawing", 1),
@@ -638,13 +681,19 @@ swf_tests! {
     (edittext_antialiastype, "avm1/edittext_antialiastype", 1),
     (edittext_default_format, "avm1/edittext_default_format", 1),
     (edittext_font_size, "avm1/edittext_font_size", 1),
+    (edittext_html_entities, "avm1/edittext_html_entities", 1),
     (edittext_html_entity, "avm1/edittext_html_entity", 1),
     (edittext_html_roundtrip, "avm1/edittext_html_roundtrip", 1),
+    (edittext_ime_composition, "avm1/edittext_ime_composition", 8),
+    (edittext_keyboard_input, "avm1/edittext_keyboard_input", 9),
     (edittext_leading, "avm1/edittext_leading", 1),
     (edittext_newline_stripping, "avm1/edittext_newline_stripping", 1),
     #[ignore] (edittext_newlines, "avm1/edittext_newlines", 1),
     (edittext_password, "avm1/edittext_password", 1),
+    (edittext_relayout_after_resize, "avm1/edittext_relayout_after_resize", 1),
     (edittext_scroll, "avm1/edittext_scroll", 1),
+    #[cfg_attr(not(feature = "imgtests"), ignore)] (edittext_selection_highlight, "avm1/edittext_selection_highlight", 1, img = true),
+    (edittext_variable_binding, "avm1/edittext_variable_binding", 2),
     (edittext_width_height, "avm1/edittext_width_height", 1),
     (empty_movieclip_can_attach_movies, "avm1/empty_movieclip_can_attach_movies", 1),
     (enumerate, "avm1/enumerate", 1),
@@ -672,8 +721,10 @@ swf_tests! {
     (get_bytes_total, "avm1/get_bytes_total", 1),
     (getproperty_swf4, "avm1/getproperty_swf4", 1),
     (getproperty_swf5, "avm1/getproperty_swf5", 1),
+    (geturl_level_target, "avm1/geturl_level_target", 3),
     (getproperty, "avm1/getproperty", 1),
     (get_variable_in_scope, "avm1/get_variable_in_scope", 1),
+    (get_version, "avm1/get_version", 1),
     (global_array, "avm1/global_array", 1),
     (global_is_bare, "avm1/global_is_bare", 1),
     (glow_filter, "avm1/glow_filter", 1),
@@ -721,6 +772,7 @@ swf_tests! {
     (issue_4377, "avm1/issue_4377", 1),
     (issue_710, "avm1/issue_710", 1),
     (issue_768, "avm1/issue_768", 1),
+    (key_listener_is_down, "avm1/key_listener_is_down", 3),
     (lessthan_swf4, "avm1/lessthan_swf4", 1),
     (lessthan_swf4_alt, "avm1/lessthan_swf4_alt", 1),
     (lessthan_swf5, "avm1/lessthan_swf5", 1),
@@ -728,16 +780,19 @@ swf_tests! {
     (lessthan2_swf5, "avm1/lessthan2_swf5", 1),
     (lessthan2_swf6, "avm1/lessthan2_swf6", 1),
     (lessthan2_swf7, "avm1/lessthan2_swf7", 1),
+    #[cfg_attr(not(feature = "imgtests"), ignore)] (levels_render_order, "avm1/levels_render_order", 3, img = true),
     (load_vars, "avm1/load_vars", 2),
     (loadmovie_fail, "avm1/loadmovie_fail", 1),
     (loadmovie_method, "avm1/loadmovie_method", 2),
     (loadmovie_registerclass, "avm1/loadmovie_registerclass", 2),
     (loadmovie_replace_root, "avm1/loadmovie_replace_root", 3),
     (loadmovie, "avm1/loadmovie", 2),
+    (loadmovie_bytes_loaded, "avm1/loadmovie_bytes_loaded", 3),
     (loadmovienum, "avm1/loadmovienum", 2),
     (loadvariables_method, "avm1/loadvariables_method", 3),
     (loadvariables, "avm1/loadvariables", 3),
     (loadvariablesnum, "avm1/loadvariablesnum", 3),
+    (local_connection, "avm1/local_connection", 2),
     (logical_ops_swf4, "avm1/logical_ops_swf4", 1),
     (logical_ops_swf8, "avm1/logical_ops_swf8", 1),
     (looping, "avm1/looping", 6),
@@ -754,14 +809,18 @@ swf_tests! {
     (mouse_listeners, "avm1/mouse_listeners", 1),
     (mouse_events, "avm1/mouse_events", 8),
     (movieclip_depth_methods, "avm1/movieclip_depth_methods", 3),
+    (movieclip_filters_round_trip, "avm1/movieclip_filters_round_trip", 1),
     (movieclip_get_instance_at_depth, "avm1/movieclip_get_instance_at_depth", 1),
     (movieclip_hittest_shapeflag, "avm1/movieclip_hittest_shapeflag", 11),
     (movieclip_hittest, "avm1/movieclip_hittest", 1),
     (movieclip_init_object, "avm1/movieclip_init_object", 1),
     (movieclip_lockroot, "avm1/movieclip_lockroot", 10),
     (movieclip_prototype_extension, "avm1/movieclip_prototype_extension", 1),
+    (movieclip_replace_and_set_depth, "avm1/movieclip_replace_and_set_depth", 1),
+    (moviecliploader_events, "avm1/moviecliploader_events", 3),
     (nan_scale, "avm1/nan_scale", 1),
     (nested_textfields_in_buttons, "avm1/nested_textfields_in_buttons", 1),
+    (netstream_play_flv, "avm1/netstream_play_flv", 6),
     (new_method_wrap, "avm1/new_method_wrap", 1),
     (new_object_enumerate, "avm1/new_object_enumerate", 1),
     (new_object_wrap, "avm1/new_object_wrap", 1),
@@ -785,13 +844,16 @@ swf_tests! {
     (register_class_return_value, "avm1/register_class_return_value", 1),
     (register_class_swf6, "avm1/register_class_swf6", 3),
     (register_class, "avm1/register_class", 3),
+    (register_class_attach_movie, "avm1/register_class_attach_movie", 1),
     (register_underflow, "avm1/register_underflow", 1),
     (remove_movie_clip, "avm1/remove_movie_clip", 2),
     (removed_base_clip_tell_target, "avm1/removed_base_clip_tell_target", 2),
     (removed_clip_halts_script, "avm1/removed_clip_halts_script", 13),
     (root_global_parent, "avm1/root_global_parent", 3),
+    #[cfg_attr(not(feature = "imgtests"), ignore)] (scale9_grid, "avm1/scale9_grid", 1, img = true),
     (selection, "avm1/selection", 1),
     (set_interval, "avm1/set_interval", 40),
+    (set_interval_arguments, "avm1/set_interval_arguments", 16),
     (set_variable_scope, "avm1/set_variable_scope", 1),
     (single_frame, "avm1/single_frame", 2),
     (slash_syntax, "avm1/slash_syntax", 2),
@@ -801,6 +863,7 @@ swf_tests! {
     (stage_object_enumerate, "avm1/stage_object_enumerate", 1),
     (stage_object_properties_get_var, "avm1/stage_object_properties_get_var", 1),
     (stage_property_representation, "avm1/stage_property_representation", 1),
+    (startdrag_constraint, "avm1/startdrag_constraint", 5),
     (strictequals_swf6, "avm1/strictequals_swf6", 1),
     (strictly_equals, "avm1/strictly_equals", 1),
     (string_coercion, "avm1/string_coercion", 1),
@@ -817,6 +880,7 @@ swf_tests! {
     (swf6_case_insensitive, "avm1/swf6_case_insensitive", 1),
     (swf6_to_5_cross_call, "avm1/swf6_to_5_cross_call", 2),
     (swf7_case_sensitive, "avm1/swf7_case_sensitive", 1),
+    (target_and_droptarget, "avm1/target_and_droptarget", 3),
     (target_clip_removed, "avm1/target_clip_removed", 1),
     (target_clip_swf5, "avm1/target_clip_swf5", 2),
     (target_clip_swf6, "avm1/target_clip_swf6", 2),
@@ -834,7 +898,9 @@ swf_tests! {
     (avm2_timer, "avm2/timer", 280, frame_time_sleep = true),
     (timer_run_actions, "avm1/timer_run_actions", 1),
     (trace, "avm1/trace", 1),
+    (trace_carriage_return, "avm1/trace_carriage_return", 1),
     (transform, "avm1/transform", 1),
+    (transform_properties_preserve, "avm1/transform_properties_preserve", 1),
     (try_catch_finally, "avm1/try_catch_finally", 1),
     (try_finally_simple, "avm1/try_finally_simple", 1),
     (typeof_globals, "avm1/typeof_globals", 1),
@@ -861,6 +927,7 @@ swf_tests! {
     #[cfg_attr(not(feature = "imgtests"), ignore)] (visual_blendmodes_overlay, "visual/blend_modes/overlay", 1, img = true),
     #[cfg_attr(not(feature = "imgtests"), ignore)] (visual_blendmodes_screen, "visual/blend_modes/screen", 1, img = true),
     #[cfg_attr(not(feature = "imgtests"), ignore)] (visual_blendmodes_subtract, "visual/blend_modes/subtract", 1, img = true),
+    #[cfg_attr(not(feature = "imgtests"), ignore)] (visual_tinted_translucent_bitmap, "visual/tinted_translucent_bitmap", 1, img = true),
     (waitforframe, "avm1/waitforframe", 1),
     (watch_textfield, "avm1/watch_textfield", 1),
     (watch_virtual_property_proto, "avm1/watch_virtual_property_proto", 1),
@@ -868,6 +935,7 @@ swf_tests! {
     (watch, "avm1/watch", 1),
     (with_return, "avm1/with_return", 1),
     (with, "avm1/with", 1),
+    (with_nested, "avm1/with_nested", 1),
     (with_variable_scopes, "avm1/with_variable_scopes", 1),
     (xml_append_child_with_parent, "avm1/xml_append_child_with_parent", 1),
     (xml_append_child, "avm1/xml_append_child", 1),
@@ -889,6 +957,7 @@ swf_tests! {
     (xml_parent_and_child, "avm1/xml_parent_and_child", 1),
     (xml_remove_node, "avm1/xml_remove_node", 1),
     (xml_reparenting, "avm1/xml_reparenting", 1),
+    (xml_round_trip, "avm1/xml_round_trip", 1),
     (xml_siblings, "avm1/xml_siblings", 1),
     (xml_to_string_comment, "avm1/xml_to_string_comment", 1),
     (xml_to_string, "avm1/xml_to_string", 1),
@@ -1000,6 +1069,198 @@ fn external_interface_avm1() -> Result<(), Error> {
     )
 }
 
+#[test]
+fn external_interface_sandbox_avm1() -> Result<(), Error> {
+    set_logger();
+    let provider = ExternalInterfaceTestProvider::new();
+    let callbacks = provider.callbacks.clone();
+    test_swf_with_hooks(
+        "tests/swfs/avm1/external_interface_sandbox/test.swf",
+        1,
+        "tests/swfs/avm1/external_interface_sandbox/input.json",
+        "tests/swfs/avm1/external_interface_sandbox/output.txt",
+        |player| {
+            player
+                .lock()
+                .unwrap()
+                .add_external_interface(Box::new(provider));
+            Ok(())
+        },
+        |player| {
+            let mut player_locked = player.lock().unwrap();
+            player_locked
+                .log_backend()
+                .avm_trace(&format!("Announced callbacks: {:?}", callbacks.borrow()));
+
+            let greeting = player_locked.call_internal_interface("greet", vec!["ruffle".into()]);
+            player_locked.log_backend().avm_trace(&format!(
+                "After calling `greet` with a string: {greeting:?}",
+            ));
+
+            // The local-with-file sandbox can't call out of the player.
+            player_locked
+                .update(|context| context.system.sandbox_type = SandboxType::LocalWithFile);
+            player_locked.call_internal_interface("checkSandbox", vec![]);
+            Ok(())
+        },
+        false,
+        false,
+    )
+}
+
+#[test]
+fn seeded_random_avm1() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm1/seeded_random/test.swf",
+        1,
+        "tests/swfs/avm1/seeded_random/input.json",
+        "tests/swfs/avm1/seeded_random/output.txt",
+        |player| {
+            let mut player_locked = player.lock().unwrap();
+            player_locked.set_rng_seed(42);
+            player_locked.add_external_interface(Box::new(ExternalInterfaceTestProvider::new()));
+            Ok(())
+        },
+        |player| {
+            let mut player_locked = player.lock().unwrap();
+            for seed in [42, 43] {
+                player_locked.set_rng_seed(seed);
+                player_locked
+                    .log_backend()
+                    .avm_trace(&format!("Reseeded with {seed}"));
+                player_locked.call_internal_interface("compareSequence", vec![]);
+            }
+            Ok(())
+        },
+        false,
+        false,
+    )
+}
+
+#[test]
+fn simulated_clock_avm1() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm1/simulated_clock/test.swf",
+        3,
+        "tests/swfs/avm1/simulated_clock/input.json",
+        "tests/swfs/avm1/simulated_clock/output.txt",
+        |_| Ok(()),
+        |player| {
+            let mut player_locked = player.lock().unwrap();
+            player_locked.set_is_playing(true);
+            player_locked.tick(250.0);
+            player_locked.tick(16.0);
+            let time = player_locked.update(|context| context.get_timer());
+            player_locked
+                .log_backend()
+                .avm_trace(&format!("getTimer() after ticking 266ms: {time}"));
+            Ok(())
+        },
+        false,
+        false,
+    )
+}
+
+#[test]
+fn paused_clock_avm1() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm1/paused_clock/test.swf",
+        2,
+        "tests/swfs/avm1/paused_clock/input.json",
+        "tests/swfs/avm1/paused_clock/output.txt",
+        |_| Ok(()),
+        |player| {
+            let mut player_locked = player.lock().unwrap();
+            player_locked.set_paused(true);
+            let time = player_locked.update(|context| context.get_timer());
+            let message = format!(
+                "Paused: is_paused() = {}, is_playing() = {}, getTimer() = {time}",
+                player_locked.is_paused(),
+                player_locked.is_playing()
+            );
+            player_locked.log_backend().avm_trace(&message);
+
+            // Ticks don't advance a paused player, but stepping does.
+            player_locked.tick(2000.0);
+            let time = player_locked.update(|context| context.get_timer());
+            player_locked.log_backend().avm_trace(&format!(
+                "getTimer() after ticking 2000ms while paused: {time}"
+            ));
+            player_locked.step_one_frame();
+            let time = player_locked.update(|context| context.get_timer());
+            player_locked
+                .log_backend()
+                .avm_trace(&format!("getTimer() after stepping one frame: {time}"));
+
+            // Resuming continues from the frozen time, and stepping only works while paused.
+            player_locked.set_paused(false);
+            let time = player_locked.update(|context| context.get_timer());
+            let message = format!(
+                "Resumed: is_paused() = {}, is_playing() = {}, getTimer() = {time}",
+                player_locked.is_paused(),
+                player_locked.is_playing()
+            );
+            player_locked.log_backend().avm_trace(&message);
+            player_locked.step_one_frame();
+            let time = player_locked.update(|context| context.get_timer());
+            player_locked.log_backend().avm_trace(&format!(
+                "getTimer() after stepping a playing player: {time}"
+            ));
+
+            // Starting playback also unfreezes the clock of a paused player.
+            player_locked.set_paused(true);
+            player_locked.set_is_playing(true);
+            let message = format!(
+                "Playing a paused player: is_paused() = {}",
+                player_locked.is_paused()
+            );
+            player_locked.log_backend().avm_trace(&message);
+            player_locked.tick(50.0);
+            let time = player_locked.update(|context| context.get_timer());
+            player_locked
+                .log_backend()
+                .avm_trace(&format!("getTimer() after ticking 50ms: {time}"));
+            Ok(())
+        },
+        false,
+        false,
+    )
+}
+
+#[test]
+fn fscommand_avm1() -> Result<(), Error> {
+    set_logger();
+    let provider = FsCommandTestProvider::default();
+    let commands = provider.commands.clone();
+    test_swf_with_hooks(
+        "tests/swfs/avm1/fscommand_player_commands/test.swf",
+        1,
+        "tests/swfs/avm1/fscommand_player_commands/input.json",
+        "tests/swfs/avm1/fscommand_player_commands/output.txt",
+        |player| {
+            player
+                .lock()
+                .unwrap()
+                .add_external_interface(Box::new(provider));
+            Ok(())
+        },
+        |player| {
+            let player_locked = player.lock().unwrap();
+            for command in commands.borrow().iter() {
+                player_locked
+                    .log_backend()
+                    .avm_trace(&format!("[fscommand] {command}"));
+            }
+            Ok(())
+        },
+        false,
+        false,
+    )
+}
+
 #[test]
 fn external_interface_avm2() -> Result<(), Error> {
     set_logger();
@@ -1175,6 +1436,69 @@ fn timeout_avm1() -> Result<(), Error> {
     )
 }
 
+#[test]
+fn script_timeout_halts_avm1() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm1/script_timeout_halts/test.swf",
+        2,
+        "tests/swfs/avm1/script_timeout_halts/input.json",
+        "tests/swfs/avm1/script_timeout_halts/output.txt",
+        |player| {
+            player
+                .lock()
+                .unwrap()
+                .set_max_execution_duration(Duration::ZERO);
+            Ok(())
+        },
+        |_| Ok(()),
+        false,
+        false,
+    )
+}
+
+#[test]
+fn script_timeout_avm2() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm2/script_timeout_error/test.swf",
+        1,
+        "tests/swfs/avm2/script_timeout_error/input.json",
+        "tests/swfs/avm2/script_timeout_error/output.txt",
+        |player| {
+            player
+                .lock()
+                .unwrap()
+                .set_max_execution_duration(Duration::from_secs(2));
+            Ok(())
+        },
+        |_| Ok(()),
+        false,
+        false,
+    )
+}
+
+#[test]
+fn frame_script_instruction_budget() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm2/frame_script_instruction_budget/test.swf",
+        30,
+        "tests/swfs/avm2/frame_script_instruction_budget/input.json",
+        "tests/swfs/avm2/frame_script_instruction_budget/output.txt",
+        |player| {
+            player
+                .lock()
+                .unwrap()
+                .set_avm2_instruction_budget(Some(20000));
+            Ok(())
+        },
+        |_| Ok(()),
+        false,
+        false,
+    )
+}
+
 #[test]
 fn stage_scale_mode() -> Result<(), Error> {
     set_logger();
@@ -1201,6 +1525,149 @@ fn stage_scale_mode() -> Result<(), Error> {
     )
 }
 
+#[test]
+fn stage_scale_mode_resize_avm2() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm2/stage_scale_mode_resize/test.swf",
+        1,
+        "tests/swfs/avm2/stage_scale_mode_resize/input.json",
+        "tests/swfs/avm2/stage_scale_mode_resize/output.txt",
+        |player| {
+            player
+                .lock()
+                .unwrap()
+                .set_viewport_dimensions(ViewportDimensions {
+                    width: 800,
+                    height: 600,
+                    scale_factor: 1.0,
+                });
+            Ok(())
+        },
+        |player| {
+            let mut player = player.lock().unwrap();
+            let viewport = ViewportDimensions {
+                width: 1024,
+                height: 768,
+                scale_factor: 1.0,
+            };
+            player.set_viewport_dimensions(viewport);
+            // An unchanged size does not fire another event.
+            player.set_viewport_dimensions(viewport);
+            Ok(())
+        },
+        false,
+        false,
+    )
+}
+
+#[test]
+fn stage_frame_rate_avm2() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm2/stage_frame_rate/test.swf",
+        1,
+        "tests/swfs/avm2/stage_frame_rate/input.json",
+        "tests/swfs/avm2/stage_frame_rate/output.txt",
+        |_| Ok(()),
+        |player| {
+            let player = player.lock().unwrap();
+            assert_eq!(player.frame_rate(), 1000.0);
+            assert_eq!(player.time_til_next_frame(), Duration::from_millis(1));
+            Ok(())
+        },
+        false,
+        false,
+    )
+}
+
+#[test]
+fn date_locale_strings_custom_locale_avm2() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm2/date_locale_strings/test.swf",
+        1,
+        "tests/swfs/avm2/date_locale_strings/input.json",
+        "tests/swfs/avm2/date_locale_strings/output_de.txt",
+        |player| {
+            *player.lock().unwrap().locale_mut() = Box::new(GermanLocaleBackend);
+            Ok(())
+        },
+        |_| Ok(()),
+        false,
+        false,
+    )
+}
+
+#[test]
+fn security_sandbox_fetches_avm2() -> Result<(), Error> {
+    const MOVIE_URL: Option<&str> = Some("https://ruffle.rs/movie.swf");
+
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm2/security_sandbox_fetches/test.swf",
+        1,
+        "tests/swfs/avm2/security_sandbox_fetches/input.json",
+        "tests/swfs/avm2/security_sandbox_fetches/output.txt",
+        |player| {
+            player.lock().unwrap().update(|context| {
+                let system = &mut *context.system;
+                system.sandbox_type = SandboxType::Remote;
+                assert!(system.can_fetch(MOVIE_URL, "https://ruffle.rs/data.txt"));
+                assert!(system.can_fetch(MOVIE_URL, "data.txt"));
+                assert!(!system.can_fetch(MOVIE_URL, "https://example.com/data.txt"));
+                assert!(!system.can_fetch(MOVIE_URL, "file:///data.txt"));
+            });
+            Ok(())
+        },
+        |player| {
+            player.lock().unwrap().update(|context| {
+                let system = &mut *context.system;
+                // The movie called `Security.allowDomain("example.com")`.
+                assert!(system.can_fetch(MOVIE_URL, "https://example.com/data.txt"));
+                assert!(!system.can_fetch(MOVIE_URL, "https://example.org/data.txt"));
+
+                system.sandbox_type = SandboxType::LocalWithFile;
+                assert!(system.can_fetch(MOVIE_URL, "file:///data.txt"));
+                assert!(!system.can_fetch(MOVIE_URL, "https://example.com/data.txt"));
+
+                system.sandbox_type = SandboxType::LocalWithNetwork;
+                assert!(!system.can_fetch(MOVIE_URL, "file:///data.txt"));
+                assert!(system.can_fetch(MOVIE_URL, "https://example.com/data.txt"));
+            });
+            Ok(())
+        },
+        false,
+        false,
+    )
+}
+
+#[test]
+fn context_menu_custom_items_avm2() -> Result<(), Error> {
+    set_logger();
+    test_swf_with_hooks(
+        "tests/swfs/avm2/context_menu_custom_items/test.swf",
+        1,
+        "tests/swfs/avm2/context_menu_custom_items/input.json",
+        "tests/swfs/avm2/context_menu_custom_items/output.txt",
+        |_| Ok(()),
+        |player| {
+            let mut player = player.lock().unwrap();
+
+            // Built-in items are hidden, and invisible custom items are skipped.
+            let items = player.prepare_context_menu();
+            let captions: Vec<_> = items.iter().map(|item| item.caption.as_str()).collect();
+            assert!(captions.ends_with(&["First", "Second"]));
+            assert!(!captions.contains(&"Hidden"));
+
+            player.run_context_menu_callback(items.len() - 1);
+            Ok(())
+        },
+        false,
+        false,
+    )
+}
+
 /// Wrapper around string slice that makes debug output `{:?}` to print string same way as `{}`.
 /// Used in different `assert*!` macros in combination with `pretty_assertions` crate to make
 /// test failures to show nice diffs.
@@ -1448,6 +1915,7 @@ fn run_swf(
         .with_log(TestLogBackend::new(trace_output.clone()))
         .with_navigator(NullNavigatorBackend::with_base_path(base_path, &executor)?)
         .with_max_execution_duration(Duration::from_secs(300))
+        .with_simulated_clock(true)
         .with_viewport_dimensions(
             movie.width().to_pixels() as u32,
             movie.height().to_pixels() as u32,
@@ -1460,18 +1928,14 @@ fn run_swf(
 
     for _ in 0..num_frames {
         // If requested, ensure that the 'expected' amount of
-        // time actually elapses between frames. This is useful for
-        // tests that call 'flash.utils.getTimer()' and use
-        // 'setInterval'/'flash.utils.Timer'
+        // wall-clock time actually elapses between frames.
         //
         // Note that when Ruffle actually runs frames, we can
         // execute frames faster than this in order to 'catch up'
         // if we've fallen behind. However, in order to make regression
-        // tests deterministic, we always call 'update_timers' with
-        // an elapsed time of 'frame_time'. By sleeping for 'frame_time_duration',
-        // we ensure that the result of 'flash.utils.getTimer()' is consistent
-        // with timer execution (timers will see an elapsed time of *at least*
-        // the requested timer interval).
+        // tests deterministic, the movie runs on a simulated clock, which we
+        // always advance by 'frame_time' along with 'update_timers'. This keeps
+        // 'flash.utils.getTimer()' consistent with timer execution.
         if frame_time_sleep {
             std::thread::sleep(frame_time_duration);
         }
@@ -1483,6 +1947,7 @@ fn run_swf(
         {}
 
         player.lock().unwrap().run_frame();
+        player.lock().unwrap().advance_simulated_clock(frame_time);
         player.lock().unwrap().update_timers(frame_time);
         executor.run();
 
@@ -1507,6 +1972,29 @@ fn run_swf(
                         InputMouseButton::Right => RuffleMouseButton::Right,
                     },
                 },
+                AutomatedEvent::MouseWheel { lines, pixels } => PlayerEvent::MouseWheel {
+                    delta: match (lines, pixels) {
+                        (Some(lines), None) => MouseWheelDelta::Lines(*lines),
+                        (None, Some(pixels)) => MouseWheelDelta::Pixels(*pixels),
+                        _ => panic!("MouseWheel: expected exactly one of 'lines' or 'pixels'"),
+                    },
+                },
+                AutomatedEvent::KeyDown { key_code } => PlayerEvent::KeyDown {
+                    key_code: KeyCode::from_u8(*key_code).expect("KeyDown: invalid key code"),
+                    key_char: None,
+                },
+                AutomatedEvent::KeyUp { key_code } => PlayerEvent::KeyUp {
+                    key_code: KeyCode::from_u8(*key_code).expect("KeyUp: invalid key code"),
+                    key_char: None,
+                },
+                AutomatedEvent::TextInput { codepoint } => PlayerEvent::TextInput {
+                    codepoint: *codepoint,
+                },
+                AutomatedEvent::ImePreedit { text, cursor } => PlayerEvent::ImePreedit {
+                    text: text.clone(),
+                    cursor: *cursor,
+                },
+                AutomatedEvent::ImeCommit { text } => PlayerEvent::ImeCommit { text: text.clone() },
                 AutomatedEvent::Wait => unreachable!(),
             });
         });
@@ -1580,8 +2068,35 @@ impl LogBackend for TestLogBackend {
     }
 }
 
+/// Formats dates and numbers the way a German locale does.
+struct GermanLocaleBackend;
+
+impl LocaleBackend for GermanLocaleBackend {
+    fn date_time_format(&self) -> &str {
+        "%d.%m.%Y %H:%M:%S"
+    }
+
+    fn date_format(&self) -> &str {
+        "%d.%m.%Y"
+    }
+
+    fn time_format(&self) -> &str {
+        "%H:%M:%S"
+    }
+
+    fn grouping_separator(&self) -> char {
+        '.'
+    }
+
+    fn decimal_separator(&self) -> char {
+        ','
+    }
+}
+
 #[derive(Default)]
-pub struct ExternalInterfaceTestProvider {}
+pub struct ExternalInterfaceTestProvider {
+    callbacks: Rc<RefCell<Vec<String>>>,
+}
 
 impl ExternalInterfaceTestProvider {
     pub fn new() -> Self {
@@ -1589,6 +2104,25 @@ impl ExternalInterfaceTestProvider {
     }
 }
 
+/// Records every FSCommand that is forwarded to the embedder.
+#[derive(Default)]
+pub struct FsCommandTestProvider {
+    commands: Rc<RefCell<Vec<String>>>,
+}
+
+impl ExternalInterfaceProvider for FsCommandTestProvider {
+    fn get_method(&self, _name: &str) -> Option<Box<dyn ExternalInterfaceMethod>> {
+        None
+    }
+
+    fn on_callback_available(&self, _name: &str) {}
+
+    fn on_fs_command(&self, command: &str, args: &str) -> bool {
+        self.commands.borrow_mut().push(format!("{command} {args}"));
+        true
+    }
+}
+
 fn do_trace(context: &mut UpdateContext<'_, '_>, args: &[ExternalValue]) -> ExternalValue {
     context.avm_trace(&format!("[ExternalInterface] trace: {args:?}"));
     "Traced!".into()
@@ -1622,7 +2156,9 @@ impl ExternalInterfaceProvider for ExternalInterfaceTestProvider {
         }
     }
 
-    fn on_callback_available(&self, _name: &str) {}
+    fn on_callback_available(&self, name: &str) {
+        self.callbacks.borrow_mut().push(name.to_string());
+    }
 
     fn on_fs_command(&self, _command: &str, _args: &str) -> bool {
         false
@@ -0,0 +1,13 @@
+// Hand-assembled SWF 8 with a 4x2 stage; this is the script of its only frame.
+
+var bitmapData = new flash.display.BitmapData(2, 2, true, 0);
+bitmapData.setPixel32(1, 0, 0xFF00FF00);
+
+trace("// (bitmapData.getPixel32(1, 0) >>> 0).toString(16)");
+trace((bitmapData.getPixel32(1, 0) >>> 0).toString(16));
+trace("");
+
+// The attached bitmap is drawn with the bitmap data's pixels.
+trace("// this.attachBitmap(bitmapData, 3, \"auto\", true)");
+this.attachBitmap(bitmapData, 3, "auto", true);
+trace("");
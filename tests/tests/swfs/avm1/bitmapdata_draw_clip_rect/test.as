@@ -0,0 +1,37 @@
+// Hand-assembled SWF 8 with a 30x30 stage; this is the script of its only frame.
+
+function hex(color) {
+	return "0x" + color.toString(16).toUpperCase();
+}
+
+// Draws `source` into a new white 30x30 bitmap, offset by (15, 5), and clipped to `clipRect` if given.
+function drawOffset(source, clipRect) {
+	var bitmapData = new flash.display.BitmapData(30, 30, false, 0xFFFFFF);
+	var matrix = new flash.geom.Matrix(1, 0, 0, 1, 15, 5);
+	if (clipRect) {
+		bitmapData.draw(source, matrix, undefined, "normal", clipRect);
+	} else {
+		bitmapData.draw(source, matrix);
+	}
+	return bitmapData;
+}
+
+var square = this.createEmptyMovieClip("square", 1);
+square.beginFill(0xFF0000);
+square.lineTo(10, 0);
+square.lineTo(10, 10);
+square.lineTo(0, 10);
+square.lineTo(0, 0);
+square.endFill();
+
+// The square covers (15, 5) to (25, 15) of the bitmap.
+var bitmapData = drawOffset(square);
+trace("// bitmapData.getPixel() at (16, 6), (24, 14), (5, 5) and (26, 10)");
+trace(hex(bitmapData.getPixel(16, 6)) + " " + hex(bitmapData.getPixel(24, 14)) + " " + hex(bitmapData.getPixel(5, 5)) + " " + hex(bitmapData.getPixel(26, 10)));
+trace("");
+
+// The clip rect is in bitmap space, and isn't transformed by the matrix.
+var bitmapData = drawOffset(square, new flash.geom.Rectangle(0, 0, 20, 30));
+trace("// bitmapData.getPixel() at (16, 6) and (24, 6) when clipped to (0, 0, 20, 30)");
+trace(hex(bitmapData.getPixel(16, 6)) + " " + hex(bitmapData.getPixel(24, 6)));
+trace("");
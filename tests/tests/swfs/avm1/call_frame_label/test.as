@@ -0,0 +1,28 @@
+// Hand-assembled SWF 8; each "Frame" section below is the script of that frame.
+// Frame 2 is labelled "second".
+
+// Frame 1
+var count = 0;
+
+// Frame 2's actions run once for each call, without leaving frame 1.
+trace("// call(2)");
+call(2);
+trace("");
+
+trace("// call(\"second\")");
+call("second");
+trace("");
+
+trace("// count");
+trace(count);
+trace("");
+
+trace("// _currentframe");
+trace(_currentframe);
+trace("");
+
+stop();
+
+// Frame 2
+trace("Frame 2 actions");
+count++;
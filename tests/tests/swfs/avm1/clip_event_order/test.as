@@ -0,0 +1,24 @@
+// Hand-assembled SWF 8; each "Frame" section below is the script of that frame.
+
+// Frame 1
+function onEnterFrameHandler() {
+	trace(this._name + ".onEnterFrame");
+}
+
+function onUnloadHandler() {
+	trace(this._name + ".onUnload");
+}
+
+// Clips run their frames in reverse instantiation order, so children
+// created after their parents receive onEnterFrame first.
+var parentClip = this.createEmptyMovieClip("parent", 1);
+var childClip = parentClip.createEmptyMovieClip("child", 1);
+parentClip.onEnterFrame = onEnterFrameHandler;
+parentClip.onUnload = onUnloadHandler;
+childClip.onEnterFrame = onEnterFrameHandler;
+childClip.onUnload = onUnloadHandler;
+
+// Frame 3
+// Removing a clip unloads its children before itself.
+trace("// parentClip.removeMovieClip()");
+parentClip.removeMovieClip();
@@ -0,0 +1,44 @@
+// Hand-assembled SWF 8; this is the script of its only frame.
+
+function scores(array) {
+	var result = [];
+	for (var i = 0; i < array.length; i++) {
+		result.push(array[i].score);
+	}
+	return result.join(",");
+}
+
+var items = [{score: 10}, {score: 9}, {score: 100}];
+
+// By default, fields are compared as strings.
+trace("// items.sortOn(\"score\")");
+items.sortOn("score");
+trace(scores(items));
+trace("");
+
+trace("// items.sortOn(\"score\", Array.NUMERIC)");
+items.sortOn("score", Array.NUMERIC);
+trace(scores(items));
+trace("");
+
+var array = [1, 2, 3, 4, 5];
+trace("// array.splice(1, 2, \"a\", \"b\", \"c\")");
+trace(array.splice(1, 2, "a", "b", "c").join(","));
+trace("");
+
+trace("// array");
+trace(array.join(","));
+trace("");
+
+var string = new String("a::b::::c");
+trace("// string.split(\"::\")");
+var parts = string.split("::");
+trace(parts.length);
+trace(parts.join("|"));
+trace("");
+
+trace("// string.split(\"::\", 2)");
+parts = string.split("::", 2);
+trace(parts.length);
+trace(parts.join("|"));
+trace("");
@@ -0,0 +1,18 @@
+// Hand-assembled SWF 8; this is the script of its only frame.
+// The movie exports an empty sprite as "widget", and has two DoInitAction blocks for it:
+// - the first one traces "First init action" and registers `_global.Widget`, whose
+//   constructor sets `ready` to true, as the sprite's class;
+// - the second one traces "Second init action" and registers `null` as the sprite's class.
+// Each sprite's init actions only run once, so the second block is skipped.
+
+trace("// this.attachMovie(\"widget\", \"w\", 1)");
+var instance = this.attachMovie("widget", "w", 1);
+trace("");
+
+trace("// instance instanceof Widget");
+trace(instance instanceof Widget);
+trace("");
+
+trace("// instance.ready");
+trace(instance.ready);
+trace("");
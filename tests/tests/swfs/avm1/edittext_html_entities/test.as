@@ -0,0 +1,42 @@
+// Hand-assembled SWF 8; this is the script of its only frame.
+
+var field = this.createTextField("field", 1, 0, 0, 200, 50);
+field.html = true;
+
+trace("// field.htmlText = \"<b>hi&amp;bye</b>\"");
+field.htmlText = "<b>hi&amp;bye</b>";
+trace("");
+
+trace("// field.text");
+trace(field.text);
+trace("");
+
+trace("// field.htmlText contains \"<B>hi&amp;bye</B>\"");
+trace(field.htmlText.indexOf("<B>hi&amp;bye</B>") != -1);
+trace("");
+
+// Named and numeric entities are decoded into the plain text.
+trace("// field.htmlText = \"&lt;&#65;&#x42;&gt;\"");
+field.htmlText = "&lt;&#65;&#x42;&gt;";
+trace("");
+
+trace("// field.text");
+trace(field.text);
+trace("");
+
+trace("// field.htmlText contains \"&lt;AB&gt;\"");
+trace(field.htmlText.indexOf("&lt;AB&gt;") != -1);
+trace("");
+
+// Without `html`, `htmlText` is plain text.
+trace("// field.html = false");
+field.html = false;
+trace("");
+
+trace("// field.htmlText = \"<b>x</b>\"");
+field.htmlText = "<b>x</b>";
+trace("");
+
+trace("// field.text");
+trace(field.text);
+trace("");
@@ -0,0 +1,48 @@
+// Hand-assembled SWF 8; each "Frame" section below is the script of that frame.
+// Text input and input method compositions are sent in between frames.
+
+// Frame 1
+function report() {
+	trace("// field.text, Selection.getCaretIndex()");
+	trace(field.text + ", " + Selection.getCaretIndex());
+	trace("");
+}
+
+var field = this.createTextField("field", 1, 0, 0, 200, 50);
+field.type = "input";
+Selection.setFocus(field);
+Selection.setSelection(0, 0);
+
+// Frame 2
+// Typed "a" and "Ĉ". Characters outside of ASCII aren't mistaken for control codes.
+report();
+Selection.setSelection(1, 1);
+
+// Frame 3
+// The preedit text "日" is shown in place, with the caret at the input method's cursor.
+report();
+
+// Frame 4
+// Each update replaces the previous preedit text, here with "日本" and the cursor at its start.
+report();
+
+// Frame 5
+// Committing "日本語" replaces the composition, and moves the caret after it.
+report();
+
+// Frame 6
+// Characters outside of the BMP take two units.
+report();
+
+// Frame 7
+// An empty preedit cancels the composition of "x".
+trace("// field.text");
+trace(field.text);
+trace("");
+field.maxChars = 9;
+
+// Frame 8
+// Committed text is cut at a character boundary when the field is full.
+trace("// field.text after committing \"b\" and an emoji with field.maxChars = 9");
+trace(field.text);
+trace("");
@@ -0,0 +1,55 @@
+// Hand-assembled SWF 8; each "Frame" section below is the script of that frame.
+// Keyboard input is sent in between frames.
+
+// Frame 1
+function report(field) {
+	trace("// " + field._name + ".text, Selection.getCaretIndex()");
+	trace(field.text + ", " + Selection.getCaretIndex());
+}
+
+var field = this.createTextField("field", 1, 0, 0, 200, 50);
+field.type = "input";
+Selection.setFocus(field);
+Selection.setSelection(0, 0);
+
+// Frame 2
+// Typed "abc".
+report(field);
+
+// Frame 3
+// Backspace.
+report(field);
+
+// Frame 4
+// Home.
+report(field);
+
+// Frame 5
+// Delete.
+report(field);
+
+// Frame 6
+// End, then typed "z".
+report(field);
+field.type = "dynamic";
+
+// Frame 7
+// Read-only fields ignore the typed "!".
+report(field);
+
+var limited = this.createTextField("limited", 2, 0, 60, 200, 50);
+limited.type = "input";
+limited.maxChars = 3;
+Selection.setFocus(limited);
+Selection.setSelection(0, 0);
+
+// Frame 8
+// Typed "abcde", which was cut off at maxChars.
+report(limited);
+
+// Replacing the text from a script leaves the selection past its end.
+limited.text = "a";
+
+// Frame 9
+// Typed "x".
+report(limited);
@@ -0,0 +1,13 @@
+// Hand-assembled SWF 8; this is the script of its only frame.
+
+var field = this.createTextField("field", 1, 0, 0, 400, 200);
+field.wordWrap = true;
+field.text = "The quick brown fox jumps over the lazy dog";
+var wideHeight = field.textHeight;
+
+// Narrowing the field wraps the text onto more lines.
+field._width = 60;
+trace("// field.textHeight > wideHeight");
+trace(field.textHeight > wideHeight);
+trace("// field._width");
+trace(field._width);
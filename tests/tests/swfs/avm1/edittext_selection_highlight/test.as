@@ -0,0 +1,14 @@
+// Hand-assembled SWF 8 with a 200x100 stage; this is the script of its only frame.
+
+// At 77px, each space of the device font is exactly 20px wide, and has no outline,
+// so only the highlight behind the selected spaces is drawn.
+var format = new TextFormat();
+format.size = 77;
+var field = this.createTextField("field", 1, 0, 0, 200, 100);
+field.setNewTextFormat(format);
+field.text = "      ";
+
+Selection.setFocus(field);
+Selection.setSelection(1, 3);
+trace("// Selection.getBeginIndex(), Selection.getEndIndex()");
+trace(Selection.getBeginIndex() + ", " + Selection.getEndIndex());
@@ -0,0 +1,35 @@
+// Hand-assembled SWF 8; each "Frame" section below is the script of that frame.
+// The harness types "7" into the focused field after frame 1.
+
+// Frame 1
+var field = this.createTextField("field", 1, 0, 0, 100, 20);
+
+// Binding to an existing variable displays its value.
+var score = 10;
+field.variable = "_root.score";
+trace("// field.text after binding to score = 10");
+trace(field.text);
+trace("");
+
+// Changing the variable updates the field.
+score = 42;
+trace("// field.text after score = 42");
+trace(field.text);
+trace("");
+
+field.type = "input";
+Selection.setFocus(field);
+Selection.setSelection(2, 2);
+
+// Frame 2
+// User edits are written back to the variable.
+trace("// score after typing \"7\"");
+trace(score);
+trace("");
+
+// Once unbound, the field no longer follows the variable.
+field.variable = null;
+score = 0;
+trace("// field.text after unbinding and score = 0");
+trace(field.text);
+trace("");
@@ -0,0 +1,31 @@
+// Hand-assembled SWF 8; this is the script of its only frame.
+// The test harness traces the callbacks announced to the embedder, calls `greet`,
+// then switches to the local-with-file sandbox and calls `checkSandbox`.
+
+var ExternalInterface = flash.external.ExternalInterface;
+
+trace("// ExternalInterface.available");
+trace(ExternalInterface.available);
+trace("");
+
+trace("// ExternalInterface.call(\"trace\", 1, \"two\", true)");
+trace(ExternalInterface.call("trace", 1, "two", true));
+trace("");
+
+trace("// ExternalInterface.addCallback(\"greet\", null, greet)");
+trace(ExternalInterface.addCallback("greet", null, function(name) {
+	return "hello " + name;
+}));
+trace("");
+
+trace("// ExternalInterface.addCallback(\"checkSandbox\", null, checkSandbox)");
+trace(ExternalInterface.addCallback("checkSandbox", null, function() {
+	trace("// ExternalInterface.available");
+	trace(ExternalInterface.available);
+	trace("");
+
+	trace("// ExternalInterface.call(\"trace\", \"x\")");
+	trace(ExternalInterface.call("trace", "x"));
+	trace("");
+}));
+trace("");
@@ -0,0 +1,57 @@
+// Hand-assembled SWF 8; this is the script of its only frame.
+// The test harness records the commands forwarded to the embedder and traces them at the end.
+
+var listener = {};
+listener.onFullScreen = function(isFullScreen) {
+	trace("onFullScreen: " + isFullScreen);
+};
+Stage.addListener(listener);
+
+trace("// fscommand('quality', 'LOW')");
+fscommand("quality", "LOW");
+trace(_quality);
+trace("");
+
+trace("// fscommand('quality', 'best')");
+fscommand("quality", "best");
+trace(_quality);
+trace("");
+
+trace("// fscommand('quality', 'bogus')");
+fscommand("quality", "bogus");
+trace(_quality);
+trace("");
+
+trace("// fscommand('allowscale', 'false')");
+fscommand("allowscale", "false");
+trace(Stage.scaleMode);
+trace("");
+
+trace("// fscommand('allowscale', 'TRUE')");
+fscommand("allowscale", "TRUE");
+trace(Stage.scaleMode);
+trace("");
+
+trace("// fscommand('showmenu', 'false')");
+fscommand("showmenu", "false");
+trace(Stage.showMenu);
+trace("");
+
+trace("// fscommand('showmenu', 'yes')");
+fscommand("showmenu", "yes");
+trace(Stage.showMenu);
+trace("");
+
+trace("// fscommand('fullscreen', 'true')");
+fscommand("fullscreen", "true");
+trace(Stage.displayState);
+trace("");
+
+trace("// fscommand('fullscreen', 'false')");
+fscommand("fullscreen", "false");
+trace(Stage.displayState);
+trace("");
+
+trace("// fscommand('trapallkeys', 'true')");
+fscommand("trapallkeys", "true");
+trace("");
@@ -0,0 +1,18 @@
+// Hand-assembled SWF 8; this is the script of its only frame.
+
+var version = getVersion();
+
+// The version is the platform name followed by the player version, e.g. "WIN 10,0,0,0".
+var parts = version.split(" ");
+var numbers = parts[1].split(",");
+trace("// getVersion() parts");
+trace(parts.length + " " + numbers.length + " " + numbers.slice(1).join(","));
+trace("");
+
+trace("// getVersion() == System.capabilities.version");
+trace(version == System.capabilities.version);
+trace("");
+
+trace("// getVersion() == _root.$version");
+trace(version == _root.$version);
+trace("");
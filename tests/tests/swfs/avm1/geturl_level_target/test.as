@@ -0,0 +1,18 @@
+// Hand-assembled SWF 8; each "Frame" section below is the script of that frame.
+// loaded.swf traces `this` from its only frame.
+
+// Frame 1
+// Window names are case-insensitive, and `_levelN` targets load the movie into that level.
+trace("// this.getURL(\"loaded.swf\", \"_LEVEL1\")");
+this.getURL("loaded.swf", "_LEVEL1");
+trace("");
+
+// The level is created straight away.
+trace("// typeof(_level1)");
+trace(typeof(_level1));
+trace("");
+
+// Frame 3
+trace("// _level1.loaded");
+trace(_level1.loaded);
+trace("");
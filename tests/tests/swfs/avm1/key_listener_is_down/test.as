@@ -0,0 +1,26 @@
+// Hand-assembled SWF 8; each "Frame" section below is the script of that frame.
+// The test harness presses the left arrow key after frame 1 and releases it after frame 2.
+
+// Frame 1
+var listener = {};
+listener.onKeyDown = function() {
+	trace("onKeyDown: " + Key.getCode());
+};
+listener.onKeyUp = function() {
+	trace("onKeyUp: " + Key.getCode());
+};
+Key.addListener(listener);
+
+trace("// Key.isDown(Key.LEFT)");
+trace(Key.isDown(Key.LEFT));
+trace("");
+
+// Frame 2
+trace("// Key.isDown(Key.LEFT)");
+trace(Key.isDown(Key.LEFT));
+trace("");
+
+// Frame 3
+trace("// Key.isDown(Key.LEFT)");
+trace(Key.isDown(Key.LEFT));
+trace("");
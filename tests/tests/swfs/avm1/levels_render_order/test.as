@@ -0,0 +1,26 @@
+// Hand-assembled SWF 8 with a 40x20 stage; each "Frame" section below is the script of that frame.
+// red.swf and blue.swf each hold a 20x20 rectangle of their color, at x = 0 and x = 10 respectively,
+// and set `color` to the name of their color.
+
+// Frame 1
+// Levels render in ascending order, regardless of the order they were created in.
+loadMovieNum("blue.swf", 2);
+loadMovieNum("red.swf", 1);
+
+// Frame 3
+trace("// _level1.color");
+trace(_level1.color);
+trace("");
+
+trace("// _level2.color");
+trace(_level2.color);
+trace("");
+
+// `_levelN` resolves as a root path.
+trace("// eval(\"_level2\").color");
+trace(eval("_level2").color);
+trace("");
+
+trace("// eval(\"_level1/:color\")");
+trace(eval("_level1/:color"));
+trace("");
@@ -0,0 +1,17 @@
+// Hand-assembled SWF 8; each "Frame" section below is the script of that frame.
+// loaded.swf is a 4 frame movie, 128 bytes long.
+
+// Frame 1
+var target = this.createEmptyMovieClip("target", 1);
+target.loadMovie("loaded.swf");
+
+// Frame 3
+// The loaded clip reports the size of its own movie, not the one it was loaded into.
+trace("// target.getBytesLoaded()");
+trace(target.getBytesLoaded());
+trace("// target.getBytesTotal()");
+trace(target.getBytesTotal());
+trace("// this.getBytesTotal() == target.getBytesTotal()");
+trace(this.getBytesTotal() == target.getBytesTotal());
+trace("// target._framesloaded");
+trace(target._framesloaded);
@@ -0,0 +1,47 @@
+// Hand-assembled SWF 8; each "Frame" section below is the script of that frame.
+
+// Frame 1
+var receiver = new LocalConnection();
+receiver.onMessage = function(a, b, c) {
+	trace("receiver.onMessage: " + a + ", " + b + ", " + c);
+};
+
+var sender = new LocalConnection();
+sender.onStatus = function(info) {
+	trace("sender.onStatus: " + info.level);
+};
+
+trace("// receiver.connect(\"test\")");
+trace(receiver.connect("test"));
+trace("");
+
+// A connection can only listen on one name at a time.
+trace("// receiver.connect(\"other\")");
+trace(receiver.connect("other"));
+trace("");
+
+// A name can only be listened on by one connection at a time.
+var rival = new LocalConnection();
+trace("// rival.connect(\"test\")");
+trace(rival.connect("test"));
+trace("");
+
+// Sending succeeds even if nobody is listening; that's reported to `onStatus`.
+// Messages are delivered, and statuses reported, at the end of the frame.
+trace("// sender.send(\"test\", \"onMessage\", 1, \"two\", true)");
+trace(sender.send("test", "onMessage", 1, "two", true));
+trace("");
+
+trace("// sender.send(\"nobody\", \"onMessage\")");
+trace(sender.send("nobody", "onMessage"));
+trace("");
+
+// Frame 2
+// Closing a connection releases its name.
+trace("// receiver.close()");
+receiver.close();
+trace("");
+
+trace("// rival.connect(\"test\")");
+trace(rival.connect("test"));
+trace("");
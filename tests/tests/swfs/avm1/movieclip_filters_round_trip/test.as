@@ -0,0 +1,43 @@
+// Hand-assembled SWF 8; this is the script of its only frame.
+
+var clip = this.createEmptyMovieClip("clip", 1);
+var blur = new flash.filters.BlurFilter(8, 2, 3);
+
+trace("// clip.filters = [blur]");
+clip.filters = [blur];
+trace("");
+
+var filters = clip.filters;
+trace("// clip.filters.length");
+trace(filters.length);
+trace("");
+
+// The getter returns copies.
+trace("// clip.filters[0] == blur");
+trace(filters[0] == blur);
+trace("");
+
+trace("// clip.filters[0] instanceof flash.filters.BlurFilter");
+trace(filters[0] instanceof flash.filters.BlurFilter);
+trace("");
+
+trace("// clip.filters[0].blurX");
+trace(filters[0].blurX);
+trace("");
+
+trace("// clip.filters[0].blurY");
+trace(filters[0].blurY);
+trace("");
+
+trace("// clip.filters[0].quality");
+trace(filters[0].quality);
+trace("");
+
+// Assigning an empty array clears the filters.
+trace("// clip.filters = []");
+clip.filters = [];
+trace("");
+
+trace("// clip.filters.length");
+trace(clip.filters.length);
+trace("");
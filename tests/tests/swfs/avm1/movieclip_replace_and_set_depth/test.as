@@ -0,0 +1,20 @@
+// Hand-assembled SWF 8; this is the script of its only frame.
+
+// Only the clip attached last remains at an occupied depth.
+var firstClip = this.createEmptyMovieClip("first", 5);
+var secondClip = this.createEmptyMovieClip("second", 5);
+trace("// this.getInstanceAtDepth(5)._name");
+trace(this.getInstanceAtDepth(5)._name);
+trace("// this.first");
+trace(this.first);
+
+var clip = this.createEmptyMovieClip("clip", 1);
+clip.setDepth(10);
+trace("// this.getInstanceAtDepth(1)");
+trace(this.getInstanceAtDepth(1));
+trace("// this.getInstanceAtDepth(10) == clip");
+trace(this.getInstanceAtDepth(10) == clip);
+trace("// clip.getDepth()");
+trace(clip.getDepth());
+trace("// this.clip == clip");
+trace(this.clip == clip);
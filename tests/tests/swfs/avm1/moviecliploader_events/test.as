@@ -0,0 +1,33 @@
+// Hand-assembled SWF 8; each "Frame" section below is the script of that frame.
+// loaded.swf is an empty single frame movie.
+
+// Frame 1
+var lastEvent = "";
+function record(name) {
+	// The number of progress events depends on how the movie is preloaded.
+	if (name != lastEvent) {
+		trace(name);
+	}
+	lastEvent = name;
+}
+
+var listener = {};
+listener.onLoadStart = function(clip) {
+	record("onLoadStart: " + clip._name);
+};
+listener.onLoadProgress = function(clip, loaded, total) {
+	record("onLoadProgress: " + clip._name);
+};
+listener.onLoadComplete = function(clip) {
+	record("onLoadComplete: " + clip._name);
+};
+listener.onLoadInit = function(clip) {
+	record("onLoadInit: " + clip._name);
+};
+
+var target = this.createEmptyMovieClip("target", 1);
+var loader = new MovieClipLoader();
+loader.addListener(listener);
+trace("// loader.loadClip(\"loaded.swf\", target)");
+trace(loader.loadClip("loaded.swf", target));
+
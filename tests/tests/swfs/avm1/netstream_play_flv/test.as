@@ -0,0 +1,17 @@
+// Hand-assembled SWF 8; this is the script of its only frame, which also places an empty Video named `vid`.
+// video.flv holds three H.263 video tags, interleaved with a script tag and a video tag without a picture.
+
+trace("// nc.connect(null)");
+var nc = new NetConnection();
+trace(nc.connect(null));
+trace("");
+
+// The stream reports when it starts playing and when it runs out of video tags.
+var ns = new NetStream(nc);
+ns.onStatus = function(info) {
+	trace("// onStatus");
+	trace(info.code);
+	trace("");
+};
+vid.attachVideo(ns);
+ns.play("video.flv");
@@ -0,0 +1,14 @@
+// Hand-assembled SWF 8 at 10fps; each "Frame" section below is the script of that frame.
+// The test harness runs the movie on a simulated clock, advanced by 100ms after every frame.
+// At the end, it pauses, ticks, steps and resumes the player, tracing the time after each step.
+
+// Frame 1
+trace("// getTimer()");
+trace(getTimer());
+trace("");
+
+// Frame 2
+trace("// getTimer()");
+trace(getTimer());
+trace("");
+stop();
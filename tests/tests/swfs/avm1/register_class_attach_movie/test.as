@@ -0,0 +1,37 @@
+// Hand-assembled SWF 8; this is the script of its only frame.
+// The movie exports an empty sprite as "widget".
+
+function Widget() {
+	trace("Widget constructor: " + this._name);
+	this.ready = true;
+}
+Widget.prototype = new MovieClip();
+Widget.prototype.kind = "widget";
+Widget.prototype.greet = function() {
+	return "hello";
+};
+
+trace("// Object.registerClass(\"widget\", Widget)");
+trace(Object.registerClass("widget", Widget));
+trace("");
+
+trace("// this.attachMovie(\"widget\", \"w\", 1)");
+var instance = this.attachMovie("widget", "w", 1);
+trace("");
+
+// The clip inherits from the registered prototype, and the constructor ran on it.
+trace("// instance instanceof Widget");
+trace(instance instanceof Widget);
+trace("");
+
+trace("// instance.kind");
+trace(instance.kind);
+trace("");
+
+trace("// instance.ready");
+trace(instance.ready);
+trace("");
+
+trace("// instance.greet()");
+trace(instance.greet());
+trace("");
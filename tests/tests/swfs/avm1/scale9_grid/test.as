@@ -0,0 +1,41 @@
+// Hand-assembled SWF 8 with a 120x40 stage; this is the script of its only frame.
+
+function fillRect(mc, color, x0, y0, x1, y1) {
+	mc.beginFill(color);
+	mc.moveTo(x0, y0);
+	mc.lineTo(x1, y0);
+	mc.lineTo(x1, y1);
+	mc.lineTo(x0, y1);
+	mc.lineTo(x0, y0);
+	mc.endFill();
+}
+
+// A 40x12 black box with a 2px border around a red center, scaled horizontally by 3.
+var box = this.createEmptyMovieClip("box", 1);
+fillRect(box, 0x000000, 0, 0, 40, 12);
+fillRect(box, 0xFF0000, 2, 2, 38, 10);
+box.scale9Grid = new flash.geom.Rectangle(2, 2, 36, 8);
+box._xscale = 300;
+
+trace("// box.scale9Grid");
+trace(box.scale9Grid);
+trace("// box._width");
+trace(box._width);
+
+// The same box built from child clips, with a green corner that fits inside one slice.
+var nested = this.createEmptyMovieClip("nested", 2);
+nested._y = 20;
+fillRect(nested.createEmptyMovieClip("border", 1), 0x000000, 0, 0, 40, 12);
+fillRect(nested.createEmptyMovieClip("center", 2), 0xFF0000, 2, 2, 38, 10);
+fillRect(nested.createEmptyMovieClip("corner", 3), 0x00FF00, 0, 0, 2, 2);
+nested.scale9Grid = new flash.geom.Rectangle(2, 2, 36, 8);
+nested._xscale = 300;
+
+trace("// nested.scale9Grid");
+trace(nested.scale9Grid);
+
+// Setting the grid to anything but a rectangle removes it.
+nested.scale9Grid = undefined;
+trace("// nested.scale9Grid after clearing");
+trace(nested.scale9Grid);
+nested.scale9Grid = new flash.geom.Rectangle(2, 2, 36, 8);
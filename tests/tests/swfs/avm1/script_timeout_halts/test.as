@@ -0,0 +1,13 @@
+// Hand-assembled SWF 8; each "Frame" section below is the script of that frame.
+// The test harness sets the maximum execution duration to zero.
+
+// Frame 1
+trace("Frame 1 started");
+// The loop is aborted instead of hanging the player.
+while (true) {
+}
+trace("Frame 1 finished");
+
+// Frame 2
+// No further scripts run once the AVM has been halted.
+trace("Frame 2");
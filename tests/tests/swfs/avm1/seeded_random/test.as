@@ -0,0 +1,31 @@
+// Hand-assembled SWF 8; this is the script of its only frame.
+// The test harness seeds the random number generator with 42 before the movie starts.
+// At the end, it reseeds it with 42 and then 43, calling `compareSequence` after each.
+
+function sequence() {
+	var numbers = [];
+	for (var i = 0; i < 16; i++) {
+		numbers.push(Math.random());
+	}
+	return numbers;
+}
+
+var first = sequence();
+
+trace("// All numbers are in [0, 1)");
+var inRange = true;
+for (var i = 0; i < first.length; i++) {
+	if (first[i] < 0 || first[i] >= 1) {
+		inRange = false;
+	}
+}
+trace(inRange);
+trace("");
+
+trace("// ExternalInterface.addCallback(\"compareSequence\", null, compareSequence)");
+trace(flash.external.ExternalInterface.addCallback("compareSequence", null, function() {
+	trace("// sequence().join() == first.join()");
+	trace(sequence().join() == first.join());
+	trace("");
+}));
+trace("");
@@ -0,0 +1,34 @@
+// Hand-assembled SWF 8 at 20fps; each "Frame" section below is the script of that frame.
+// The test harness advances the timers by 50ms after every frame.
+
+// Frame 1
+var count = 0;
+
+function tick(step) {
+	count += step;
+	trace("tick(" + step + "): count = " + count);
+}
+
+// Extra arguments are forwarded to the callback.
+trace("// setInterval(tick, 100, 1)");
+var id = setInterval(tick, 100, 1);
+trace("");
+
+// Frame 8
+// Cleared intervals stop firing.
+trace("// clearInterval(id)");
+clearInterval(id);
+trace("");
+
+// Frame 10
+// Timeouts using the method-name form fire exactly once.
+var target = {};
+target.tick = tick;
+trace("// setTimeout(target, \"tick\", 100, 10)");
+setTimeout(target, "tick", 100, 10);
+trace("");
+
+// Frame 16
+trace("// count");
+trace(count);
+trace("");
@@ -0,0 +1,20 @@
+// Hand-assembled SWF 8 at 10fps; each "Frame" section below is the script of that frame.
+// The test harness runs the movie on a simulated clock, advanced by 100ms after every frame.
+// At the end, it ticks the player by 250ms and 16ms and traces the time.
+
+// Frame 1
+// Time only passes when the player is advanced.
+trace("// getTimer()");
+trace(getTimer());
+trace("");
+
+// Frame 2
+trace("// getTimer()");
+trace(getTimer());
+trace("");
+
+// Frame 3
+trace("// getTimer()");
+trace(getTimer());
+trace("");
+stop();
@@ -0,0 +1,33 @@
+// Hand-assembled SWF 8; each "Frame" section below is the script of that frame.
+// The mouse is moved in between frames.
+
+// Frame 1
+function report(clip) {
+	trace("// " + clip._name + "._x, " + clip._name + "._y");
+	trace(clip._x + ", " + clip._y);
+}
+
+var box = this.createEmptyMovieClip("box", 1);
+box.startDrag(false, 10, 20, 100, 50);
+
+// Frame 2
+// Inside the constraint rectangle, the clip follows the mouse to (40, 30).
+report(box);
+
+// Frame 3
+// Outside of it, the clip is clamped to the rectangle: the mouse is at (300, -20).
+report(box);
+
+// Frame 4
+// The mouse is at (-5, 80).
+report(box);
+box.stopDrag();
+
+var locked = this.createEmptyMovieClip("locked", 2);
+locked._x = 25;
+locked._y = 25;
+locked.startDrag(true);
+
+// Frame 5
+// The registration point snaps to the mouse at (60, 70).
+report(locked);
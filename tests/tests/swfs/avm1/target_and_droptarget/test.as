@@ -0,0 +1,54 @@
+// Hand-assembled SWF 8; each "Frame" section below is the script of that frame.
+// The harness moves the mouse to (20, 20) after frame 1 and to (80, 80) after frame 2.
+
+// Frame 1
+function fillSquare(clip, size) {
+	clip.beginFill(0);
+	clip.lineTo(size, 0);
+	clip.lineTo(size, size);
+	clip.lineTo(0, size);
+	clip.lineTo(0, 0);
+	clip.endFill();
+}
+
+var outer = this.createEmptyMovieClip("outer", 1);
+var inner = outer.createEmptyMovieClip("inner", 1);
+
+trace("// inner._target");
+trace(inner._target);
+trace("");
+
+trace("// _root._target");
+trace(_root._target);
+trace("");
+
+trace("// inner._url == _root._url");
+trace(inner._url == _root._url);
+trace("");
+
+trace("// _root._url ends with test.swf");
+trace(_root._url.substr(-8) == "test.swf");
+trace("");
+
+var target = this.createEmptyMovieClip("target", 2);
+fillSquare(target, 50);
+var dragged = this.createEmptyMovieClip("dragged", 3);
+fillSquare(dragged, 10);
+
+trace("// dragged._droptarget before dragging");
+trace(dragged._droptarget);
+trace("");
+
+dragged.startDrag();
+
+// Frame 2
+// The dragged clip itself is never its own drop target.
+trace("// dragged._droptarget over target");
+trace(dragged._droptarget);
+trace("");
+
+// Frame 3
+trace("// dragged._droptarget outside of target");
+trace(dragged._droptarget);
+trace("");
+dragged.stopDrag();
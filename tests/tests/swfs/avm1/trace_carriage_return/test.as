@@ -0,0 +1,10 @@
+// Hand-assembled SWF 8; this is the script of its only frame.
+
+// Carriage returns are normalized to newlines before reaching the log backend.
+trace("// trace(\"a\\rb\")");
+trace("a\rb");
+trace("");
+
+trace("// trace(\"a\\r\\nb\")");
+trace("a\r\nb");
+trace("");
@@ -0,0 +1,62 @@
+// Hand-assembled SWF 8; this is the script of its only frame.
+
+function round(n) {
+	return Math.round(n * 100) / 100;
+}
+
+function traceMatrix(clip) {
+	var m = clip.transform.matrix;
+	trace(round(m.a) + " " + round(m.b) + " " + round(m.c) + " " + round(m.d));
+}
+
+var clip = this.createEmptyMovieClip("clip", 1);
+clip._xscale = 200;
+clip._yscale = 50;
+clip._rotation = 90;
+
+trace("// clip._xscale, clip._yscale");
+trace(round(clip._xscale) + " " + round(clip._yscale));
+trace("");
+
+trace("// clip.transform.matrix");
+traceMatrix(clip);
+trace("");
+
+// Re-deriving the components from the recomposed matrix gives the same values.
+clip.transform.matrix = clip.transform.matrix;
+trace("// clip._xscale, clip._yscale, clip._rotation after reassigning the matrix");
+trace(round(clip._xscale) + " " + round(clip._yscale) + " " + round(clip._rotation));
+trace("");
+
+// Rotating a skewed clip keeps its skew.
+clip.transform.matrix = new flash.geom.Matrix(1, 0, 1, 1, 0, 0);
+clip._rotation = 90;
+trace("// clip.transform.matrix after rotating a skewed clip");
+traceMatrix(clip);
+trace("");
+
+clip.transform.matrix = clip.transform.matrix;
+trace("// clip._xscale, clip._yscale after reassigning the matrix");
+trace(round(clip._xscale) + " " + round(clip._yscale));
+trace("");
+
+clip._alpha = 50;
+trace("// clip._alpha, clip.transform.colorTransform.alphaMultiplier");
+trace(round(clip._alpha) + " " + clip.transform.colorTransform.alphaMultiplier);
+trace("");
+
+clip._visible = false;
+trace("// clip._visible = false");
+trace(clip._visible);
+trace("");
+
+// Strings are coerced to numbers, so this has no effect.
+clip._visible = "true";
+trace("// clip._visible = \"true\"");
+trace(clip._visible);
+trace("");
+
+clip._visible = 1;
+trace("// clip._visible = 1");
+trace(clip._visible);
+trace("");
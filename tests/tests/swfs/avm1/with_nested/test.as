@@ -0,0 +1,57 @@
+// Hand-assembled SWF 8; this is the script of its only frame.
+
+var x = "root";
+var a = {x: "a", y: "a"};
+var b = {x: "b"};
+
+with (a) {
+	with (b) {
+		// The innermost `with` object takes precedence, then the enclosing ones.
+		trace("// x (in with (a) { with (b) { ... } })");
+		trace(x);
+		trace("");
+
+		trace("// y (in with (a) { with (b) { ... } })");
+		trace(y);
+		trace("");
+
+		x = "set";
+		y = "set2";
+		z = "new";
+	}
+
+	// Leaving a `with` block restores the previous scope.
+	trace("// x (in with (a) { ... })");
+	trace(x);
+	trace("");
+}
+
+trace("// x");
+trace(x);
+trace("");
+
+// Writes go to the `with` object that has the property...
+trace("// b.x");
+trace(b.x);
+trace("");
+
+trace("// a.x");
+trace(a.x);
+trace("");
+
+trace("// a.y");
+trace(a.y);
+trace("");
+
+// ...and new variables are created on the timeline.
+trace("// z");
+trace(z);
+trace("");
+
+trace("// a.hasOwnProperty(\"z\")");
+trace(a.hasOwnProperty("z"));
+trace("");
+
+trace("// b.hasOwnProperty(\"z\")");
+trace(b.hasOwnProperty("z"));
+trace("");
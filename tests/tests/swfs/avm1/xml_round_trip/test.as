@@ -0,0 +1,46 @@
+// Hand-assembled SWF 8; this is the script of its only frame.
+
+var source = "<root><item id=\"a\" name=\"first\" /><item id=\"b\">text</item></root>";
+var document = new XML(source);
+
+trace("// document.status");
+trace(document.status);
+trace("");
+
+// Walk the tree: root -> [item, item -> text].
+var root = document.firstChild;
+trace("// root.nodeName");
+trace(root.nodeName);
+trace("");
+
+trace("// root.childNodes.length");
+trace(root.childNodes.length);
+trace("");
+
+var first = root.childNodes[0];
+trace("// first.nextSibling === root.childNodes[1]");
+trace(first.nextSibling === root.childNodes[1]);
+trace("");
+
+var text = first.nextSibling.firstChild;
+trace("// text.nodeType");
+trace(text.nodeType);
+trace("");
+
+trace("// text.nodeValue");
+trace(text.nodeValue);
+trace("");
+
+// Elements with an `id` are reachable through the ID map.
+trace("// document.idMap.a === first");
+trace(document.idMap.a === first);
+trace("");
+
+trace("// document.idMap.a.attributes.name");
+trace(document.idMap.a.attributes.name);
+trace("");
+
+// Serializing gives back the source.
+trace("// document.toString() == source");
+trace(document.toString() == source);
+trace("");
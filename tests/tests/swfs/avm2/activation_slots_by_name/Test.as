@@ -0,0 +1,27 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+// `x` lives in a slot of `slotsAndNames`'s activation object. The function
+// itself accesses it by slot, while the closures look it up by name.
+function slotsAndNames():void {
+	var x:Number = 0;
+	var getByName:Function = function():Number {
+		return x;
+	};
+	var setByName:Function = function(value:Number):void {
+		x = value;
+	};
+
+	setByName(12.5);
+	trace("///setByName(12.5); x");
+	trace(x);
+
+	x = -3;
+	trace("///x = -3; getByName()");
+	trace(getByName());
+}
+
+slotsAndNames();
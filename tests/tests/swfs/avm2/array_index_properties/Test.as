@@ -0,0 +1,37 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+var arr = [];
+arr[5] = "five";
+
+trace("///arr[5] = \"five\"; arr.length");
+trace(arr.length);
+trace("///arr[5]");
+trace(arr[5]);
+trace("///arr[2]");
+trace(arr[2]);
+trace("///arr[10]");
+trace(arr[10]);
+
+var five:Number = 5.0;
+trace("///arr[five:Number]");
+trace(arr[five]);
+
+arr.foo = 1;
+trace("///arr.foo = 1; arr.length");
+trace(arr.length);
+
+arr[-1] = "minus one";
+trace("///arr[-1] = \"minus one\"; arr.length");
+trace(arr.length);
+trace("///arr[-1]");
+trace(arr[-1]);
+
+arr[1.5] = "one and a half";
+trace("///arr[1.5] = \"one and a half\"; arr.length");
+trace(arr.length);
+trace("///arr[1.5]");
+trace(arr[1.5]);
@@ -0,0 +1,59 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+import flash.display.BitmapData;
+import flash.geom.Point;
+import flash.geom.Rectangle;
+
+var bmd:BitmapData = new BitmapData(4, 4, true, 0);
+bmd.fillRect(new Rectangle(1, 1, 2, 2), 0x80FF0000);
+trace("///bmd.fillRect(new Rectangle(1, 1, 2, 2), 0x80FF0000)");
+trace("///bmd.getPixel32(1, 1).toString(16)");
+trace(bmd.getPixel32(1, 1).toString(16));
+trace("///bmd.getPixel(2, 2).toString(16)");
+trace(bmd.getPixel(2, 2).toString(16));
+trace("///bmd.getPixel32(0, 0)");
+trace(bmd.getPixel32(0, 0));
+trace("///bmd.getPixel32(3, 1)");
+trace(bmd.getPixel32(3, 1));
+
+bmd.setPixel32(0, 3, 0xFF00FF00);
+trace("///bmd.setPixel32(0, 3, 0xFF00FF00)");
+trace("///bmd.getPixel32(0, 3).toString(16)");
+trace(bmd.getPixel32(0, 3).toString(16));
+
+var opaque:BitmapData = new BitmapData(2, 2, false, 0x00123456);
+trace("///opaque.getPixel32(1, 1).toString(16)");
+trace(opaque.getPixel32(1, 1).toString(16));
+
+var source:BitmapData = new BitmapData(4, 4, true, 0);
+source.setPixel32(1, 1, 0xFF0000FF);
+source.setPixel32(2, 2, 0x80FF0000);
+var dest:BitmapData = new BitmapData(4, 4, true, 0xFFFFFFFF);
+dest.copyPixels(source, new Rectangle(1, 1, 2, 2), new Point(0, 0));
+trace("///dest.copyPixels(source, new Rectangle(1, 1, 2, 2), new Point(0, 0))");
+trace("///dest.getPixel32(0, 0).toString(16)");
+trace(dest.getPixel32(0, 0).toString(16));
+trace("///dest.getPixel32(1, 1).toString(16)");
+trace(dest.getPixel32(1, 1).toString(16));
+trace("///dest.getPixel32(1, 0)");
+trace(dest.getPixel32(1, 0));
+trace("///dest.getPixel32(2, 2).toString(16)");
+trace(dest.getPixel32(2, 2).toString(16));
+
+var copy:BitmapData = dest.clone();
+trace("///copy = dest.clone(); copy.getPixel32(1, 1).toString(16)");
+trace(copy.getPixel32(1, 1).toString(16));
+
+dest.dispose();
+trace("///dest.dispose(); dest.getPixel(0, 0)");
+try {
+	dest.getPixel(0, 0);
+} catch (e:Error) {
+	trace(e);
+}
+trace("///copy.getPixel32(0, 0).toString(16)");
+trace(copy.getPixel32(0, 0).toString(16));
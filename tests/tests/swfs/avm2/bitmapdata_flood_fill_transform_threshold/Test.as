@@ -0,0 +1,43 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+import flash.display.BitmapData;
+import flash.geom.ColorTransform;
+import flash.geom.Point;
+import flash.geom.Rectangle;
+
+var fill:BitmapData = new BitmapData(5, 5, false, 0xFFFFFF);
+fill.fillRect(new Rectangle(2, 0, 1, 5), 0);
+fill.floodFill(0, 0, 0xFFFF0000);
+trace("///fill.floodFill(0, 0, 0xFFFF0000) with a wall at x = 2");
+trace("///fill.getPixel32(0, 0).toString(16)");
+trace(fill.getPixel32(0, 0).toString(16));
+trace("///fill.getPixel32(1, 4).toString(16)");
+trace(fill.getPixel32(1, 4).toString(16));
+trace("///fill.getPixel32(2, 2).toString(16)");
+trace(fill.getPixel32(2, 2).toString(16));
+trace("///fill.getPixel32(3, 2).toString(16)");
+trace(fill.getPixel32(3, 2).toString(16));
+
+var colors:BitmapData = new BitmapData(4, 4, true, 0xFFFF0000);
+colors.colorTransform(new Rectangle(0, 0, 2, 4), new ColorTransform(0, 1, 1, 1, 0, 0, 255, 0));
+trace("///colors.colorTransform(new Rectangle(0, 0, 2, 4), new ColorTransform(0, 1, 1, 1, 0, 0, 255, 0))");
+trace("///colors.getPixel32(1, 3).toString(16)");
+trace(colors.getPixel32(1, 3).toString(16));
+trace("///colors.getPixel32(2, 0).toString(16)");
+trace(colors.getPixel32(2, 0).toString(16));
+
+var source:BitmapData = new BitmapData(3, 1, true, 0);
+source.setPixel32(0, 0, 0xFF900000);
+source.setPixel32(1, 0, 0xFF100000);
+source.setPixel32(2, 0, 0xFF800000);
+var dest:BitmapData = new BitmapData(4, 1, true, 0xFF000000);
+trace("///dest.threshold(source, new Rectangle(0, 0, 3, 1), new Point(1, 0), \">=\", 0x00800000, 0xFFFFFFFF, 0x00FF0000, true)");
+trace(dest.threshold(source, new Rectangle(0, 0, 3, 1), new Point(1, 0), ">=", 0x00800000, 0xFFFFFFFF, 0x00FF0000, true));
+for (var x:int = 0; x < 4; x++) {
+	trace("///dest.getPixel32(" + x + ", 0).toString(16)");
+	trace(dest.getPixel32(x, 0).toString(16));
+}
@@ -0,0 +1,50 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+import flash.utils.ByteArray;
+
+function roundTrip(value:*):* {
+	var bytes:ByteArray = new ByteArray();
+	bytes.writeObject(value);
+	bytes.position = 0;
+	return bytes.readObject();
+}
+
+var cyclic:Object = {name: "cyclic"};
+cyclic.self = cyclic;
+
+trace("///var read = roundTrip(cyclic);");
+var read:* = roundTrip(cyclic);
+trace("///read.name");
+trace(read.name);
+trace("///read.self === read");
+trace(read.self === read);
+trace("///read === cyclic");
+trace(read === cyclic);
+
+var shared:Object = {value: 1};
+var array:Array = [shared, shared, {value: 1}];
+
+trace("///var readArray = roundTrip(array);");
+var readArray:* = roundTrip(array);
+trace("///readArray.length");
+trace(readArray.length);
+trace("///readArray[0] === readArray[1]");
+trace(readArray[0] === readArray[1]);
+trace("///readArray[0] === readArray[2]");
+trace(readArray[0] === readArray[2]);
+trace("///readArray[2].value");
+trace(readArray[2].value);
+
+var nested:Array = [1, 2];
+nested.push(nested);
+
+trace("///var readNested = roundTrip(nested);");
+var readNested:* = roundTrip(nested);
+trace("///readNested[2] === readNested");
+trace(readNested[2] === readNested);
+trace("///readNested[1]");
+trace(readNested[1]);
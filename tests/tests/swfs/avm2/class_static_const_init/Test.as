@@ -0,0 +1,16 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+class StaticTest {
+	// Initialized by the class initializer, which runs after constants get their values.
+	public static var BAR:int = FOO * 2;
+	public static const FOO:int = 5;
+}
+
+trace("///StaticTest.FOO");
+trace(StaticTest.FOO);
+trace("///StaticTest.BAR");
+trace(StaticTest.BAR);
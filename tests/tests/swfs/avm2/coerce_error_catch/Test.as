@@ -0,0 +1,45 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+function toArray(a:*):* {
+	try {
+		var arr:Array = a;
+		return arr;
+	} catch (e:TypeError) {
+		return "TypeError " + e.errorID;
+	}
+}
+
+trace("///toArray([1, 2])");
+trace(toArray([1, 2]));
+
+trace("///toArray({})");
+trace(toArray({}));
+
+trace("///toArray(null)");
+trace(toArray(null));
+
+var v:* = new Vector.<int>();
+v.push(1);
+
+trace("///v.removeAt(5)");
+try {
+	v.removeAt(5);
+} catch (e:RangeError) {
+	trace("RangeError " + e.errorID);
+}
+
+var noPrimitive:* = {
+	toString: function():* { return {}; },
+	valueOf: function():* { return {}; }
+};
+
+trace("///\"\" + noPrimitive");
+try {
+	trace("" + noPrimitive);
+} catch (e:TypeError) {
+	trace("TypeError " + e.errorID);
+}
@@ -0,0 +1,47 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+import flash.utils.getQualifiedClassName;
+
+class Base {
+	public function greet() {
+		return "hello";
+	}
+}
+
+class Sub extends Base {}
+
+function className(o) {
+	var name = getQualifiedClassName(o);
+	return name.substring(name.lastIndexOf(":") + 1);
+}
+
+function check(instance, base, sub) {
+	trace(instance is sub);
+	trace(instance is base);
+	trace(className(instance));
+	trace(instance.constructor === sub);
+	trace(sub.prototype.isPrototypeOf(instance));
+	trace(base.prototype.isPrototypeOf(sub.prototype));
+	trace(instance.greet());
+}
+
+var base = Base;
+var sub = Sub;
+var holder = {Sub: Sub};
+
+trace("///new sub()");
+check(new sub(), base, sub);
+
+trace("///new holder.Sub()");
+check(new holder.Sub(), base, sub);
+
+trace("///new base()");
+var instance = new base();
+trace(instance is base);
+trace(instance is sub);
+trace(className(instance));
+trace(instance.greet());
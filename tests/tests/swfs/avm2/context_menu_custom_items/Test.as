@@ -0,0 +1,27 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.ContextMenuEvent;
+	import flash.ui.ContextMenu;
+	import flash.ui.ContextMenuItem;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var menu:ContextMenu = new ContextMenu();
+			menu.hideBuiltInItems();
+			menu.addEventListener(ContextMenuEvent.MENU_SELECT, function(event:ContextMenuEvent):void {
+				trace("menuSelect");
+			});
+
+			for each (var caption:String in ["First", "Second"]) {
+				var item:ContextMenuItem = new ContextMenuItem(caption);
+				item.addEventListener(ContextMenuEvent.MENU_ITEM_SELECT, function(event:ContextMenuEvent):void {
+					trace("menuItemSelect: " + event.target.caption);
+				});
+				menu.customItems.push(item);
+			}
+			menu.customItems.push(new ContextMenuItem("Hidden", false, true, false));
+
+			contextMenu = menu;
+		}
+	}
+}
@@ -0,0 +1,15 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+var date:Date = new Date(2001, 1, 3, 16, 5, 6);
+trace("///date.toLocaleString()");
+trace(date.toLocaleString());
+trace("///date.toLocaleDateString()");
+trace(date.toLocaleDateString());
+trace("///date.toLocaleTimeString()");
+trace(date.toLocaleTimeString());
+trace("///date.toDateString()");
+trace(date.toDateString());
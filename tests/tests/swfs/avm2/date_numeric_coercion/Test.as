@@ -0,0 +1,19 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+var earlier = new Date(1000000000000);
+var later = new Date(1000000090061);
+
+trace("///later - earlier");
+trace(later - earlier);
+trace("///Number(later)");
+trace(Number(later));
+trace("///later * 1");
+trace(later * 1);
+trace("///typeof (later + 1)");
+trace(typeof (later + 1));
+trace("///typeof (later + earlier)");
+trace(typeof (later + earlier));
@@ -0,0 +1,35 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+class Sealed {
+	public var x:int = 1;
+}
+
+function deleteProperty(o:*, name:*):Boolean {
+	return delete o[name];
+}
+
+var a:Array = [1, 2, 3];
+
+trace("///deleteProperty(a, 1)");
+trace(deleteProperty(a, 1));
+trace("///a.length");
+trace(a.length);
+trace("///a[1]");
+trace(a[1]);
+trace("///a[2]");
+trace(a[2]);
+trace("///a.hasOwnProperty(1)");
+trace(a.hasOwnProperty(1));
+
+var sealed:* = new Sealed();
+
+trace("///deleteProperty(sealed, \"x\")");
+trace(deleteProperty(sealed, "x"));
+trace("///sealed.x");
+trace(sealed.x);
+trace("///deleteProperty(sealed, \"z\")");
+trace(deleteProperty(sealed, "z"));
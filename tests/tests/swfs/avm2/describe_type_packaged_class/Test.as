@@ -0,0 +1,29 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+import flash.utils.ByteArray;
+import flash.utils.describeType;
+import flash.utils.getQualifiedClassName;
+import flash.utils.getQualifiedSuperclassName;
+
+var bytes = new ByteArray();
+
+trace("///getQualifiedClassName(bytes)");
+trace(getQualifiedClassName(bytes));
+trace("///getQualifiedSuperclassName(bytes)");
+trace(getQualifiedSuperclassName(bytes));
+trace("///getQualifiedClassName(1)");
+trace(getQualifiedClassName(1));
+
+var description:String = String(describeType(bytes));
+trace("///description starts with the type element");
+trace(description.indexOf('<type name="flash.utils::ByteArray" base="Object" isDynamic="false" isFinal="false" isStatic="false">') == 0);
+trace("///description extends Object");
+trace(description.indexOf('<extendsClass type="Object"/>') != -1);
+trace("///description implements IDataInput");
+trace(description.indexOf('<implementsInterface type="flash.utils::IDataInput"/>') != -1);
+trace("///description ends with the type element");
+trace(description.lastIndexOf("</type>") == description.length - 7);
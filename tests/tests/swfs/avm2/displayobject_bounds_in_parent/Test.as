@@ -0,0 +1,38 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var parent:Sprite = new Sprite();
+			var child:Sprite = new Sprite();
+			addChild(parent);
+			parent.addChild(child);
+
+			// A 10x20 rectangle with a 4px stroke, which reaches 2px past every edge.
+			child.graphics.lineStyle(4);
+			child.graphics.drawRect(0, 0, 10, 20);
+			child.x = 5;
+			child.y = 7;
+			child.scaleX = 2;
+			child.scaleY = 3;
+
+			trace("///child.getBounds(child)");
+			trace(child.getBounds(child));
+			trace("///child.getRect(child)");
+			trace(child.getRect(child));
+			trace("///child.getBounds(parent)");
+			trace(child.getBounds(parent));
+			trace("///child.getRect(parent)");
+			trace(child.getRect(parent));
+			trace("///child.width");
+			trace(child.width);
+			trace("///child.height");
+			trace(child.height);
+
+			// The parent is not transformed, so pixel bounds match the bounds in its space.
+			trace("///child.transform.pixelBounds");
+			trace(child.transform.pixelBounds);
+		}
+	}
+}
@@ -0,0 +1,34 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+import flash.display.Sprite;
+import flash.filters.BlurFilter;
+
+var sprite:Sprite = new Sprite();
+var blur:BlurFilter = new BlurFilter(8, 2, 3);
+sprite.filters = [blur];
+trace("///sprite.filters = [new BlurFilter(8, 2, 3)]; sprite.filters.length");
+trace(sprite.filters.length);
+
+var copy = sprite.filters[0];
+trace("///copy = sprite.filters[0]; copy === blur");
+trace(copy === blur);
+trace("///copy is BlurFilter");
+trace(copy is BlurFilter);
+trace("///copy.blurX");
+trace(copy.blurX);
+trace("///copy.blurY");
+trace(copy.blurY);
+trace("///copy.quality");
+trace(copy.quality);
+
+blur.blurX = 20;
+trace("///blur.blurX = 20; sprite.filters[0].blurX");
+trace(sprite.filters[0].blurX);
+
+sprite.filters = [];
+trace("///sprite.filters = []; sprite.filters.length");
+trace(sprite.filters.length);
@@ -0,0 +1,59 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var first:Sprite = square();
+			var second:Sprite = square();
+			addChild(first);
+			addChild(second);
+			second.x = 5;
+			trace("///first.hitTestObject(second)");
+			trace(first.hitTestObject(second));
+			trace("///second.hitTestObject(first)");
+			trace(second.hitTestObject(first));
+			second.x = 20;
+			trace("///first.hitTestObject(second) after moving it away");
+			trace(first.hitTestObject(second));
+			removeChild(first);
+			removeChild(second);
+
+			// A right triangle filling the top left half of a 20x20 square.
+			var triangle:Sprite = new Sprite();
+			triangle.graphics.beginFill(0xFF0000);
+			triangle.graphics.moveTo(0, 0);
+			triangle.graphics.lineTo(20, 0);
+			triangle.graphics.lineTo(0, 20);
+			triangle.graphics.lineTo(0, 0);
+			triangle.graphics.endFill();
+			triangle.x = 100;
+			addChild(triangle);
+
+			// Points are given in stage coordinates.
+			hitTest(triangle, 105, 5, true);
+			hitTest(triangle, 105, 5, false);
+			hitTest(triangle, 5, 5, true);
+
+			// Inside the bounds, but outside of the triangle.
+			hitTest(triangle, 115, 15, true);
+			hitTest(triangle, 115, 15, false);
+
+			hitTest(triangle, 130, 30, true);
+			hitTest(triangle, 130, 30, false);
+		}
+
+		private function square():Sprite {
+			var sprite:Sprite = new Sprite();
+			sprite.graphics.beginFill(0xFF0000);
+			sprite.graphics.drawRect(0, 0, 10, 10);
+			sprite.graphics.endFill();
+			return sprite;
+		}
+
+		private function hitTest(sprite:Sprite, x:Number, y:Number, shapeFlag:Boolean):void {
+			trace("///triangle.hitTestPoint(" + x + ", " + y + ", " + shapeFlag + ")");
+			trace(sprite.hitTestPoint(x, y, shapeFlag));
+		}
+	}
+}
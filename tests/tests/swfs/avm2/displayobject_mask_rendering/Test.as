@@ -0,0 +1,40 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			graphics.beginFill(0xFFFFFF);
+			graphics.drawRect(0, 0, 60, 30);
+			graphics.endFill();
+
+			// The red square is only drawn where the mask covers it, and the green
+			// mask itself is not drawn.
+			var content:Shape = square(0xFF0000, 0, 0, 20);
+			var mask:Shape = square(0x00FF00, 5, 5, 10);
+			addChild(content);
+			addChild(mask);
+			content.mask = mask;
+			trace("///content.mask == mask");
+			trace(content.mask == mask);
+
+			// Clearing the mask draws both squares normally again.
+			var unmasked:Shape = square(0xFF0000, 30, 0, 20);
+			var unusedMask:Shape = square(0x00FF00, 35, 5, 10);
+			addChild(unmasked);
+			addChild(unusedMask);
+			unmasked.mask = unusedMask;
+			unmasked.mask = null;
+			trace("///unmasked.mask");
+			trace(unmasked.mask);
+		}
+
+		private function square(color:uint, x:Number, y:Number, size:Number):Shape {
+			var shape:Shape = new Shape();
+			shape.graphics.beginFill(color);
+			shape.graphics.drawRect(x, y, size, size);
+			shape.graphics.endFill();
+			return shape;
+		}
+	}
+}
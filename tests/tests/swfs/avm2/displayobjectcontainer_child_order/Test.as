@@ -0,0 +1,90 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+import flash.display.DisplayObjectContainer;
+import flash.display.Sprite;
+import flash.events.Event;
+
+function named(name:String):Sprite {
+	var sprite:Sprite = new Sprite();
+	sprite.name = name;
+	return sprite;
+}
+
+function order(container:DisplayObjectContainer):String {
+	var names:Array = [];
+	for (var i:int = 0; i < container.numChildren; i++) {
+		names.push(container.getChildAt(i).name);
+	}
+	return names.join(",");
+}
+
+function attempt(description:String, action:Function):void {
+	trace("///" + description);
+	try {
+		action();
+		trace("no error");
+	} catch (e:Error) {
+		trace(e.errorID);
+	}
+}
+
+var parent:Sprite = named("parent");
+var c0:Sprite = named("c0");
+var c1:Sprite = named("c1");
+var c2:Sprite = named("c2");
+
+function logEvent(event:Event):void {
+	trace(event.type + ": " + event.target.name);
+}
+parent.addEventListener(Event.ADDED, logEvent);
+parent.addEventListener(Event.REMOVED, logEvent);
+
+trace("///parent.addChild(c0); parent.addChild(c1); parent.addChild(c2)");
+parent.addChild(c0);
+parent.addChild(c1);
+parent.addChild(c2);
+trace("///parent.numChildren");
+trace(parent.numChildren);
+
+parent.setChildIndex(c0, 2);
+trace("///parent.setChildIndex(c0, 2)");
+trace(order(parent));
+trace("///parent.getChildIndex(c0)");
+trace(parent.getChildIndex(c0));
+
+parent.swapChildren(c1, c0);
+trace("///parent.swapChildren(c1, c0)");
+trace(order(parent));
+
+trace("///parent.contains(c2)");
+trace(parent.contains(c2));
+trace("///parent.contains(parent)");
+trace(parent.contains(parent));
+
+trace("///parent.removeChild(c2)");
+parent.removeChild(c2);
+trace("///parent.contains(c2)");
+trace(parent.contains(c2));
+trace("///parent.numChildren");
+trace(parent.numChildren);
+
+// Out of range indices, and objects that are not children, throw.
+attempt("parent.getChildAt(2)", function():void {
+	parent.getChildAt(2);
+});
+attempt("parent.setChildIndex(c0, 2)", function():void {
+	parent.setChildIndex(c0, 2);
+});
+attempt("parent.removeChild(c2)", function():void {
+	parent.removeChild(c2);
+});
+attempt("c0.addChild(parent)", function():void {
+	c0.addChild(parent);
+});
+attempt("parent.addChild(parent)", function():void {
+	parent.addChild(parent);
+});
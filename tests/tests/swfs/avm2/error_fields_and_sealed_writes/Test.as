@@ -0,0 +1,39 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+import flash.events.EventDispatcher;
+
+var error:* = new TypeError("Error #1034: Type Coercion failed", 1034);
+trace("///error is TypeError");
+trace(error is TypeError);
+trace("///error.message");
+trace(error.message);
+trace("///error.name");
+trace(error.name);
+trace("///error.errorID");
+trace(error.errorID);
+
+trace("///error.errorID = 1");
+try {
+	error.errorID = 1;
+} catch (e:Error) {
+	trace(e.name);
+	trace(e.errorID);
+}
+
+var dispatcher:* = new EventDispatcher();
+trace("///dispatcher.missing = 1");
+try {
+	dispatcher.missing = 1;
+} catch (e:Error) {
+	trace(e);
+}
+trace("///dispatcher.addEventListener = null");
+try {
+	dispatcher.addEventListener = null;
+} catch (e:Error) {
+	trace(e);
+}
@@ -0,0 +1,34 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.Event;
+
+	// Run with a small AVM2 instruction budget, so that the frame 1 script is
+	// suspended and finishes on a later frame.
+	public class Test extends MovieClip {
+		private var frames:int = 0;
+
+		public function Test() {
+			addFrameScript(0, frame1, 1, frame2);
+			addEventListener(Event.ENTER_FRAME, function(e:Event):void {
+				frames++;
+			});
+		}
+
+		public function frame1():void {
+			stop();
+			trace("frame 1 script started");
+			var sum:int = 0;
+			for (var i:int = 0; i < 10000; i++) {
+				sum += i;
+			}
+			trace("sum: " + sum);
+			trace("resumed on a later frame: " + (frames > 1));
+			trace("currentFrame: " + currentFrame);
+			gotoAndStop(2);
+		}
+
+		public function frame2():void {
+			trace("frame 2 script");
+		}
+	}
+}
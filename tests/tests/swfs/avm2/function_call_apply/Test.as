@@ -0,0 +1,37 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+// Returns `[this, ...args]`, so that we can observe how it was called.
+var record:Function = function(... args):Array {
+	return [this].concat(args);
+};
+var receiver:Object = {};
+
+var result:Array = record.call(receiver, 1, "two");
+trace("///result = record.call(receiver, 1, \"two\"); result.length");
+trace(result.length);
+trace("///result[0] === receiver");
+trace(result[0] === receiver);
+trace("///result[1]");
+trace(result[1]);
+trace("///result[2]");
+trace(result[2]);
+
+result = record.apply(receiver, [3, true]);
+trace("///result = record.apply(receiver, [3, true]); result.length");
+trace(result.length);
+trace("///result[0] === receiver");
+trace(result[0] === receiver);
+trace("///result[1]");
+trace(result[1]);
+trace("///result[2]");
+trace(result[2]);
+
+result = record.apply(receiver);
+trace("///result = record.apply(receiver); result.length");
+trace(result.length);
+trace("///result[0] === receiver");
+trace(result[0] === receiver);
@@ -0,0 +1,31 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+function typedDefault(a:int, b:int = 7):int {
+	return b;
+}
+
+function stringDefault(a:int, b:String = "default"):String {
+	return b;
+}
+
+trace("///typedDefault(1)");
+trace(typedDefault(1));
+
+trace("///typedDefault(1, 3.5)");
+trace(typedDefault(1, 3.5));
+
+trace("///typedDefault(1, \"12\")");
+trace(typedDefault(1, "12"));
+
+trace("///stringDefault(1)");
+trace(stringDefault(1));
+
+trace("///stringDefault(1, 5)");
+trace(stringDefault(1, 5));
+
+trace("///stringDefault(1, null)");
+trace(stringDefault(1, null));
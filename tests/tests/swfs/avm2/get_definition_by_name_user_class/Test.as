@@ -0,0 +1,23 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+import com.example.Widget;
+import flash.utils.getDefinitionByName;
+import flash.utils.getQualifiedClassName;
+
+trace("///getDefinitionByName(\"com.example.Widget\") === Widget");
+trace(getDefinitionByName("com.example.Widget") === Widget);
+trace("///getDefinitionByName(\"com.example::Widget\") === Widget");
+trace(getDefinitionByName("com.example::Widget") === Widget);
+trace("///getQualifiedClassName(Widget)");
+trace(getQualifiedClassName(Widget));
+
+trace("///getDefinitionByName(\"com.example.Missing\")");
+try {
+	getDefinitionByName("com.example.Missing");
+} catch (e:ReferenceError) {
+	trace("ReferenceError");
+}
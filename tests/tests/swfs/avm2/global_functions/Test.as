@@ -0,0 +1,37 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+trace("///parseInt(\"0x1F\")");
+trace(parseInt("0x1F"));
+trace("///parseInt(\"  42abc\")");
+trace(parseInt("  42abc"));
+trace("///parseInt(\"-ff\", 16)");
+trace(parseInt("-ff", 16));
+trace("///parseInt(\"abc\")");
+trace(parseInt("abc"));
+trace("///parseFloat(\"3.14xyz\")");
+trace(parseFloat("3.14xyz"));
+trace("///isNaN(NaN)");
+trace(isNaN(NaN));
+trace("///isFinite(\"12\")");
+trace(isFinite("12"));
+
+var text:String = "a bé☺";
+var escaped:String = escape(text);
+trace("///escape(text)");
+trace(escaped);
+trace("///unescape(escaped) == text");
+trace(unescape(escaped) == text);
+
+// Malformed escapes are left alone.
+trace("///unescape(\"100%%zz%u12\")");
+trace(unescape("100%%zz%u12"));
+
+var uri:String = "http://a.com/?q=x y&r=é";
+trace("///encodeURIComponent(uri)");
+trace(encodeURIComponent(uri));
+trace("///encodeURI(uri)");
+trace(encodeURI(uri));
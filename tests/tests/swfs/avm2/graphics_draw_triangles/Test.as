@@ -0,0 +1,42 @@
+package {
+	import flash.display.BitmapData;
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			graphics.beginFill(0xFFFFFF);
+			graphics.drawRect(0, 0, 40, 20);
+			graphics.endFill();
+
+			var bitmap:BitmapData = new BitmapData(2, 2, false);
+			bitmap.setPixel(0, 0, 0xFF0000);
+			bitmap.setPixel(1, 0, 0x00FF00);
+			bitmap.setPixel(0, 1, 0x0000FF);
+			bitmap.setPixel(1, 1, 0x000000);
+
+			// Two quads with the bitmap stretched over them. Both are wound the same
+			// way, so only the first one survives its culling mode.
+			var quads:Shape = new Shape();
+			quads.graphics.beginBitmapFill(bitmap);
+			var indices:Vector.<int> = Vector.<int>([0, 1, 2, 0, 2, 3]);
+			var uvs:Vector.<Number> = Vector.<Number>([0, 0, 1, 0, 1, 1, 0, 1]);
+			quads.graphics.drawTriangles(Vector.<Number>([0, 0, 20, 0, 20, 20, 0, 20]), indices, uvs, "negative");
+			quads.graphics.drawTriangles(Vector.<Number>([20, 0, 40, 0, 40, 20, 20, 20]), indices, uvs, "positive");
+			addChild(quads);
+
+			var triangle:Shape = new Shape();
+			triangle.graphics.beginFill(0xFF0000);
+			var vertices:Vector.<Number> = Vector.<Number>([0, 0, 10, 0, 10, 10]);
+			trace("///triangle.graphics.drawTriangles(vertices, Vector.<int>([0, 1, -1]))");
+			try {
+				triangle.graphics.drawTriangles(vertices, Vector.<int>([0, 1, -1]));
+			} catch (e:Error) {
+				trace(e);
+			}
+			trace("///triangle.graphics.drawTriangles(vertices, Vector.<int>([0, 1, 2]))");
+			triangle.graphics.drawTriangles(vertices, Vector.<int>([0, 1, 2]));
+			trace("drawn");
+		}
+	}
+}
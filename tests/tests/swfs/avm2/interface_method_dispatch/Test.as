@@ -0,0 +1,40 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+interface IBase {
+	function base():int;
+}
+
+interface IFoo extends IBase {
+	function foo():int;
+}
+
+class Impl implements IFoo {
+	public function foo():int {
+		return 42;
+	}
+
+	public function base():int {
+		return 7;
+	}
+}
+
+var impl = new Impl();
+var asFoo:IFoo = impl;
+var asBase:IBase = impl;
+
+trace("///impl is IFoo");
+trace(impl is IFoo);
+trace("///impl is IBase");
+trace(impl is IBase);
+trace("///Impl is IFoo");
+trace(Impl is IFoo);
+trace("///asFoo.foo()");
+trace(asFoo.foo());
+trace("///asFoo.base()");
+trace(asFoo.base());
+trace("///asBase.base()");
+trace(asBase.base());
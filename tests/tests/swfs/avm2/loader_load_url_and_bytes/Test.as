@@ -0,0 +1,66 @@
+package {
+	import flash.display.DisplayObject;
+	import flash.display.Loader;
+	import flash.display.LoaderInfo;
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.net.URLLoader;
+	import flash.net.URLLoaderDataFormat;
+	import flash.net.URLRequest;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var loader:Loader = new Loader();
+			trace("///loader.load(null)");
+			try {
+				loader.load(null);
+			} catch (e:Error) {
+				trace(e.errorID);
+			}
+			trace("///loader.loadBytes(null)");
+			try {
+				loader.loadBytes(null);
+			} catch (e:Error) {
+				trace(e.errorID);
+			}
+
+			addChild(loader);
+			listen(loader.contentLoaderInfo, "load", loadBytes);
+			loader.load(new URLRequest("loadable.swf"));
+		}
+
+		private function loadBytes():void {
+			var urlLoader:URLLoader = new URLLoader();
+			urlLoader.dataFormat = URLLoaderDataFormat.BINARY;
+			urlLoader.addEventListener(Event.COMPLETE, function(event:Event):void {
+				var loader:Loader = new Loader();
+				addChild(loader);
+				// `loadBytes` never fires an "open" event.
+				listen(loader.contentLoaderInfo, "loadBytes", null);
+				loader.loadBytes(urlLoader.data);
+			});
+			urlLoader.load(new URLRequest("loadable.swf"));
+		}
+
+		/// Traces the load events of `info`, with repeats collapsed, and calls `done` on completion.
+		private function listen(info:LoaderInfo, label:String, done:Function):void {
+			var last:String = null;
+			var handler:Function = function(event:Event):void {
+				if (event.type != last) {
+					trace(label + ": " + event.type);
+					last = event.type;
+				}
+				if (event.type == Event.COMPLETE) {
+					trace(label + ": bytesLoaded = " + info.bytesLoaded + ", bytesTotal = " + info.bytesTotal);
+					trace(label + ": content is DisplayObject = " + (info.content is DisplayObject));
+					if (done != null) {
+						done();
+					}
+				}
+			};
+			for each (var type:String in ["open", "progress", "init", "complete"]) {
+				info.addEventListener(type, handler);
+			}
+		}
+	}
+}
@@ -0,0 +1,13 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.MouseEvent;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			stage.addEventListener(MouseEvent.MOUSE_WHEEL, function(event:MouseEvent):void {
+				trace("///mouseWheel on " + event.target);
+				trace(event.delta);
+			});
+		}
+	}
+}
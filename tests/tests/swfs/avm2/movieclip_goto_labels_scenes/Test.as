@@ -0,0 +1,59 @@
+package {
+	import flash.display.MovieClip;
+
+	// The timeline of test.swf has two scenes: "Scene 1" spans frames 1-2 and
+	// "Scene 2" spans frames 3-4. Frames 2, 3, and 4 are labeled "intro", "main",
+	// and "outro" respectively.
+	public class Test extends MovieClip {
+		public function Test() {
+			addFrameScript(0, frame1);
+		}
+
+		private function frame1():void {
+			trace("///totalFrames");
+			trace(totalFrames);
+
+			gotoAndStop("intro");
+			trace("///gotoAndStop(\"intro\")");
+			trace(currentFrame + " " + currentLabel + " " + isPlaying);
+
+			// Frame numbers are relative to the given scene.
+			gotoAndPlay(1, "Scene 2");
+			trace("///gotoAndPlay(1, \"Scene 2\")");
+			trace(currentFrame + " " + currentLabel + " " + isPlaying);
+			trace("///currentScene.name");
+			trace(currentScene.name);
+
+			stop();
+			trace("///stop()");
+			trace(isPlaying);
+
+			nextFrame();
+			trace("///nextFrame()");
+			trace(currentLabel);
+
+			prevFrame();
+			prevFrame();
+			trace("///prevFrame(); prevFrame()");
+			trace(currentLabel);
+
+			// Labels outside of the requested scene, and unknown labels, are errors.
+			trace("///gotoAndStop(\"outro\", \"Scene 1\")");
+			try {
+				gotoAndStop("outro", "Scene 1");
+			} catch (e:Error) {
+				trace(e is ArgumentError);
+				trace(e.errorID);
+			}
+			trace("///gotoAndStop(\"missing\")");
+			try {
+				gotoAndStop("missing");
+			} catch (e:Error) {
+				trace(e is ArgumentError);
+				trace(e.errorID);
+			}
+			trace("///currentLabel");
+			trace(currentLabel);
+		}
+	}
+}
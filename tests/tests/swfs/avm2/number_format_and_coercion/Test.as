@@ -0,0 +1,38 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+var byteValue:Number = 255;
+var negative:Number = -10;
+var pi:Number = 3.14159;
+var half:Number = 2.5;
+trace("///byteValue.toString(16)");
+trace(byteValue.toString(16));
+trace("///negative.toString(2)");
+trace(negative.toString(2));
+trace("///pi.toFixed(2)");
+trace(pi.toFixed(2));
+trace("///half.toPrecision(2)");
+trace(half.toPrecision(2));
+
+var minusOne:* = -1;
+var maxUint:* = 4294967295;
+var decimal:* = "12.7";
+var letters:* = "abc";
+var empty:* = "";
+trace("///uint(minusOne)");
+trace(uint(minusOne));
+trace("///int(maxUint)");
+trace(int(maxUint));
+trace("///int(decimal)");
+trace(int(decimal));
+trace("///Number(letters)");
+trace(Number(letters));
+trace("///Boolean(empty)");
+trace(Boolean(empty));
+
+// Values too large for an int keep the methods of their class.
+trace("///new uint(maxUint).toString(16)");
+trace(new uint(maxUint).toString(16));
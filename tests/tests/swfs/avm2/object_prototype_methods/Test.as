@@ -0,0 +1,29 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+var object = {};
+object.foo = 1;
+
+trace("///object.hasOwnProperty(\"foo\")");
+trace(object.hasOwnProperty("foo"));
+trace("///object.hasOwnProperty(\"bar\")");
+trace(object.hasOwnProperty("bar"));
+trace("///object.hasOwnProperty(\"toString\")");
+trace(object.hasOwnProperty("toString"));
+trace("///object.propertyIsEnumerable(\"foo\")");
+trace(object.propertyIsEnumerable("foo"));
+
+trace("///Object.prototype.hasOwnProperty(\"toString\")");
+trace(Object.prototype.hasOwnProperty("toString"));
+trace("///Object.prototype.propertyIsEnumerable(\"toString\")");
+trace(Object.prototype.propertyIsEnumerable("toString"));
+
+trace("///Object.prototype.isPrototypeOf(object)");
+trace(Object.prototype.isPrototypeOf(object));
+trace("///object.isPrototypeOf(object)");
+trace(object.isPrototypeOf(object));
+trace("///object.isPrototypeOf(Object.prototype)");
+trace(object.isPrototypeOf(Object.prototype));
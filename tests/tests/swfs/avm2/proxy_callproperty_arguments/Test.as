@@ -0,0 +1,23 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+import flash.utils.Proxy;
+import flash.utils.flash_proxy;
+
+use namespace flash_proxy;
+
+dynamic class ArgsProxy extends Proxy {
+	flash_proxy override function callProperty(name:*, ... rest):* {
+		return name.localName + "(" + rest.join(", ") + ")";
+	}
+}
+
+var a = new ArgsProxy();
+
+trace("///a.join_args(1, 2, 3)");
+trace(a.join_args(1, 2, 3));
+trace("///a.no_args()");
+trace(a.no_args());
@@ -0,0 +1,52 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+import flash.utils.Proxy;
+import flash.utils.flash_proxy;
+
+use namespace flash_proxy;
+
+dynamic class RangeProxy extends Proxy {
+	flash_proxy override function nextNameIndex(index:int):int {
+		return index < 3 ? index + 1 : 0;
+	}
+
+	flash_proxy override function nextName(index:int):String {
+		return "k" + index;
+	}
+
+	flash_proxy override function nextValue(index:int):* {
+		return 100 + index;
+	}
+}
+
+dynamic class EmptyProxy extends Proxy {
+	flash_proxy override function nextNameIndex(index:int):int {
+		return 0;
+	}
+}
+
+var r = new RangeProxy();
+
+trace("///names of RangeProxy");
+var names = "";
+for (var name in r) {
+	names += name;
+}
+trace(names);
+
+trace("///sum of values of RangeProxy");
+var sum = 0;
+for each (var value in r) {
+	sum += value;
+}
+trace(sum);
+
+trace("///for (var k in new EmptyProxy())...");
+for (var k in new EmptyProxy()) {
+	trace(k);
+}
+trace("///done");
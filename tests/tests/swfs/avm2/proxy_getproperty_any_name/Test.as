@@ -0,0 +1,26 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+import flash.utils.Proxy;
+import flash.utils.flash_proxy;
+
+use namespace flash_proxy;
+
+dynamic class MapProxy extends Proxy {
+	flash_proxy override function getProperty(name:*):* {
+		trace("///getProperty: " + name.localName);
+		return 42;
+	}
+}
+
+var m = new MapProxy();
+
+trace("///m.anything + m.other");
+trace(m.anything + m.other);
+trace("///m[\"computed\"]");
+trace(m["computed"]);
+trace("///m[1]");
+trace(m[1]);
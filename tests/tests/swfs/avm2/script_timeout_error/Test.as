@@ -0,0 +1,27 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+import flash.errors.ScriptTimeoutError;
+
+// The test harness lowers the timeout to 2 seconds.
+trace("///while (true) {} // catching ScriptTimeoutError");
+try {
+	while (true) {}
+} catch (e:ScriptTimeoutError) {
+	trace("///caught");
+	trace(e is ScriptTimeoutError);
+	trace(e.errorID);
+	trace(e.message);
+}
+
+// The error is only thrown once; running for as long again terminates the script.
+trace("///while (true) {} // after the error was caught");
+try {
+	while (true) {}
+} catch (e:ScriptTimeoutError) {
+	trace("///caught again");
+}
+trace("///not reached");
@@ -0,0 +1,13 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+import flash.system.Security;
+
+// The test harness places this movie in the remote sandbox.
+trace("///Security.sandboxType");
+trace(Security.sandboxType);
+
+Security.allowDomain("example.com");
@@ -0,0 +1,22 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("///stage.frameRate");
+			trace(stage.frameRate);
+
+			stage.frameRate = 50;
+			trace("///stage.frameRate = 50");
+			trace(stage.frameRate);
+
+			stage.frameRate = 5000;
+			trace("///stage.frameRate = 5000");
+			trace(stage.frameRate);
+
+			stage.frameRate = NaN;
+			trace("///stage.frameRate = NaN");
+			trace(stage.frameRate);
+		}
+	}
+}
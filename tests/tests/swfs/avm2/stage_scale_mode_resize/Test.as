@@ -0,0 +1,31 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.Event;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			// The test harness uses an 800x600 viewport for this 550x400 movie.
+			trace("///stage.scaleMode");
+			trace(stage.scaleMode);
+			trace("///stage.stageWidth");
+			trace(stage.stageWidth);
+			trace("///stage.stageHeight");
+			trace(stage.stageHeight);
+
+			stage.scaleMode = "noScale";
+			stage.align = "TL";
+			trace("///stage.scaleMode = \"noScale\"; stage.align = \"TL\"");
+			trace("///stage.align");
+			trace(stage.align);
+			trace("///stage.stageWidth");
+			trace(stage.stageWidth);
+			trace("///stage.stageHeight");
+			trace(stage.stageHeight);
+
+			stage.addEventListener(Event.RESIZE, function(event:Event):void {
+				trace("///resize");
+				trace(stage.stageWidth + "x" + stage.stageHeight);
+			});
+		}
+	}
+}
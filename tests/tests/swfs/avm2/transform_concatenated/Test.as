@@ -0,0 +1,42 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.geom.ColorTransform;
+	import flash.geom.Matrix;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			var outer:Sprite = new Sprite();
+			var inner:Sprite = new Sprite();
+			outer.addChild(inner);
+			addChild(outer);
+			outer.scaleX = 2;
+			outer.scaleY = 2;
+			outer.x = 10;
+			outer.y = 20;
+			inner.scaleX = 0.5;
+			inner.scaleY = 1;
+			inner.x = 5;
+			inner.y = -3;
+
+			trace("///inner.transform.matrix");
+			trace(matrixFields(inner.transform.matrix));
+			trace("///inner.transform.concatenatedMatrix");
+			trace(matrixFields(inner.transform.concatenatedMatrix));
+
+			outer.transform.colorTransform = new ColorTransform(1, 1, 1, 0.5, 10, 0, 0, 0);
+			inner.transform.colorTransform = new ColorTransform(0.5, 1, 1, 0.5, 0, 0, 0, 0);
+			var concatenated:ColorTransform = inner.transform.concatenatedColorTransform;
+			trace("///concatenated.redMultiplier");
+			trace(concatenated.redMultiplier);
+			trace("///concatenated.alphaMultiplier");
+			trace(concatenated.alphaMultiplier);
+			trace("///concatenated.redOffset");
+			trace(concatenated.redOffset);
+		}
+
+		private function matrixFields(matrix:Matrix):String {
+			return [matrix.a, matrix.b, matrix.c, matrix.d, matrix.tx, matrix.ty].join(",");
+		}
+	}
+}
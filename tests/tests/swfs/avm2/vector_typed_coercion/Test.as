@@ -0,0 +1,81 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+var ints:* = new Vector.<int>();
+ints.push(1.9, -2.7);
+trace("///ints.push(1.9, -2.7); ints.length");
+trace(ints.length);
+trace("///ints[0]");
+trace(ints[0]);
+trace("///ints[1]");
+trace(ints[1]);
+
+var uints:* = new Vector.<uint>();
+uints.push(-1);
+trace("///uints.push(-1); uints.pop()");
+trace(uints.pop());
+
+var numbers:* = new Vector.<Number>();
+numbers.push("2.5");
+trace("///numbers.push(\"2.5\"); numbers.pop()");
+trace(numbers.pop());
+
+var bools:* = new Vector.<Boolean>(2);
+trace("///bools[0]");
+trace(bools[0]);
+bools[1] = "yes";
+trace("///bools[1] = \"yes\"; bools[1]");
+trace(bools[1]);
+
+var resized:* = new Vector.<int>(1);
+resized[0] = 7.75;
+trace("///resized[0] = 7.75; resized[0]");
+trace(resized[0]);
+resized[1] = null;
+trace("///resized[1] = null; resized.length");
+trace(resized.length);
+trace("///resized[1]");
+trace(resized[1]);
+
+var fixed:* = new Vector.<Number>(2, true);
+trace("///fixed.fixed");
+trace(fixed.fixed);
+
+trace("///fixed.push(1)");
+try {
+	fixed.push(1);
+} catch (e:Error) {
+	trace(e);
+}
+trace("///fixed.pop()");
+try {
+	fixed.pop();
+} catch (e:Error) {
+	trace(e);
+}
+trace("///fixed.length = 3");
+try {
+	fixed.length = 3;
+} catch (e:Error) {
+	trace(e);
+}
+trace("///fixed[2] = 1");
+try {
+	fixed[2] = 1;
+} catch (e:Error) {
+	trace(e is RangeError);
+}
+trace("///fixed.length");
+trace(fixed.length);
+
+fixed[1] = 4.5;
+trace("///fixed[1] = 4.5; fixed[1]");
+trace(fixed[1]);
+
+fixed.fixed = false;
+fixed.push(1);
+trace("///fixed.fixed = false; fixed.push(1); fixed.length");
+trace(fixed.length);
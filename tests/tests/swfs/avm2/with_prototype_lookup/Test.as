@@ -0,0 +1,32 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {}
+}
+
+function lookupArray(o:Object):* {
+	with (o) {
+		return Array;
+	}
+}
+
+function Inherits() {}
+Inherits.prototype = {Array: 2};
+
+trace("///own property");
+trace(lookupArray({Array: 1}));
+
+trace("///inherited property");
+trace(lookupArray(new Inherits()));
+
+trace("///no property");
+trace(lookupArray({}));
+
+trace("///assignment through an inherited property");
+var inherits:* = new Inherits();
+with (inherits) {
+	Array = 3;
+}
+trace(inherits.hasOwnProperty("Array"));
+trace(inherits.Array);
+trace(Inherits.prototype.Array);
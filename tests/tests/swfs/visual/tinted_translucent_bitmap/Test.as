@@ -0,0 +1,35 @@
+package {
+	import flash.display.Bitmap;
+	import flash.display.BitmapData;
+	import flash.display.Shape;
+	import flash.display.Sprite;
+	import flash.geom.ColorTransform;
+
+	// Tints a bitmap with translucent pixels red over a black background.
+	// The color transform pushes the red channel past 1.0; it has to be clamped
+	// before alpha is premultiplied, or translucent pixels come out too bright.
+	public class Test extends Sprite {
+		public function Test() {
+			var background:* = new Shape();
+			background.graphics.beginFill(0x000000);
+			background.graphics.drawRect(0, 0, 40, 10);
+			background.graphics.endFill();
+			addChild(background);
+
+			// Four 10x10 white blocks, fading out from left to right.
+			var bitmapData:* = new BitmapData(40, 10, true, 0);
+			var alphas:Array = [0xFF, 0xAA, 0x55, 0x00];
+			for (var block:int = 0; block < 4; block++) {
+				for (var x:int = 0; x < 10; x++) {
+					for (var y:int = 0; y < 10; y++) {
+						bitmapData.setPixel32(block * 10 + x, y, (alphas[block] << 24) | 0xFFFFFF);
+					}
+				}
+			}
+
+			var bitmap:* = new Bitmap(bitmapData);
+			bitmap.transform.colorTransform = new ColorTransform(1, 0, 0, 1, 255, 0, 0, 0);
+			addChild(bitmap);
+		}
+	}
+}